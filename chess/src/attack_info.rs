@@ -0,0 +1,75 @@
+/*
+ * attack_info.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use crate::{bitboard::Bitboard, definitions::NumberOf, pieces::Piece, side::PlayerSide};
+
+/// Attack information for a position, computed once with
+/// [`MoveGenerator::compute_attack_info`](crate::move_generation::MoveGenerator::compute_attack_info)
+/// so that evaluation terms can share it instead of recomputing slider attacks.
+#[derive(Clone, Debug)]
+pub struct AttackInfo {
+    square_attacks: [Bitboard; NumberOf::SQUARES],
+    piece_attacks: [[Bitboard; NumberOf::PIECE_TYPES]; NumberOf::SIDES],
+    attacks: [Bitboard; NumberOf::SIDES],
+    attacked_by_two: [Bitboard; NumberOf::SIDES],
+}
+
+impl Default for AttackInfo {
+    fn default() -> Self {
+        Self {
+            square_attacks: [Bitboard::default(); NumberOf::SQUARES],
+            piece_attacks: [[Bitboard::default(); NumberOf::PIECE_TYPES]; NumberOf::SIDES],
+            attacks: [Bitboard::default(); NumberOf::SIDES],
+            attacked_by_two: [Bitboard::default(); NumberOf::SIDES],
+        }
+    }
+}
+
+impl AttackInfo {
+    /// Adds the attacks of a single piece.
+    ///
+    /// # Arguments
+    ///
+    /// - `square` - The square the piece is on.
+    /// - `piece` - The attacking piece.
+    /// - `side` - The side the piece belongs to.
+    /// - `attacks` - The squares attacked by the piece.
+    pub(crate) fn add(&mut self, square: u8, piece: Piece, side: PlayerSide, attacks: Bitboard) {
+        self.square_attacks[square as usize] = attacks;
+        self.piece_attacks[side as usize][piece as usize] |= attacks;
+        self.attacked_by_two[side as usize] |= self.attacks[side as usize] & attacks;
+        self.attacks[side as usize] |= attacks;
+    }
+
+    /// Returns the squares attacked by the piece on `square`, or an empty bitboard if the square
+    /// is empty.
+    pub fn square_attacks(&self, square: u8) -> Bitboard {
+        self.square_attacks[square as usize]
+    }
+
+    /// Returns the squares attacked by all pieces of the given type and side.
+    pub fn piece_attacks(&self, piece: Piece, side: PlayerSide) -> Bitboard {
+        self.piece_attacks[side as usize][piece as usize]
+    }
+
+    /// Returns all squares attacked by the given side.
+    pub fn attacks(&self, side: PlayerSide) -> Bitboard {
+        self.attacks[side as usize]
+    }
+
+    /// Returns the squares attacked by at least two pieces of the given side.
+    pub fn attacked_by_two(&self, side: PlayerSide) -> Bitboard {
+        self.attacked_by_two[side as usize]
+    }
+}
@@ -21,7 +21,51 @@ use std::{
     },
 };
 
-use crate::square::Square;
+use crate::{definitions::FILE_A, square::Square};
+
+const FILE_H: u64 = FILE_A << 7;
+
+/// The eight compass directions a bitboard can be shifted in. North is towards rank 8 and east is
+/// towards the h-file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// All eight directions.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+    /// The directions a rook moves in.
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+    /// The directions a bishop moves in.
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::SouthWest,
+        Direction::NorthWest,
+    ];
+}
 
 /// Bitboard representation of a chess board.
 /// LSB (bit 0) is a1, MSB (bit 63) is h8.
@@ -109,6 +153,88 @@ impl Bitboard {
     pub fn intersects(&self, other: impl Into<Self>) -> bool {
         (*self & other.into()).number_of_occupied_squares() > 0
     }
+
+    /// Returns the index of the least significant set bit without modifying the bitboard.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u8>` - The square index, or `None` if the bitboard is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chess::bitboard::Bitboard;
+    ///
+    /// let bb = Bitboard::new(0x8000000000000010);
+    /// assert_eq!(bb.lsb(), Some(4));
+    /// assert_eq!(Bitboard::EMPTY.lsb(), None);
+    /// ```
+    pub fn lsb(&self) -> Option<u8> {
+        if self.data == 0 {
+            None
+        } else {
+            Some(self.data.trailing_zeros() as u8)
+        }
+    }
+
+    /// Shifts every square one step in the given direction. Squares that would leave the board are
+    /// dropped instead of wrapping around to the other side.
+    ///
+    /// # Arguments
+    ///
+    /// - `direction` - The direction to shift in.
+    ///
+    /// # Returns
+    ///
+    /// - `Bitboard` - The shifted bitboard.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chess::bitboard::{Bitboard, Direction};
+    /// use chess::definitions::Squares;
+    ///
+    /// let bb = Bitboard::from_square(Squares::E4);
+    /// assert_eq!(bb.shift(Direction::NorthEast), Bitboard::from_square(Squares::F5));
+    /// // there is nothing east of the h-file
+    /// assert_eq!(Bitboard::from_square(Squares::H4).shift(Direction::East), Bitboard::EMPTY);
+    /// ```
+    pub const fn shift(&self, direction: Direction) -> Bitboard {
+        let data = self.data;
+        Bitboard::new(match direction {
+            Direction::North => data << 8,
+            Direction::NorthEast => (data & !FILE_H) << 9,
+            Direction::East => (data & !FILE_H) << 1,
+            Direction::SouthEast => (data & !FILE_H) >> 7,
+            Direction::South => data >> 8,
+            Direction::SouthWest => (data & !FILE_A) >> 9,
+            Direction::West => (data & !FILE_A) >> 1,
+            Direction::NorthWest => (data & !FILE_A) << 7,
+        })
+    }
+
+    /// Clears the least significant set bit and returns its index. This allows iterating over the
+    /// set squares with `while let Some(square) = bb.pop_lsb()`.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<u8>` - The square index, or `None` if the bitboard is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chess::bitboard::Bitboard;
+    ///
+    /// let mut bb = Bitboard::new(0x8000000000000010);
+    /// assert_eq!(bb.pop_lsb(), Some(4));
+    /// assert_eq!(bb.pop_lsb(), Some(63));
+    /// assert_eq!(bb.pop_lsb(), None);
+    /// ```
+    pub fn pop_lsb(&mut self) -> Option<u8> {
+        let square = self.lsb()?;
+        self.data &= self.data - 1;
+        Some(square)
+    }
 }
 
 impl PartialOrd<u64> for Bitboard {
@@ -347,6 +473,42 @@ mod tests {
         assert_eq!(bb.data, 0x7FFFFFFFFFFFFFFE);
     }
 
+    #[test]
+    fn shift_does_not_wrap() {
+        let h_file = Bitboard::new(FILE_H);
+        let a_file = Bitboard::new(FILE_A);
+        for direction in [Direction::East, Direction::NorthEast, Direction::SouthEast] {
+            assert_eq!(h_file.shift(direction), Bitboard::EMPTY, "{:?}", direction);
+        }
+        for direction in [Direction::West, Direction::NorthWest, Direction::SouthWest] {
+            assert_eq!(a_file.shift(direction), Bitboard::EMPTY, "{:?}", direction);
+        }
+        assert_eq!(
+            Bitboard::new(0xFF << 56).shift(Direction::North),
+            Bitboard::EMPTY
+        );
+        assert_eq!(Bitboard::new(0xFF).shift(Direction::South), Bitboard::EMPTY);
+
+        // every direction moves a center square to its neighbour, and back again
+        let e4 = Bitboard::from_square(Squares::E4);
+        let neighbours = [
+            Squares::E5,
+            Squares::F5,
+            Squares::F4,
+            Squares::F3,
+            Squares::E3,
+            Squares::D3,
+            Squares::D4,
+            Squares::D5,
+        ];
+        for (direction, square) in Direction::ALL.into_iter().zip(neighbours) {
+            let shifted = e4.shift(direction);
+            assert_eq!(shifted, Bitboard::from_square(square), "{:?}", direction);
+            let opposite = Direction::ALL[(direction as usize + 4) % 8];
+            assert_eq!(shifted.shift(opposite), e4, "{:?}", direction);
+        }
+    }
+
     #[test]
     fn bitwise_operations() {
         let bb1 = Bitboard::new(0xF0F0F0F0F0F0F0F0);
@@ -393,4 +555,38 @@ mod tests {
         assert_eq!(front_square, Squares::B5);
         assert_eq!(back_square, Squares::B3);
     }
+
+    #[test]
+    fn lsb() {
+        assert_eq!(Bitboard::EMPTY.lsb(), None);
+        assert_eq!(Bitboard::from_square(Squares::A1).lsb(), Some(Squares::A1));
+        assert_eq!(Bitboard::from_square(Squares::H8).lsb(), Some(Squares::H8));
+
+        let bb = Bitboard::from_square(Squares::C3) | Bitboard::from_square(Squares::F6);
+        assert_eq!(bb.lsb(), Some(Squares::C3));
+        // peeking does not modify the bitboard
+        assert_eq!(bb.lsb(), Some(Squares::C3));
+        assert_eq!(bb.number_of_occupied_squares(), 2);
+    }
+
+    #[test]
+    fn pop_lsb() {
+        let mut bb = Bitboard::EMPTY;
+        assert_eq!(bb.pop_lsb(), None);
+        assert_eq!(bb, Bitboard::EMPTY);
+
+        let mut bb = Bitboard::new(0xFFFFFFFFFFFFFFFF);
+        for square in 0..64 {
+            assert_eq!(bb.pop_lsb(), Some(square));
+        }
+        assert_eq!(bb.pop_lsb(), None);
+        assert_eq!(bb, Bitboard::EMPTY);
+
+        let mut bb = Bitboard::from_square(Squares::E4) | Bitboard::from_square(Squares::B7);
+        let mut squares = Vec::new();
+        while let Some(square) = bb.pop_lsb() {
+            squares.push(square);
+        }
+        assert_eq!(squares, vec![Squares::E4, Squares::B7]);
+    }
 }
@@ -4,7 +4,7 @@
  * Created Date: Monday, November 25th 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Tue Nov 26 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -16,6 +16,11 @@ use crate::bitboard::Bitboard;
 
 /// Returns the index of the next bit set to 1 in the bitboard and sets it to 0.
 ///
+/// The bitboard must not be empty, this is checked with a debug assertion. In release builds an
+/// empty bitboard returns 64, and because the shift amount wraps, `1u64 << 64` becomes `1 << 0`:
+/// bit 0 is set and the bitboard is corrupted. Use [`Bitboard::pop_lsb`] if the bitboard may be
+/// empty.
+///
 /// # Arguments
 ///
 /// * `bitboard` - The bitboard to get the next bit from.
@@ -49,6 +54,7 @@ use crate::bitboard::Bitboard;
 /// ```
 ///
 pub fn next_bit(bitboard: &mut Bitboard) -> usize {
+    debug_assert!(bitboard.as_number() != 0, "next_bit on an empty bitboard");
     let square = bitboard.as_number().trailing_zeros();
     *bitboard ^= 1u64 << square;
     square as usize
@@ -72,4 +78,12 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "next_bit on an empty bitboard")]
+    fn next_bit_of_an_empty_bitboard_panics() {
+        use super::*;
+        next_bit(&mut Bitboard::new(0));
+    }
 }
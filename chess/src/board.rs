@@ -4,7 +4,7 @@
  * Created Date: Wednesday, August 21st 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Tue Nov 26 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -15,20 +15,23 @@
 use std::iter::zip;
 
 use crate::bitboard_helpers;
-use crate::board_state::BoardState;
+use crate::board_state::{BoardSnapshot, BoardState};
 use crate::definitions::{CastlingAvailability, MAX_MOVE_RULE, MAX_REPETITION_COUNT, SPACE};
 use crate::fen::FenError;
 use crate::move_generation::MoveGenerator;
 use crate::move_history::BoardHistory;
 use crate::move_list::MoveList;
-use crate::moves::Move;
+use crate::moves::{Move, MoveType};
 use crate::square::Square;
 use crate::zobrist::{ZobristHash, ZobristRandomValues};
 
 use super::definitions::NumberOf;
 use super::fen;
-use super::side::Side;
-use super::{bitboard::Bitboard, pieces::Piece};
+use super::side::{PlayerSide, Side};
+use super::{
+    bitboard::Bitboard,
+    pieces::{Piece, ALL_PIECES},
+};
 
 /// Represents a chess board position.
 pub struct Board {
@@ -49,6 +52,18 @@ impl Clone for Board {
     }
 }
 
+/// Two boards are equal if they have the same position (see [`Board::same_position`]) and the same
+/// half move clock and full move number. The move history is not compared.
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.same_position(other)
+            && self.half_move_clock() == other.half_move_clock()
+            && self.full_move_number() == other.full_move_number()
+    }
+}
+
+impl Eq for Board {}
+
 // Private methods
 impl Board {
     /// Create a new board in the default, *uninitialized*, state.
@@ -62,55 +77,15 @@ impl Board {
     }
 
     pub(crate) fn initialize(&mut self) {
-        self.state.zobrist_hash = self.initialize_zobrist_hash();
-    }
-
-    fn initialize_zobrist_hash(&self) -> ZobristHash {
-        // create the initial zobrist hash based on the starting position
-        // for each piece on the board, get the corresponding zobrist value and xor it with the hash
-        // for each side to move, xor the hash with the zobrist value for the side
-        // for each castling right, xor the hash with the zobrist value for the castling right
-        // for the en passant square, xor the hash with the zobrist value for the en passant square
-        // Initialize the zobrist hash to 0
-        let mut zobrist_hash = ZobristHash::default();
-
-        // XOR the zobrist values for each piece on the board
-        for side in 0..NumberOf::SIDES {
-            for piece in 0..NumberOf::PIECE_TYPES {
-                let mut bitboard = self.piece_bitboards[side][piece];
-
-                while bitboard != 0 {
-                    let square = bitboard_helpers::next_bit(&mut bitboard);
-                    zobrist_hash ^= self.zobrist_values.get_piece_value(piece, side, square);
-                }
-            }
-        }
-
-        // XOR the zobrist value for the side to move
-        zobrist_hash ^= self
-            .zobrist_values
-            .get_side_value(self.side_to_move() as usize);
-
-        // XOR the zobrist values for castling rights
-        zobrist_hash ^= self
-            .zobrist_values
-            .get_castling_value(self.castling_rights() as usize);
-
-        // XOR the zobrist value for the en passant square, if any
-        zobrist_hash ^= self
-            .zobrist_values
-            .get_en_passant_value(self.state.en_passant_square);
-
-        zobrist_hash
+        self.state.zobrist_hash = self.compute_zobrist_hash();
     }
 
     /// Initialize bitboards for a given side
-    fn initialize_piece_bbs(&mut self, side: Side) {
+    fn initialize_piece_bbs(&mut self, side: PlayerSide) {
         // Set up the board with the starting position
         match side {
-            Side::White => self.initialize_white_bbs(),
-            Side::Black => self.initialize_black_bbs(),
-            _ => panic!("Invalid side"),
+            PlayerSide::White => self.initialize_white_bbs(),
+            PlayerSide::Black => self.initialize_black_bbs(),
         }
     }
 
@@ -138,7 +113,7 @@ impl Board {
         self.piece_bitboards[index][Piece::King as usize] = Bitboard::new(0x1000000000000000);
     }
 
-    pub(crate) fn mut_piece_bitboard(&mut self, piece: Piece, side: Side) -> &mut Bitboard {
+    pub(crate) fn mut_piece_bitboard(&mut self, piece: Piece, side: PlayerSide) -> &mut Bitboard {
         &mut self.piece_bitboards[side as usize][piece as usize]
     }
 
@@ -146,29 +121,15 @@ impl Board {
         self.piece_bitboards[side][piece].set_square(square);
     }
 
-    /// Sets the side to move and updates the zobrist hash.
-    pub(crate) fn set_side_to_move(&mut self, side: Side) {
-        // undo the current side to move in the hash
-        self.state.zobrist_hash ^= self
-            .zobrist_values
-            .get_side_value(self.state.side_to_move as usize);
-        // set the new side to move
-        self.state.side_to_move = side;
-        // update zobrist hash with the new side to move
-        self.state.zobrist_hash ^= self
-            .zobrist_values
-            .get_side_value(self.state.side_to_move as usize);
-    }
-
     /// Set the en passant square and update the zobrist hash.
     pub(crate) fn set_en_passant_square(&mut self, square: Option<u8>) {
         self.state.zobrist_hash ^= self
             .zobrist_values
-            .get_en_passant_value(self.state.en_passant_square);
+            .en_passant_key(self.state.en_passant_square);
         self.state.en_passant_square = square;
         self.state.zobrist_hash ^= self
             .zobrist_values
-            .get_en_passant_value(self.state.en_passant_square);
+            .en_passant_key(self.state.en_passant_square);
     }
 
     pub(crate) fn set_half_move_clock(&mut self, half_move_clock: u32) {
@@ -180,19 +141,18 @@ impl Board {
     }
 
     pub(crate) fn set_castling_rights(&mut self, castling_rights: u8) {
-        self.state.zobrist_hash ^= self
-            .zobrist_values
-            .get_castling_value(self.state.castling_rights as usize);
+        self.state.zobrist_hash ^= self.zobrist_values.castling_key(self.state.castling_rights);
         self.state.castling_rights = castling_rights;
-        self.state.zobrist_hash ^= self
-            .zobrist_values
-            .get_castling_value(self.state.castling_rights as usize);
+        self.state.zobrist_hash ^= self.zobrist_values.castling_key(self.state.castling_rights);
     }
 
-    pub(crate) fn update_zobrist_hash_for_piece(&mut self, square: u8, piece: Piece, side: Side) {
-        self.state.zobrist_hash ^=
-            self.zobrist_values
-                .get_piece_value(piece as usize, side as usize, square as usize);
+    pub(crate) fn update_zobrist_hash_for_piece(
+        &mut self,
+        square: u8,
+        piece: Piece,
+        side: PlayerSide,
+    ) {
+        self.state.zobrist_hash ^= self.zobrist_values.piece_key(piece, side, square);
     }
 
     fn set_zobrist_hash(&mut self, hash: u64) {
@@ -215,15 +175,15 @@ impl Board {
         let mut board = Board::new();
         // Set up the board with the starting position
         // White pieces
-        board.initialize_piece_bbs(Side::White);
+        board.initialize_piece_bbs(PlayerSide::White);
         // Black pieces
-        board.initialize_piece_bbs(Side::Black);
+        board.initialize_piece_bbs(PlayerSide::Black);
         board.set_en_passant_square(None);
         board.set_half_move_clock(0);
         board.set_full_move_number(1);
-        board.set_side_to_move(Side::White);
+        board.set_side_to_move(PlayerSide::White);
         board.set_castling_rights(CastlingAvailability::ALL);
-        board.set_zobrist_hash(board.initialize_zobrist_hash());
+        board.set_zobrist_hash(board.compute_zobrist_hash());
         board
     }
 
@@ -263,6 +223,33 @@ impl Board {
 
     /// Convert the board to a FEN string.
     pub fn to_fen(&self) -> String {
+        let mut fen = self.fen_without_counters();
+        fen.push(SPACE);
+        // Halfmove clock
+        fen.push_str(&fen::halfmove_clock_to_fen(self));
+        fen.push(SPACE);
+        // Fullmove number
+        fen.push_str(&fen::fullmove_number_to_fen(self));
+
+        fen
+    }
+
+    /// Convert the board to a FEN string without the halfmove clock and fullmove number, i.e. only
+    /// the piece placement, side to move, castling and en passant fields. Two boards with the same
+    /// position have the same result regardless of how many moves were played.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::board::Board;
+    ///
+    /// let board = Board::default_board();
+    /// assert_eq!(
+    ///     board.fen_without_counters(),
+    ///     "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq -"
+    /// );
+    /// ```
+    pub fn fen_without_counters(&self) -> String {
         let mut fen = String::new();
         // Piece placement
         fen.push_str(&fen::piece_placement_to_fen(self));
@@ -275,12 +262,6 @@ impl Board {
         fen.push(SPACE);
         // En passant target square
         fen.push_str(&fen::en_passant_target_square_to_fen(self));
-        fen.push(SPACE);
-        // Halfmove clock
-        fen.push_str(&fen::halfmove_clock_to_fen(self));
-        fen.push(SPACE);
-        // Fullmove number
-        fen.push_str(&fen::fullmove_number_to_fen(self));
 
         fen
     }
@@ -298,7 +279,8 @@ impl Board {
     }
 
     /// Returns all the pieces of a given side in a single [`Bitboard`].
-    pub fn pieces(&self, side: Side) -> Bitboard {
+    pub fn pieces(&self, side: impl Into<Side>) -> Bitboard {
+        let side = side.into();
         let mut pieces = Bitboard::default();
         for piece_type in 0..NumberOf::PIECE_TYPES {
             pieces |= self.piece_bitboards[side as usize][piece_type];
@@ -317,13 +299,14 @@ impl Board {
     }
 
     /// Returns the bitboard for a specific piece and side.
-    pub fn piece_bitboard(&self, piece: Piece, side: Side) -> &Bitboard {
+    pub fn piece_bitboard(&self, piece: Piece, side: impl Into<Side>) -> &Bitboard {
+        let side = side.into();
         debug_assert!(side != Side::Both);
         &self.piece_bitboards[side as usize][piece as usize]
     }
 
     /// Returns the current square of the king for a given side.
-    pub fn king_square(&self, side: Side) -> u8 {
+    pub fn king_square(&self, side: impl Into<Side>) -> u8 {
         let king_bb = self.piece_bitboard(Piece::King, side);
         bitboard_helpers::next_bit(&mut king_bb.clone()) as u8
     }
@@ -336,26 +319,152 @@ impl Board {
     ///
     /// # Returns
     ///
-    /// - Optional tuple of the piece and the side that the piece belongs to. (Piece, Side)
-    pub fn piece_on_square(&self, square: u8) -> Option<(Piece, Side)> {
+    /// - Optional tuple of the piece and the side that the piece belongs to. (Piece, PlayerSide)
+    pub fn piece_on_square(&self, square: u8) -> Option<(Piece, PlayerSide)> {
         for piece in 0..NumberOf::PIECE_TYPES {
-            for side in 0..NumberOf::SIDES {
-                if self.piece_bitboards[side][piece].is_square_occupied(square) {
-                    return Some((
-                        Piece::try_from(piece as u8).unwrap(),
-                        Side::try_from(side as u8).unwrap(),
-                    ));
+            for side in [PlayerSide::White, PlayerSide::Black] {
+                if self.piece_bitboards[side as usize][piece].is_square_occupied(square) {
+                    return Some((Piece::try_from(piece as u8).unwrap(), side));
                 }
             }
         }
         None
     }
 
+    /// Returns every piece on the board together with its square and side, from a1 to h8.
+    ///
+    /// # Returns
+    ///
+    /// - An iterator over `(square, piece, side)` tuples, one per occupied square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::{board::Board, pieces::Piece, side::PlayerSide, square::Square};
+    ///
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+    /// let pieces: Vec<_> = board.pieces_iter().collect();
+    /// assert_eq!(
+    ///     pieces,
+    ///     vec![
+    ///         (Square::try_from("e1").unwrap(), Piece::King, PlayerSide::White),
+    ///         (Square::try_from("e8").unwrap(), Piece::King, PlayerSide::Black),
+    ///     ]
+    /// );
+    /// ```
+    pub fn pieces_iter(&self) -> impl Iterator<Item = (Square, Piece, PlayerSide)> + '_ {
+        let mut occupancy = self.all_pieces();
+        std::iter::from_fn(move || occupancy.pop_lsb()).map(|square| {
+            // the square comes from the occupancy, so there is always a piece on it
+            let (piece, side) = self.piece_on_square(square).unwrap();
+            (Square::from_square_index(square), piece, side)
+        })
+    }
+
+    /// Takes a snapshot of the current position that can later be restored with
+    /// [`Board::restore`], see [`BoardSnapshot`] for what it captures.
+    pub fn snapshot(&self) -> BoardSnapshot {
+        BoardSnapshot {
+            piece_bitboards: self.piece_bitboards,
+            state: self.state,
+            history_len: self.history.len(),
+        }
+    }
+
+    /// Restores the position of a snapshot taken with [`Board::snapshot`]. This is cheaper than
+    /// unmaking every move played since the snapshot was taken.
+    ///
+    /// Moves made after the snapshot are dropped from the move history. The history before the
+    /// snapshot is kept, so the snapshot must have been taken on this board and no moves from
+    /// before it may have been unmade since.
+    ///
+    /// # Arguments
+    ///
+    /// - `snapshot` - The snapshot to restore.
+    pub fn restore(&mut self, snapshot: &BoardSnapshot) {
+        debug_assert!(self.history.len() >= snapshot.history_len);
+        self.piece_bitboards = snapshot.piece_bitboards;
+        self.state = snapshot.state;
+        self.history.truncate(snapshot.history_len);
+    }
+
     /// Returns the side to move of this [`Board`].
-    pub fn side_to_move(&self) -> Side {
+    pub fn side_to_move(&self) -> PlayerSide {
         self.state.side_to_move
     }
 
+    /// Flips the side to move of this [`Board`], updating only the side to move component of the
+    /// zobrist hash. Unlike [`Board::null_move`], this does not record any history, so it is meant
+    /// for analyzing the position from the other side's perspective.
+    pub fn flip_side_to_move(&mut self) {
+        self.set_side_to_move(PlayerSide::opposite(self.side_to_move()));
+    }
+
+    /// Sets the side to move and updates the zobrist hash.
+    pub fn set_side_to_move(&mut self, side: PlayerSide) {
+        // undo the current side to move in the hash
+        self.state.zobrist_hash ^= self.zobrist_values.side_key(self.state.side_to_move);
+        // set the new side to move
+        self.state.side_to_move = side;
+        // update zobrist hash with the new side to move
+        self.state.zobrist_hash ^= self.zobrist_values.side_key(self.state.side_to_move);
+    }
+
+    /// Places a piece on a square, replacing any piece that is already there. The bitboards and the
+    /// zobrist hash are updated incrementally.
+    ///
+    /// This is meant for editing positions, so castling rights and the en passant square are not
+    /// adjusted and no move history is recorded.
+    ///
+    /// # Arguments
+    ///
+    /// - `square` - The square to place the piece on.
+    /// - `piece` - The piece to place.
+    /// - `side` - The side the piece belongs to.
+    ///
+    /// # Returns
+    ///
+    /// - The piece and side that was replaced, if the square was occupied.
+    pub fn set_piece(
+        &mut self,
+        square: u8,
+        piece: Piece,
+        side: PlayerSide,
+    ) -> Option<(Piece, PlayerSide)> {
+        let replaced = self.remove_piece(square);
+        self.mut_piece_bitboard(piece, side).set_square(square);
+        self.update_zobrist_hash_for_piece(square, piece, side);
+        replaced
+    }
+
+    /// Removes the piece on a square, if any. The bitboards and the zobrist hash are updated
+    /// incrementally.
+    ///
+    /// # Arguments
+    ///
+    /// - `square` - The square to clear.
+    ///
+    /// # Returns
+    ///
+    /// - The piece and side that was removed, or `None` if the square was empty.
+    pub fn remove_piece(&mut self, square: u8) -> Option<(Piece, PlayerSide)> {
+        debug_assert!((square as usize) < NumberOf::SQUARES);
+        let (piece, side) = self.piece_on_square(square)?;
+        self.mut_piece_bitboard(piece, side).clear_square(square);
+        self.update_zobrist_hash_for_piece(square, piece, side);
+        Some((piece, side))
+    }
+
+    /// Checks if two boards have the same position, i.e. the same piece placement, side to move,
+    /// castling rights and en passant square. These are the fields that make up the zobrist hash,
+    /// so the move counters are ignored.
+    pub fn same_position(&self, other: &Board) -> bool {
+        self.piece_bitboards == other.piece_bitboards
+            && self.side_to_move() == other.side_to_move()
+            && self.castling_rights() == other.castling_rights()
+            && self.en_passant_square() == other.en_passant_square()
+    }
+
     /// Returns the en passant square of this [`Board`] (if it exists)
     pub fn en_passant_square(&self) -> Option<u8> {
         self.state.en_passant_square
@@ -381,6 +490,54 @@ impl Board {
         self.state.zobrist_hash
     }
 
+    /// Computes the Zobrist hash of this [`Board`] from scratch. The hash only depends on the piece
+    /// placement, the side to move, the castling rights and the en passant square, not on the move
+    /// counters or the history. [`Board::zobrist_hash`] is updated incrementally by making moves and
+    /// always has to be equal to this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::board::Board;
+    ///
+    /// let mut board = Board::default_board();
+    /// board.make_uci_move("e2e4").unwrap();
+    /// assert_eq!(board.zobrist_hash(), board.compute_zobrist_hash());
+    /// ```
+    pub fn compute_zobrist_hash(&self) -> ZobristHash {
+        // create the initial zobrist hash based on the starting position
+        // for each piece on the board, get the corresponding zobrist value and xor it with the hash
+        // for each side to move, xor the hash with the zobrist value for the side
+        // for each castling right, xor the hash with the zobrist value for the castling right
+        // for the en passant square, xor the hash with the zobrist value for the en passant square
+        // Initialize the zobrist hash to 0
+        let mut zobrist_hash = ZobristHash::default();
+
+        // XOR the zobrist values for each piece on the board
+        for side in [PlayerSide::White, PlayerSide::Black] {
+            for piece in ALL_PIECES {
+                let mut bitboard = *self.piece_bitboard(piece, side);
+
+                while let Some(square) = bitboard.pop_lsb() {
+                    zobrist_hash ^= self.zobrist_values.piece_key(piece, side, square);
+                }
+            }
+        }
+
+        // XOR the zobrist value for the side to move
+        zobrist_hash ^= self.zobrist_values.side_key(self.side_to_move());
+
+        // XOR the zobrist values for castling rights
+        zobrist_hash ^= self.zobrist_values.castling_key(self.castling_rights());
+
+        // XOR the zobrist value for the en passant square, if any
+        zobrist_hash ^= self
+            .zobrist_values
+            .en_passant_key(self.state.en_passant_square);
+
+        zobrist_hash
+    }
+
     /// Checks if a given square is empty.
     pub fn is_square_empty(&self, square: &Square) -> bool {
         !self
@@ -397,12 +554,11 @@ impl Board {
     /// # Returns
     ///
     /// - `true` if the side has kingside castling rights, otherwise `false`.
-    pub fn can_castle_kingside(&self, side: Side) -> bool {
+    pub fn can_castle_kingside(&self, side: PlayerSide) -> bool {
         let castling_rights = self.castling_rights();
         match side {
-            Side::White => castling_rights & CastlingAvailability::WHITE_KINGSIDE != 0,
-            Side::Black => castling_rights & CastlingAvailability::BLACK_KINGSIDE != 0,
-            Side::Both => panic!("Cannot check if both sides can castle kingside"),
+            PlayerSide::White => castling_rights & CastlingAvailability::WHITE_KINGSIDE != 0,
+            PlayerSide::Black => castling_rights & CastlingAvailability::BLACK_KINGSIDE != 0,
         }
     }
 
@@ -415,12 +571,11 @@ impl Board {
     /// # Returns
     ///
     /// - `true` if the side has queenside castling rights, otherwise `false`.
-    pub fn can_castle_queenside(&self, side: Side) -> bool {
+    pub fn can_castle_queenside(&self, side: PlayerSide) -> bool {
         let castling_rights = self.castling_rights();
         match side {
-            Side::White => castling_rights & CastlingAvailability::WHITE_QUEENSIDE != 0,
-            Side::Black => castling_rights & CastlingAvailability::BLACK_QUEENSIDE != 0,
-            Side::Both => panic!("Cannot check if both sides can castle queenside"),
+            PlayerSide::White => castling_rights & CastlingAvailability::WHITE_QUEENSIDE != 0,
+            PlayerSide::Black => castling_rights & CastlingAvailability::BLACK_QUEENSIDE != 0,
         }
     }
 
@@ -442,10 +597,34 @@ impl Board {
         move_gen.is_square_attacked(
             self,
             &Square::from_square_index(king_square),
-            Side::opposite(self.side_to_move()),
+            PlayerSide::opposite(self.side_to_move()),
         )
     }
 
+    /// Returns the number of pieces giving check to the side to move.
+    ///
+    /// # Arguments
+    ///
+    /// - `move_gen` - The move generator to use for generating attacks.
+    ///
+    /// # Returns
+    ///
+    /// - `0` if the side to move is not in check, `2` for a double check.
+    pub fn checkers_count(&self, move_gen: &MoveGenerator) -> u32 {
+        move_gen.in_check_info(self).1
+    }
+
+    /// Returns all squares attacked by `side`, including squares occupied by its own pieces. See
+    /// [`MoveGenerator::attacks_by`].
+    ///
+    /// # Arguments
+    ///
+    /// - `side` - The side to get the attacks for.
+    /// - `move_gen` - The move generator to use for generating attacks.
+    pub fn attacks_by(&self, side: PlayerSide, move_gen: &MoveGenerator) -> Bitboard {
+        move_gen.attacks_by(self, side, &self.all_pieces())
+    }
+
     /// Check if the side to move is in checkmate.
     pub fn is_checkmate(&self, move_gen: &MoveGenerator) -> bool {
         // if the side to move is not in check, it's not checkmate
@@ -475,7 +654,7 @@ impl Board {
             if move_gen.is_square_attacked_with_occupancy(
                 self,
                 &Square::from_square_index(square as u8),
-                Side::opposite(self.side_to_move()),
+                PlayerSide::opposite(self.side_to_move()),
                 &occupancy,
             ) {
                 return true;
@@ -486,19 +665,58 @@ impl Board {
 
     /// Get the color of the piece on a given square.
     ///
-    /// Returns `Some(Side)` if the square is occupied, otherwise `None`.
-    pub fn color_on(&self, square: u8) -> Option<Side> {
+    /// Returns `Some(PlayerSide)` if the square is occupied, otherwise `None`.
+    pub fn color_on(&self, square: u8) -> Option<PlayerSide> {
         let white_pieces = self.white_pieces();
         let black_pieces = self.black_pieces();
         if white_pieces.is_square_occupied(square) {
-            Some(Side::White)
+            Some(PlayerSide::White)
         } else if black_pieces.is_square_occupied(square) {
-            Some(Side::Black)
+            Some(PlayerSide::Black)
         } else {
             None
         }
     }
 
+    /// Checks if `mv` captures a piece on this board, including en passant captures. Unlike
+    /// [`Move::is_capture`], this looks at the board instead of the capture encoded in the move, so
+    /// it also works for moves that were built without it.
+    ///
+    /// # Arguments
+    ///
+    /// - `mv` - The move to check, which has to be a move for the side to move.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::{board::Board, move_generation::MoveGenerator};
+    ///
+    /// let board = Board::from_fen("4k3/8/8/3p4/4P3/8/8/4K3 w - - 0 1").unwrap();
+    /// let moves = MoveGenerator::new().generate_legal_moves_vec(&board);
+    /// let capture = moves.iter().find(|mv| mv.to_long_algebraic() == "e4d5").unwrap();
+    /// let push = moves.iter().find(|mv| mv.to_long_algebraic() == "e4e5").unwrap();
+    /// assert!(board.is_capture(capture));
+    /// assert!(board.is_quiet(push));
+    /// ```
+    pub fn is_capture(&self, mv: &Move) -> bool {
+        let en_passant = mv.piece() == Piece::Pawn && self.en_passant_square() == Some(mv.to());
+        en_passant
+            || mv.is_en_passant_capture()
+            || self.color_on(mv.to()) == Some(PlayerSide::opposite(self.side_to_move()))
+    }
+
+    /// Checks if `mv` is quiet on this board, meaning it is neither a capture nor a promotion. See
+    /// [`Board::is_capture`].
+    pub fn is_quiet(&self, mv: &Move) -> bool {
+        !self.is_tactical(mv)
+    }
+
+    /// Checks if `mv` is tactical on this board, meaning it is a capture or a promotion. See
+    /// [`Board::is_capture`].
+    pub fn is_tactical(&self, mv: &Move) -> bool {
+        mv.is_promotion() || self.is_capture(mv)
+    }
+
     /// Checks for draws for the current [`Board`].
     ///
     /// This function checks for:
@@ -583,13 +801,57 @@ impl Board {
         repetition_count >= 2
     }
 
-    /// Check if a given move is legal. This function does not alter the current board state.
-    /// Instead it makes a copy of the current state and tries to make the move. There is a performance
-    /// penalty for this, so use this function sparingly.
+    /// Check if the current position repeats a position from the last `plies` plies, e.g. any
+    /// position since the root of a search. Unlike [`Board::is_repetition`], a single repetition is
+    /// enough. A search can score this as a draw, because the side that repeated can force the
+    /// repetition again, as with a perpetual check.
+    pub fn is_repetition_within(&self, plies: usize) -> bool {
+        for previous_state in self.history.iter().rev().take(plies) {
+            if previous_state.zobrist_hash == self.zobrist_hash() {
+                return true;
+            }
+
+            // irreversible move, older positions can't be repeated
+            if previous_state.half_move_clock == 0 {
+                return false;
+            }
+        }
+
+        false
+    }
+
+    /// Check if a given move is pseudo-legal, i.e. the move generator could produce it for the
+    /// current position, ignoring whether it leaves the king in check. Use this to validate moves
+    /// that may come from a different position, such as transposition table moves after a hash
+    /// collision.
+    ///
+    /// # Arguments
+    ///
+    /// - `mv` - The move to check.
+    /// - `move_gen` - The move generator to use for generating moves.
+    ///
+    /// # Returns
+    ///
+    /// - `true` if the move is pseudo-legal, otherwise `false`.
+    pub fn is_pseudo_legal(&self, mv: &Move, move_gen: &MoveGenerator) -> bool {
+        if mv.is_null() {
+            return false;
+        }
+
+        // cheap check first, the moving piece of the side to move has to be on the from square
+        if self.piece_on_square(mv.from()) != Some((mv.piece(), self.side_to_move())) {
+            return false;
+        }
+
+        let mut move_list = MoveList::new();
+        move_gen.generate_moves(self, &mut move_list, MoveType::All);
+        move_list.contains(mv)
+    }
+
+    /// Check if a given move is legal, see [`MoveGenerator::is_legal`]. This function does not alter
+    /// the current board state.
     pub fn is_legal(&self, mv: &Move, move_gen: &MoveGenerator) -> bool {
-        // check if a move is legal without altering the current board state
-        let mut board_copy = self.clone();
-        board_copy.make_move(mv, move_gen).is_ok()
+        move_gen.is_legal(self, mv)
     }
 
     /// Check if a list of moves are legal. This function does not alter the current board state.
@@ -619,6 +881,149 @@ mod tests {
     };
 
     use super::*;
+    #[test]
+    fn restore_snapshot_after_moves() {
+        let move_gen = MoveGenerator::new();
+        let mut board = Board::default_board();
+        board.make_uci_move("e2e4").unwrap();
+        let original = board.clone();
+        let snapshot = board.snapshot();
+
+        // explore two different variations from the same snapshot
+        for variation in [["e7e5", "g1f3", "b8c6"], ["d7d6", "e1e2", "e8d7"]] {
+            for mv in variation {
+                board.make_uci_move(mv).unwrap();
+            }
+            assert_ne!(board.zobrist_hash(), original.zobrist_hash());
+
+            board.restore(&snapshot);
+            assert_eq!(board.zobrist_hash(), original.zobrist_hash());
+            assert_eq!(board.zobrist_hash(), board.compute_zobrist_hash());
+            assert!(board == original);
+            assert_eq!(board.castling_rights(), original.castling_rights());
+            assert_eq!(board.en_passant_square(), original.en_passant_square());
+        }
+
+        // the history before the snapshot is still there
+        board.unmake_move().unwrap();
+        assert!(board == Board::default_board());
+        assert!(board.is_legal(&move_gen.generate_legal_moves_vec(&board)[0], &move_gen));
+    }
+
+    #[test]
+    fn pieces_iter_start_position() {
+        let board = Board::default_board();
+        let pieces: Vec<_> = board.pieces_iter().collect();
+        assert_eq!(pieces.len(), 32);
+
+        for (piece, count) in [
+            (Piece::Pawn, 8),
+            (Piece::Knight, 2),
+            (Piece::Bishop, 2),
+            (Piece::Rook, 2),
+            (Piece::Queen, 1),
+            (Piece::King, 1),
+        ] {
+            for side in [PlayerSide::White, PlayerSide::Black] {
+                let found = pieces
+                    .iter()
+                    .filter(|(_, p, s)| *p == piece && *s == side)
+                    .count();
+                assert_eq!(found, count, "{:?} {:?}", piece, side);
+            }
+        }
+
+        for (square, piece, side) in pieces {
+            assert_eq!(
+                board.piece_on_square(square.to_square_index()),
+                Some((piece, side))
+            );
+        }
+        assert_eq!(
+            board.pieces_iter().next(),
+            Some((
+                Square::from_square_index(Squares::A1),
+                Piece::Rook,
+                PlayerSide::White
+            ))
+        );
+    }
+
+    #[test]
+    fn flip_side_to_move() {
+        let mut board =
+            Board::from_fen("rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2")
+                .unwrap();
+        let hash = board.zobrist_hash();
+        let en_passant = board.en_passant_square();
+
+        board.flip_side_to_move();
+        assert_eq!(board.side_to_move(), Side::Black);
+        assert_ne!(board.zobrist_hash(), hash);
+        assert_eq!(board.en_passant_square(), en_passant);
+
+        board.flip_side_to_move();
+        assert_eq!(board.side_to_move(), Side::White);
+        assert_eq!(board.zobrist_hash(), hash);
+    }
+
+    #[test]
+    fn fen_without_counters_matches_to_fen() {
+        for fen in [
+            DEFAULT_FEN,
+            "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 37 90",
+        ] {
+            let board = Board::from_fen(fen).unwrap();
+            let full_fen = board.to_fen();
+            let fields = full_fen.split_whitespace().take(4).collect::<Vec<_>>();
+            assert_eq!(board.fen_without_counters(), fields.join(" "));
+        }
+    }
+
+    #[test]
+    fn pseudo_legal_moves() {
+        let move_gen = MoveGenerator::new();
+        // the e2 knight is pinned by the rook on e8
+        let board = Board::from_fen("4r1k1/8/8/8/8/8/4N3/4K3 w - - 0 1").unwrap();
+        let mv = |from: u8, to: u8, piece: Piece, captured: Option<Piece>| {
+            Move::new(
+                &Square::from_square_index(from),
+                &Square::from_square_index(to),
+                MoveDescriptor::None,
+                piece,
+                captured,
+                None,
+            )
+        };
+
+        // pinned, so pseudo-legal but not legal
+        let pinned_knight = mv(Squares::E2, Squares::C3, Piece::Knight, None);
+        assert!(board.is_pseudo_legal(&pinned_knight, &move_gen));
+        assert!(!board.is_legal(&pinned_knight, &move_gen));
+        assert!(board.is_pseudo_legal(&mv(Squares::E1, Squares::D1, Piece::King, None), &move_gen));
+
+        // wrong piece, wrong side, impossible shape, wrong capture and the null move
+        assert!(!board.is_pseudo_legal(
+            &mv(Squares::E2, Squares::C3, Piece::Bishop, None),
+            &move_gen
+        ));
+        assert!(!board.is_pseudo_legal(
+            &mv(Squares::E8, Squares::E2, Piece::Rook, Some(Piece::Knight)),
+            &move_gen
+        ));
+        assert!(!board.is_pseudo_legal(
+            &mv(Squares::E2, Squares::E4, Piece::Knight, None),
+            &move_gen
+        ));
+        assert!(!board.is_pseudo_legal(
+            &mv(Squares::E2, Squares::C3, Piece::Knight, Some(Piece::Pawn)),
+            &move_gen
+        ));
+        assert!(!board.is_pseudo_legal(&Move::NULL, &move_gen));
+    }
+
     #[test]
     fn threefold_repetition_detection() {
         let mut board = Board::from_fen("k7/8/KQ6/8/8/8/8/8 w - - 0 1").unwrap();
@@ -665,11 +1070,16 @@ mod tests {
             None,
         );
 
-        for _i in 0..2 {
+        for i in 0..2 {
             assert!(board.make_move_unchecked(&white_queen_move).is_ok());
             assert!(board.make_move_unchecked(&black_king_move).is_ok());
             assert!(board.make_move_unchecked(&while_queen_reverse_move).is_ok());
+            assert!(!board.is_repetition_within(2));
             assert!(board.make_move_unchecked(&black_king_reverse_move).is_ok());
+            // the starting position was repeated once, four plies ago
+            assert!(board.is_repetition_within(4));
+            assert!(!board.is_repetition_within(3));
+            assert_eq!(board.is_repetition(), i == 1);
         }
 
         assert!(board.is_repetition());
@@ -776,10 +1186,7 @@ mod tests {
             Board::from_fen("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8").unwrap();
         move_gen.generate_moves(&board, &mut move_list, MoveType::All);
 
-        let initial_mv = *move_list
-            .iter()
-            .find(|mv| mv.to_long_algebraic() == "d7c8q")
-            .unwrap();
+        let initial_mv = *move_list.find_uci("d7c8q").unwrap();
 
         let mut queen_bb = *board.piece_bitboard(Piece::Queen, Side::White);
         assert_eq!(queen_bb.number_of_occupied_squares(), 1);
@@ -810,15 +1217,9 @@ mod tests {
             Board::from_fen("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8").unwrap();
         move_gen.generate_moves(&board, &mut move_list, MoveType::All);
 
-        let initial_mv = *move_list
-            .iter()
-            .find(|mv| mv.to_long_algebraic() == "d7c8q")
-            .unwrap();
+        let initial_mv = *move_list.find_uci("d7c8q").unwrap();
 
-        let next_move = *move_list
-            .iter()
-            .find(|mv| mv.to_long_algebraic() == "d7c8r")
-            .unwrap();
+        let next_move = *move_list.find_uci("d7c8r").unwrap();
 
         let mut mv_ok = board.make_move(&initial_mv, &move_gen);
         assert!(mv_ok.is_ok());
@@ -888,14 +1289,8 @@ mod tests {
 
         move_gen.generate_moves(&board, &mut move_list, MoveType::All);
 
-        let first_mv = move_list
-            .iter()
-            .find(|mv| mv.to_long_algebraic() == "b1d2")
-            .unwrap();
-        let second_mv = move_list
-            .iter()
-            .find(|mv| mv.to_long_algebraic() == "b1a3")
-            .unwrap();
+        let first_mv = move_list.find_uci("b1d2").unwrap();
+        let second_mv = move_list.find_uci("b1a3").unwrap();
 
         println!("{}\n{}", board.to_fen(), board.board_state());
         let mut mv_ok = board.make_move(first_mv, &move_gen);
@@ -930,4 +1325,147 @@ mod tests {
             assert_eq!(fen, board.to_fen());
         }
     }
+
+    #[test]
+    fn editing_pieces_matches_fen() {
+        let mut board = Board::default_board();
+        // 1. e4 without making a move, then replace the knight on g8 with a queen
+        assert_eq!(
+            board.remove_piece(Squares::E2),
+            Some((Piece::Pawn, PlayerSide::White))
+        );
+        assert_eq!(
+            board.set_piece(Squares::E4, Piece::Pawn, PlayerSide::White),
+            None
+        );
+        assert_eq!(
+            board.set_piece(Squares::G8, Piece::Queen, PlayerSide::Black),
+            Some((Piece::Knight, PlayerSide::Black))
+        );
+        board.set_side_to_move(PlayerSide::Black);
+        assert_eq!(board.remove_piece(Squares::E3), None);
+
+        let expected =
+            Board::from_fen("rnbqkbqr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1").unwrap();
+        assert_eq!(board.zobrist_hash(), expected.zobrist_hash());
+        assert_eq!(board.all_pieces(), expected.all_pieces());
+        assert_eq!(board.to_fen(), expected.to_fen());
+        assert_eq!(board.zobrist_hash(), board.compute_zobrist_hash());
+    }
+
+    #[test]
+    fn fen_hashes_match_computed_hashes() {
+        let move_gen = MoveGenerator::new();
+        let path = format!("{}/../data/standard.epd", env!("CARGO_MANIFEST_DIR"));
+        let epd = std::fs::read_to_string(path).unwrap();
+        for fen in epd
+            .lines()
+            .map(|line| line.split(';').next().unwrap().trim())
+        {
+            let mut board = Board::from_fen(fen).unwrap();
+            assert_eq!(
+                board.zobrist_hash(),
+                Board::from_fen(fen).unwrap().compute_zobrist_hash(),
+                "{}",
+                fen
+            );
+
+            // the incremental updates of every legal move agree with the computed hash too
+            let mut move_list = MoveList::new();
+            move_gen.generate_legal_moves(&board, &mut move_list);
+            for mv in move_list.iter() {
+                board.make_move_unchecked(mv).unwrap();
+                assert_eq!(
+                    board.zobrist_hash(),
+                    board.compute_zobrist_hash(),
+                    "{} {}",
+                    fen,
+                    mv.to_long_algebraic()
+                );
+                board.unmake_move().unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn same_position_ignores_move_counters() {
+        let board = Board::default_board();
+        let later =
+            Board::from_fen("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 8 5").unwrap();
+        assert!(board.same_position(&later));
+        assert!(board != later);
+        assert!(board == Board::from_fen(DEFAULT_FEN).unwrap());
+
+        // the zobrist relevant fields are compared
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR b KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w Kkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq e3 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKB1R w KQkq - 0 1",
+        ] {
+            let other = Board::from_fen(fen).unwrap();
+            assert!(!board.same_position(&other), "{}", fen);
+            assert!(board != other);
+        }
+    }
+
+    #[test]
+    fn classifies_captures_quiets_and_tactical_moves() {
+        let move_gen = MoveGenerator::new();
+        // white can promote on b8, take on a8 with promotion, take en passant on d6 or push e5-e6
+        let board = Board::from_fen("r3k3/1P6/8/3pP3/8/8/8/4K3 w - d6 0 1").unwrap();
+        let moves = move_gen.generate_legal_moves_vec(&board);
+        let find = |uci: &str| {
+            *moves
+                .iter()
+                .find(|mv| mv.to_long_algebraic() == uci)
+                .unwrap()
+        };
+
+        // (move, capture, quiet, tactical)
+        for (uci, capture, quiet, tactical) in [
+            ("b7a8q", true, false, true),
+            ("b7b8n", false, false, true),
+            ("e5d6", true, false, true),
+            ("e5e6", false, true, false),
+        ] {
+            let mv = find(uci);
+            assert_eq!(board.is_capture(&mv), capture, "{}", uci);
+            assert_eq!(board.is_quiet(&mv), quiet, "{}", uci);
+            assert_eq!(board.is_tactical(&mv), tactical, "{}", uci);
+            // the board agrees with the capture encoded in the move
+            assert_eq!(mv.is_capture(), capture, "{}", uci);
+            assert_eq!(mv.is_quiet(), quiet, "{}", uci);
+        }
+
+        // a capture without the captured piece encoded is still a capture on the board
+        let bare = Move::new(
+            &Square::try_from("b7").unwrap(),
+            &Square::try_from("a8").unwrap(),
+            MoveDescriptor::None,
+            Piece::Pawn,
+            None,
+            None,
+        );
+        assert!(!bare.is_capture());
+        assert!(board.is_capture(&bare));
+        assert!(!board.is_quiet(&bare));
+    }
+
+    #[test]
+    fn from_fen_rejects_malformed_strings() {
+        for fen in [
+            "",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNRR w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/9/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR/8 w KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR x KQkq - 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq z9 0 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - x 1",
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 y",
+        ] {
+            assert!(Board::from_fen(fen).is_err(), "{}", fen);
+        }
+    }
 }
@@ -12,7 +12,13 @@
  *
  */
 
-use crate::{definitions::CastlingAvailability, moves::Move, side::Side, zobrist::ZobristHash};
+use crate::{
+    bitboard::Bitboard,
+    definitions::{CastlingAvailability, NumberOf},
+    moves::Move,
+    side::PlayerSide,
+    zobrist::ZobristHash,
+};
 use std::fmt::Display;
 
 /// Represents the state of the board at a given point in time.
@@ -24,7 +30,7 @@ use std::fmt::Display;
 pub struct BoardState {
     pub half_move_clock: u32,
     pub full_move_number: u32,
-    pub side_to_move: Side,
+    pub side_to_move: PlayerSide,
     pub en_passant_square: Option<u8>,
     pub castling_rights: u8,
     pub zobrist_hash: ZobristHash,
@@ -42,11 +48,11 @@ impl BoardState {
         BoardState {
             half_move_clock: 0,
             full_move_number: 1,
-            side_to_move: Side::White,
+            side_to_move: PlayerSide::White,
             en_passant_square: None,
             castling_rights: CastlingAvailability::NONE,
             zobrist_hash: 0,
-            next_move: Move::default(),
+            next_move: Move::NULL,
         }
     }
 }
@@ -66,3 +72,18 @@ impl Display for BoardState {
         )
     }
 }
+
+/// A snapshot of a [`Board`](crate::board::Board) position, taken with
+/// [`Board::snapshot`](crate::board::Board::snapshot) and restored with
+/// [`Board::restore`](crate::board::Board::restore).
+///
+/// A snapshot captures the piece placement, the [`BoardState`] (side to move, castling rights,
+/// en passant square, clocks and Zobrist hash) and the length of the move history. It is a plain
+/// copy of these, so taking one is cheap and the same snapshot can be restored any number of
+/// times to explore several variations from the same position.
+#[derive(Debug, Clone, Copy)]
+pub struct BoardSnapshot {
+    pub(crate) piece_bitboards: [[Bitboard; NumberOf::PIECE_TYPES]; NumberOf::SIDES],
+    pub(crate) state: BoardState,
+    pub(crate) history_len: usize,
+}
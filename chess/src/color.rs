@@ -4,7 +4,7 @@
  * Created Date: Monday, November 25th 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Tue Nov 26 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -12,8 +12,86 @@
  *
  */
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use crate::side::{PlayerSide, Side};
+
+/// The color of a square on the board, see [`Square::color`](crate::square::Square::color).
+/// Light squares are [`Color::White`] and dark squares are [`Color::Black`].
+///
+/// This is not a player: use [`PlayerSide`] for the side to move and the owner of a piece, and
+/// [`Side`] where both sides have to be representable too. The conversions to and from the sides
+/// map the variants by name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Color {
     White,
     Black,
 }
+
+impl Color {
+    /// Returns the opposite color.
+    pub fn opposite(color: Color) -> Color {
+        match color {
+            Color::White => Color::Black,
+            Color::Black => Color::White,
+        }
+    }
+}
+
+impl From<PlayerSide> for Color {
+    fn from(side: PlayerSide) -> Self {
+        match side {
+            PlayerSide::White => Color::White,
+            PlayerSide::Black => Color::Black,
+        }
+    }
+}
+
+impl From<Color> for PlayerSide {
+    fn from(color: Color) -> Self {
+        match color {
+            Color::White => PlayerSide::White,
+            Color::Black => PlayerSide::Black,
+        }
+    }
+}
+
+impl From<Color> for Side {
+    fn from(color: Color) -> Self {
+        PlayerSide::from(color).into()
+    }
+}
+
+impl TryFrom<Side> for Color {
+    type Error = ();
+
+    fn try_from(side: Side) -> Result<Self, Self::Error> {
+        PlayerSide::try_from(side).map(Color::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Color;
+    use crate::{
+        side::{PlayerSide, Side},
+        square::Square,
+    };
+
+    #[test]
+    fn color_conversions() {
+        for color in [Color::White, Color::Black] {
+            let side = PlayerSide::from(color);
+            assert_eq!(Color::from(side), color);
+            assert_eq!(Color::try_from(Side::from(color)), Ok(color));
+            assert_eq!(
+                Color::from(PlayerSide::opposite(side)),
+                Color::opposite(color)
+            );
+        }
+        assert_ne!(Color::opposite(Color::White), Color::White);
+        assert!(Color::try_from(Side::Both).is_err());
+
+        // a1 is a dark square, h1 a light one
+        assert_eq!(Square::from_square_index(0).color(), Color::Black);
+        assert_eq!(Square::from_square_index(7).color(), Color::White);
+    }
+}
@@ -4,7 +4,7 @@
  * Created Date: Wednesday, August 21st 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Tue Nov 26 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -18,8 +18,20 @@ pub const DASH: char = '-';
 pub const EM_DASH: char = '–';
 pub const SLASH: char = '/';
 
-/// max number of moves in a game from this pos R6R/3Q4/1Q4Q1/4Q3/2Q4Q/Q4Q2/pp1Q4/kBNN1KB1 w - - 0 1
-pub const MAX_MOVE_LIST_SIZE: usize = 218;
+/// max number of legal moves in a game from this pos R6R/3Q4/1Q4Q1/4Q3/2Q4Q/Q4Q2/pp1Q4/kBNN1KB1 w - - 0 1
+pub const MAX_LEGAL_MOVES: usize = 218;
+/// Upper bound on the number of pseudo-legal moves of one side. Every piece is counted with its
+/// mobility on an empty board from its best square: the king has 8 moves plus 2 castling moves, a
+/// queen 27, a rook 14, a bishop 13 and a knight 8. A pawn has at most 3 target squares with 4
+/// promotions each, so 12 moves, which is less than it has after promoting to a queen. That gives
+/// 8 + 2 + 27 + 2 * 14 + 2 * 13 + 2 * 8 + 8 * 27 = 323.
+pub const MAX_PSEUDO_LEGAL_MOVES: usize = 8 + 2 + 27 + 2 * 14 + 2 * 13 + 2 * 8 + 8 * 27;
+/// Capacity of a [`MoveList`](crate::move_list::MoveList). Move generation also fills move lists
+/// with pseudo-legal moves, so this has to hold [`MAX_PSEUDO_LEGAL_MOVES`], not just
+/// [`MAX_LEGAL_MOVES`].
+pub const MAX_MOVE_LIST_SIZE: usize = 323;
+const _: () = assert!(MAX_MOVE_LIST_SIZE >= MAX_PSEUDO_LEGAL_MOVES);
+const _: () = assert!(MAX_PSEUDO_LEGAL_MOVES >= MAX_LEGAL_MOVES);
 /// Maximum number of moves saved in the history
 pub const MAX_MOVES: usize = 3072;
 pub const MAX_MOVE_RULE: u32 = 100;
@@ -45,6 +57,8 @@ pub const QUEEN_OFFSETS: [(i8, i8); 8] = [
 pub const BISHOP_OFFSETS: [(i8, i8); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
 pub const ROOK_OFFSETS: [(i8, i8); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
 
+/// Counts describing the board geometry and the pieces on it. The most commonly used ones are
+/// also re-exported from the crate root, e.g. [`crate::SQUARES`].
 pub struct NumberOf;
 impl NumberOf {
     pub const PIECE_TYPES: usize = 6;
@@ -148,6 +162,27 @@ impl Squares {
     pub const F8: u8 = 61;
     pub const G8: u8 = 62;
     pub const H8: u8 = 63;
+
+    /// All square indices in order, from A1 to H8.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chess::{definitions::Squares, SQUARES};
+    ///
+    /// assert_eq!(Squares::ALL.len(), SQUARES);
+    /// assert_eq!(Squares::ALL[0], Squares::A1);
+    /// assert_eq!(Squares::ALL[63], Squares::H8);
+    /// ```
+    pub const ALL: [u8; NumberOf::SQUARES] = {
+        let mut squares = [0; NumberOf::SQUARES];
+        let mut i = 0;
+        while i < NumberOf::SQUARES {
+            squares[i] = i as u8;
+            i += 1;
+        }
+        squares
+    };
 }
 
 pub const DARK_SQUARES: u64 = 0xAA55AA55AA55AA55;
@@ -0,0 +1,344 @@
+/*
+ * epd.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::{
+    board::Board, move_generation::MoveGenerator, moves::Move, pieces::Piece, pieces::SQUARE_NAME,
+};
+
+/// A token of an EPD record.
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    /// A run of characters without whitespace, semicolons or quotes.
+    Word(&'a str),
+    /// The contents of a quoted string, without the quotes.
+    Quoted(&'a str),
+    /// The end of an operation.
+    Semicolon,
+}
+
+/// Splits an EPD record into tokens. Semicolons and whitespace inside quoted strings are kept.
+fn tokenize(line: &str) -> Result<Vec<Token<'_>>> {
+    let mut tokens = Vec::new();
+    let mut rest = line;
+    loop {
+        rest = rest.trim_start();
+        let Some(c) = rest.chars().next() else {
+            break;
+        };
+
+        match c {
+            ';' => {
+                tokens.push(Token::Semicolon);
+                rest = &rest[1..];
+            }
+            '"' => {
+                let Some(end) = rest[1..].find('"') else {
+                    bail!("unterminated string in EPD record \"{}\"", line);
+                };
+                tokens.push(Token::Quoted(&rest[1..=end]));
+                rest = &rest[end + 2..];
+            }
+            _ => {
+                let end = rest
+                    .find(|c: char| c.is_whitespace() || c == ';' || c == '"')
+                    .unwrap_or(rest.len());
+                tokens.push(Token::Word(&rest[..end]));
+                rest = &rest[end..];
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+/// Parses an EPD record: the first four FEN fields followed by operations. Each operation is an
+/// opcode and its operands, terminated by a `;` (optional for the last one), for example
+/// `bm Nf3; id "test 1"; c9 "1-0";`.
+///
+/// Many EPD files also put the halfmove clock and the fullmove number after the position, like a
+/// full FEN, so two numbers right after the position are read as the move counters. Otherwise the
+/// `hmvc` and `fmvn` opcodes are used, and `0 1` if they are missing.
+///
+/// # Arguments
+///
+/// - `line` - The EPD record.
+///
+/// # Returns
+///
+/// The board and a map from each opcode to its operands. Quotes are removed from string operands
+/// and multiple operands are joined with a single space. If an opcode appears more than once, the
+/// last operation wins.
+///
+/// # Errors
+///
+/// Returns an error if the position is missing or invalid, a string is not terminated or an
+/// operation doesn't start with an opcode.
+///
+/// # Examples
+///
+/// ```
+/// use chess::epd::parse_epd_record;
+///
+/// let (board, opcodes) =
+///     parse_epd_record("4k3/8/8/8/8/8/8/4K2R w K - bm Rh8+; id \"mate\";").unwrap();
+/// assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+/// assert_eq!(opcodes["bm"], "Rh8+");
+/// assert_eq!(opcodes["id"], "mate");
+/// ```
+pub fn parse_epd_record(line: &str) -> Result<(Board, HashMap<String, String>)> {
+    let tokens = tokenize(line)?;
+    let mut tokens = tokens.as_slice();
+
+    let mut position = Vec::with_capacity(4);
+    while position.len() < 4 {
+        match tokens.split_first() {
+            Some((Token::Word(field), rest)) => {
+                position.push(*field);
+                tokens = rest;
+            }
+            _ => bail!("missing position in EPD record \"{}\"", line),
+        }
+    }
+
+    let mut clocks = None;
+    if let [Token::Word(halfmove), Token::Word(fullmove), rest @ ..] = tokens {
+        if halfmove.parse::<u32>().is_ok() && fullmove.parse::<u32>().is_ok() {
+            clocks = Some((halfmove.to_string(), fullmove.to_string()));
+            tokens = rest;
+        }
+    }
+
+    let mut opcodes = HashMap::new();
+    for operation in tokens.split(|token| *token == Token::Semicolon) {
+        let Some((opcode, operands)) = operation.split_first() else {
+            // stray or trailing semicolons
+            continue;
+        };
+        let Token::Word(opcode) = opcode else {
+            bail!("operation without an opcode in EPD record \"{}\"", line);
+        };
+        let operands = operands
+            .iter()
+            .map(|operand| match operand {
+                Token::Word(value) | Token::Quoted(value) => *value,
+                Token::Semicolon => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        opcodes.insert(opcode.to_string(), operands.join(" "));
+    }
+
+    let (halfmove, fullmove) = clocks.unwrap_or_else(|| {
+        let opcode = |name: &str, default: &str| {
+            opcodes
+                .get(name)
+                .filter(|value| value.parse::<u32>().is_ok())
+                .cloned()
+                .unwrap_or_else(|| default.to_string())
+        };
+        (opcode("hmvc", "0"), opcode("fmvn", "1"))
+    });
+    let fen = format!("{} {} {}", position.join(" "), halfmove, fullmove);
+    let board = Board::from_fen(&fen)
+        .with_context(|| format!("invalid position in EPD record \"{}\"", line))?;
+
+    Ok((board, opcodes))
+}
+
+/// Finds the legal move of a position described by a move in standard algebraic notation (SAN),
+/// the notation EPD uses for the `bm` and `am` operands, e.g. `Nf3`, `exd5`, `Rad1`, `e8=Q+` or
+/// `O-O`. Check and annotation suffixes (`+`, `#`, `!`, `?`) are ignored, and a missing `x` or
+/// `=` is accepted.
+///
+/// # Arguments
+///
+/// - `board` - The position the move is played in.
+/// - `move_gen` - The move generator used to find the legal moves.
+/// - `san` - The move in SAN.
+///
+/// # Returns
+///
+/// The legal move described by `san`.
+///
+/// # Errors
+///
+/// Returns an error if `san` is malformed, or describes no legal move or more than one.
+///
+/// # Examples
+///
+/// ```
+/// use chess::{board::Board, epd::parse_san_move, move_generation::MoveGenerator};
+///
+/// let board = Board::default_board();
+/// let mv = parse_san_move(&board, &MoveGenerator::new(), "Nf3").unwrap();
+/// assert_eq!(mv.to_long_algebraic(), "g1f3");
+/// ```
+pub fn parse_san_move(board: &Board, move_gen: &MoveGenerator, san: &str) -> Result<Move> {
+    let legal_moves = move_gen.generate_legal_moves_vec(board);
+    let notation = san.trim_end_matches(['+', '#', '!', '?']);
+
+    let castle_file = match notation {
+        "O-O" | "0-0" => Some('g'),
+        "O-O-O" | "0-0-0" => Some('c'),
+        _ => None,
+    };
+    if let Some(file) = castle_file {
+        return legal_moves
+            .into_iter()
+            .find(|mv| mv.is_castle() && SQUARE_NAME[mv.to() as usize].starts_with(file))
+            .with_context(|| format!("no legal move {}", san));
+    }
+
+    let mut chars = notation
+        .chars()
+        .filter(|c| *c != 'x' && *c != '=')
+        .collect::<Vec<_>>();
+    let promotion = match chars.last() {
+        Some(c) if c.is_ascii_uppercase() => {
+            let piece = Piece::try_from(*c).map_err(|_| anyhow!("invalid move {}", san))?;
+            chars.pop();
+            Some(piece)
+        }
+        _ => None,
+    };
+    let piece = match chars.first() {
+        Some(c) if c.is_ascii_uppercase() => {
+            let piece = Piece::try_from(*c).map_err(|_| anyhow!("invalid move {}", san))?;
+            chars.remove(0);
+            piece
+        }
+        _ => Piece::Pawn,
+    };
+    if chars.len() < 2 || chars.len() > 4 {
+        bail!("invalid move {}", san);
+    }
+    let (from, to) = chars.split_at(chars.len() - 2);
+    let to = to.iter().collect::<String>();
+    if !SQUARE_NAME.contains(&to.as_str()) {
+        bail!("invalid move {}", san);
+    }
+
+    // the file and/or rank of the from square, if the piece type and target square are ambiguous
+    let from_matches = |mv: &Move| {
+        let name = SQUARE_NAME[mv.from() as usize];
+        from.iter().all(|c| name.contains(*c))
+    };
+    let mut candidates = legal_moves.into_iter().filter(|mv| {
+        mv.piece() == piece
+            && SQUARE_NAME[mv.to() as usize] == to
+            && mv.promotion_piece() == promotion
+            && from_matches(mv)
+    });
+    match (candidates.next(), candidates.next()) {
+        (Some(mv), None) => Ok(mv),
+        (Some(_), Some(_)) => bail!("ambiguous move {}", san),
+        (None, _) => bail!("no legal move {}", san),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{board::Board, move_generation::MoveGenerator};
+
+    use super::{parse_epd_record, parse_san_move};
+
+    #[test]
+    fn parses_operations() {
+        let (board, opcodes) = parse_epd_record(
+            "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - \
+             bm Bb5 Bc4; am Nxe5; id \"opening.001\"; c9 \"1/2-1/2\";",
+        )
+        .unwrap();
+        assert_eq!(
+            board.to_fen(),
+            "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 0 1"
+        );
+        assert_eq!(opcodes.len(), 4);
+        assert_eq!(opcodes["bm"], "Bb5 Bc4");
+        assert_eq!(opcodes["am"], "Nxe5");
+        assert_eq!(opcodes["id"], "opening.001");
+        assert_eq!(opcodes["c9"], "1/2-1/2");
+    }
+
+    #[test]
+    fn handles_strings_counters_and_terminators() {
+        // semicolons and spaces inside strings don't end the operation, the last one doesn't need
+        // a semicolon
+        let (_, opcodes) =
+            parse_epd_record("4k3/8/8/8/8/8/8/4K3 b - - c0 \"draw; only kings\";;c1 \"a b\"")
+                .unwrap();
+        assert_eq!(opcodes["c0"], "draw; only kings");
+        assert_eq!(opcodes["c1"], "a b");
+
+        // move counters after the position, or as opcodes
+        let (board, opcodes) = parse_epd_record("4k3/8/8/8/8/8/8/4K3 b - - 12 40 ;D1 5").unwrap();
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K3 b - - 12 40");
+        assert_eq!(opcodes["D1"], "5");
+        let (board, _) = parse_epd_record("4k3/8/8/8/8/8/8/4K3 w - - hmvc 3; fmvn 7;").unwrap();
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K3 w - - 3 7");
+
+        // a record without operations
+        let (board, opcodes) = parse_epd_record("4k3/8/8/8/8/8/8/4K3 w - -").unwrap();
+        assert_eq!(board.to_fen(), "4k3/8/8/8/8/8/8/4K3 w - - 0 1");
+        assert!(opcodes.is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_records() {
+        for line in [
+            "",
+            "4k3/8/8/8/8/8/8/4K3 w -",
+            "4k3/8/8/8/8/8/8/4K3 w - - ; \"value\";",
+            "4k3/8/8/8/8/8/8/4K3 w - - id \"unterminated;",
+            "4k3/8/8/8/8/8/8/4K3 x - - id \"bad side\";",
+        ] {
+            assert!(parse_epd_record(line).is_err(), "{}", line);
+        }
+    }
+
+    #[test]
+    fn parses_san_moves() {
+        let move_gen = MoveGenerator::new();
+        let parse = |fen: &str, san: &str| {
+            let board = Board::from_fen(fen).unwrap();
+            parse_san_move(&board, &move_gen, san).map(|mv| mv.to_long_algebraic())
+        };
+
+        let italian = "r1bqkbnr/pppp1ppp/2n5/4p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4";
+        assert_eq!(parse(italian, "O-O").unwrap(), "e1g1");
+        assert_eq!(parse(italian, "Bxf7+").unwrap(), "c4f7");
+        assert_eq!(parse(italian, "Bf7").unwrap(), "c4f7");
+        assert_eq!(parse(italian, "d4!?").unwrap(), "d2d4");
+        assert_eq!(parse(italian, "Nxe5").unwrap(), "f3e5");
+        assert!(parse(italian, "O-O-O").is_err());
+        assert!(parse(italian, "Qh5xf7").is_err());
+        assert!(parse(italian, "Ke3").is_err());
+        assert!(parse(italian, "Nz9").is_err());
+
+        // disambiguation by file and rank, pawn captures and promotions
+        let fen = "1r2k3/2P5/8/8/3p4/4PN2/2N5/R3K2R w KQ - 0 1";
+        assert!(parse(fen, "Nd4").is_err());
+        assert_eq!(parse(fen, "Ncxd4").unwrap(), "c2d4");
+        assert_eq!(parse(fen, "Nfxd4").unwrap(), "f3d4");
+        assert_eq!(parse(fen, "exd4").unwrap(), "e3d4");
+        assert_eq!(parse(fen, "Rad1").unwrap(), "a1d1");
+        assert_eq!(parse(fen, "R1h2").unwrap(), "h1h2");
+        assert_eq!(parse(fen, "O-O-O").unwrap(), "e1c1");
+        assert_eq!(parse(fen, "c8=Q+").unwrap(), "c7c8q");
+        assert_eq!(parse(fen, "cxb8N").unwrap(), "c7b8n");
+        assert!(parse(fen, "c8").is_err());
+    }
+}
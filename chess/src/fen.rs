@@ -18,9 +18,9 @@ use thiserror::Error;
 
 use crate::{
     board::Board,
-    definitions::{CastlingAvailability, DASH, EM_DASH},
+    definitions::{CastlingAvailability, NumberOf, DASH, EM_DASH},
     pieces::{Piece, PIECE_SHORT_NAMES, SQUARE_NAME},
-    side::Side,
+    side::{PlayerSide, Side},
     square::to_square,
 };
 
@@ -147,6 +147,13 @@ fn parse_piece_placement(board: &mut Board, part: &str) -> FenResult {
             }
             c if c.is_ascii_digit() => {
                 file += c.to_digit(10).unwrap() as usize;
+                if file > NumberOf::FILES {
+                    return Err(FenError::new(&format!(
+                        "Too many squares in rank {} in FEN part {}",
+                        rank + 1,
+                        FenPart::PiecePlacement,
+                    )));
+                }
             }
             'P' | 'N' | 'B' | 'R' | 'Q' | 'K' | 'p' | 'n' | 'b' | 'r' | 'q' | 'k' => {
                 let piece = match c.to_ascii_lowercase() {
@@ -165,6 +172,14 @@ fn parse_piece_placement(board: &mut Board, part: &str) -> FenResult {
                     Side::Black
                 };
 
+                if file >= NumberOf::FILES {
+                    return Err(FenError::new(&format!(
+                        "Too many squares in rank {} in FEN part {}",
+                        rank + 1,
+                        FenPart::PiecePlacement,
+                    )));
+                }
+
                 let square = to_square(file as u8, rank);
                 board.set_piece_square(piece as usize, side as usize, square);
 
@@ -244,8 +259,8 @@ fn parse_active_color(board: &mut Board, part: &str) -> FenResult {
     }
 
     match part.trim() {
-        "w" => board.set_side_to_move(Side::White),
-        "b" => board.set_side_to_move(Side::Black),
+        "w" => board.set_side_to_move(PlayerSide::White),
+        "b" => board.set_side_to_move(PlayerSide::Black),
         _ => {
             return Err(FenError::new(&format!(
                 "Invalid active color found in FEN part {}",
@@ -259,9 +274,8 @@ fn parse_active_color(board: &mut Board, part: &str) -> FenResult {
 /// Converts the active color of a board to a FEN string.
 pub(crate) fn active_color_to_fen(board: &Board) -> String {
     match board.side_to_move() {
-        Side::White => "w".to_string(),
-        Side::Black => "b".to_string(),
-        _ => panic!("Invalid side"),
+        PlayerSide::White => "w".to_string(),
+        PlayerSide::Black => "b".to_string(),
     }
 }
 
@@ -367,7 +381,13 @@ pub(crate) fn castling_availability_to_fen(board: &Board) -> String {
 
 /// Parses the halfmove clock part of a FEN string and updates the board accordingly.
 fn parse_halfmove_clock(board: &mut Board, part: &str) -> FenResult {
-    let halfmove_clock = part.trim().parse::<u32>().unwrap();
+    let halfmove_clock = part.trim().parse::<u32>().map_err(|_| {
+        FenError::new(&format!(
+            "Invalid halfmove clock {} in FEN part {}",
+            part,
+            FenPart::HalfmoveClock,
+        ))
+    })?;
     board.set_half_move_clock(halfmove_clock);
     Ok(())
 }
@@ -379,7 +399,13 @@ pub(crate) fn halfmove_clock_to_fen(board: &Board) -> String {
 
 /// Parses the fullmove number part of a FEN string and updates the board accordingly.
 fn parse_fullmove_number(board: &mut Board, part: &str) -> FenResult {
-    let fullmove_number = part.trim().parse::<u32>().unwrap();
+    let fullmove_number = part.trim().parse::<u32>().map_err(|_| {
+        FenError::new(&format!(
+            "Invalid fullmove number {} in FEN part {}",
+            part,
+            FenPart::FullmoveNumber,
+        ))
+    })?;
     board.set_full_move_number(fullmove_number);
     Ok(())
 }
@@ -54,6 +54,32 @@ impl File {
         None
     }
 
+    /// Parses a file from its lowercase character, `a` - `h`.
+    ///
+    /// Returns `None` if the character is not a valid file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::file::File;
+    ///
+    /// assert_eq!(File::from_char('e'), Some(File::E));
+    /// assert_eq!(File::from_char('i'), None);
+    /// ```
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'a' => Some(Self::A),
+            'b' => Some(Self::B),
+            'c' => Some(Self::C),
+            'd' => Some(Self::D),
+            'e' => Some(Self::E),
+            'f' => Some(Self::F),
+            'g' => Some(Self::G),
+            'h' => Some(Self::H),
+            _ => None,
+        }
+    }
+
     /// Returns the character representation of the file (lowercase)
     pub fn to_char(&self) -> char {
         match self {
@@ -90,17 +116,7 @@ impl TryFrom<u8> for File {
 impl TryFrom<char> for File {
     type Error = anyhow::Error;
     fn try_from(value: char) -> Result<Self> {
-        match value {
-            'a' => Ok(Self::A),
-            'b' => Ok(Self::B),
-            'c' => Ok(Self::C),
-            'd' => Ok(Self::D),
-            'e' => Ok(Self::E),
-            'f' => Ok(Self::F),
-            'g' => Ok(Self::G),
-            'h' => Ok(Self::H),
-            _ => Err(anyhow::Error::msg(format!("Invalid file {}", value))),
-        }
+        Self::from_char(value).ok_or_else(|| anyhow::Error::msg(format!("Invalid file {}", value)))
     }
 }
 
@@ -115,4 +131,21 @@ mod tests {
         assert_eq!(File::H.offset(1), None);
         assert_eq!(File::H.offset(-1), Some(File::G));
     }
+
+    #[test]
+    fn char_conversions() {
+        for (i, c) in ('a'..='h').enumerate() {
+            let file = File::from_char(c).unwrap();
+            assert_eq!(file as usize, i);
+            assert_eq!(file.to_char(), c);
+            assert_eq!(File::try_from(c).unwrap(), file);
+            assert_eq!(File::try_from(i as u8).unwrap(), file);
+        }
+
+        for c in ['i', 'A', '1', ' '] {
+            assert_eq!(File::from_char(c), None);
+            assert!(File::try_from(c).is_err());
+        }
+        assert!(File::try_from(8_u8).is_err());
+    }
 }
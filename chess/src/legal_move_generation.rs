@@ -12,14 +12,17 @@
  *
  */
 
+use anyhow::{bail, Result};
+
 use crate::move_generation::NORTH;
 use crate::move_generation::RANK_BITBOARDS;
 use crate::move_generation::SOUTH;
 use crate::move_list::MoveList;
+use crate::moves::Move;
 use crate::square;
 use crate::{
     bitboard::Bitboard, bitboard_helpers, board::Board, definitions::Squares,
-    move_generation::MoveGenerator, pieces::Piece, rank::Rank, side::Side, square::Square,
+    move_generation::MoveGenerator, pieces::Piece, rank::Rank, side::PlayerSide, square::Square,
 };
 
 impl MoveGenerator {
@@ -45,7 +48,7 @@ impl MoveGenerator {
     ) -> (Bitboard, Bitboard, Bitboard, Bitboard, Bitboard, Bitboard) {
         // helpers to simplify things later
         let us = board.side_to_move();
-        let them = Side::opposite(us);
+        let them = PlayerSide::opposite(us);
         let occupancy = board.all_pieces();
         let empty = !occupancy;
         let their_pieces = board.pieces(them);
@@ -168,19 +171,18 @@ impl MoveGenerator {
         // if "us" is white, then we should shift the en passant square left
         // if "us" is black, then we should shift the en passant square right
         match board.side_to_move() {
-            Side::White => {
+            PlayerSide::White => {
                 let left = en_passant_bb >> SOUTH;
                 if left & checkers != 0 {
                     capture_mask |= en_passant_bb;
                 }
             }
-            Side::Black => {
+            PlayerSide::Black => {
                 let right = en_passant_bb << NORTH;
                 if right & checkers != 0 {
                     capture_mask |= en_passant_bb;
                 }
             }
-            Side::Both => panic!("Both side not allowed"),
         }
 
         (
@@ -193,6 +195,76 @@ impl MoveGenerator {
         )
     }
 
+    /// Returns the pieces that are checking the king of the side to move.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The current board state
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::board::Board;
+    /// use chess::definitions::Squares;
+    /// use chess::move_generation::MoveGenerator;
+    ///
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/8/4K2r w - - 0 1").unwrap();
+    /// let movegen = MoveGenerator::new();
+    /// assert!(movegen.checkers(&board).is_square_occupied(Squares::H1));
+    /// ```
+    pub fn checkers(&self, board: &Board) -> Bitboard {
+        let (checkers, _, _, _, _, _) = self.calculate_check_and_pin_metadata(board);
+        checkers
+    }
+
+    /// Returns whether the side to move is in check and by how many pieces. This only computes the
+    /// checkers, so it is cheaper than [`MoveGenerator::checkers`] and avoids repeated
+    /// [`MoveGenerator::is_square_attacked`] calls when the search needs both answers.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The current board state
+    ///
+    /// # Returns
+    ///
+    /// A tuple of whether the side to move is in check and the number of checking pieces.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::board::Board;
+    /// use chess::move_generation::MoveGenerator;
+    ///
+    /// let board = Board::from_fen("4k3/8/8/8/8/8/8/4K2r w - - 0 1").unwrap();
+    /// let movegen = MoveGenerator::new();
+    /// assert_eq!(movegen.in_check_info(&board), (true, 1));
+    /// ```
+    pub fn in_check_info(&self, board: &Board) -> (bool, u32) {
+        let count = self
+            .calculate_checkers(board, &board.all_pieces())
+            .number_of_occupied_squares();
+        (count > 0, count)
+    }
+
+    /// Returns the pieces of `side` that are pinned to their own king.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The current board state
+    /// - `side` - The side whose pinned pieces to return. Does not have to be the side to move.
+    pub fn pinned(&self, board: &Board, side: PlayerSide) -> Bitboard {
+        if side == board.side_to_move() {
+            let (_, _, _, pinned, _, _) = self.calculate_check_and_pin_metadata(board);
+            return pinned;
+        }
+
+        // pins are calculated for the side to move, so look at the position from the other side
+        let mut flipped = board.clone();
+        flipped.flip_side_to_move();
+        let (_, _, _, pinned, _, _) = self.calculate_check_and_pin_metadata(&flipped);
+        pinned
+    }
+
     /// Calculate 'checkers' and 'pinned' bitboard masks for the current position.
     ///
     /// # Arguments
@@ -204,7 +276,7 @@ impl MoveGenerator {
     /// A [`Bitboard`] representing the squares that are checking the king
     fn calculate_checkers(&self, board: &Board, occupancy: &Bitboard) -> Bitboard {
         let us = board.side_to_move();
-        let them = Side::opposite(us);
+        let them = PlayerSide::opposite(us);
         let king_bb = board.piece_bitboard(Piece::King, us);
         let king_square = bitboard_helpers::next_bit(&mut king_bb.clone()) as u8;
 
@@ -219,7 +291,8 @@ impl MoveGenerator {
             self.get_piece_attacks(Piece::Bishop, king_square, us, &kingless_occupancy);
         let queen_attacks = rook_attacks | bishop_attacks;
         // note we use the opposite side for the pawn attacks
-        let pawn_attacks = self.pawn_attacks[Side::opposite(them) as usize][king_square as usize];
+        let pawn_attacks =
+            self.pawn_attacks[PlayerSide::opposite(them) as usize][king_square as usize];
 
         let enemy_pawns = board.piece_bitboard(Piece::Pawn, them);
         let enemy_knights = board.piece_bitboard(Piece::Knight, them);
@@ -266,9 +339,8 @@ impl MoveGenerator {
                 occupancy &= !(Bitboard::from_square(from));
                 // remove the captured pawn
                 let captured_sq = match board.side_to_move() {
-                    Side::White => sq - SOUTH as u8,
-                    Side::Black => sq + NORTH as u8,
-                    Side::Both => panic!("Both side not allowed"),
+                    PlayerSide::White => sq - SOUTH as u8,
+                    PlayerSide::Black => sq + NORTH as u8,
                 };
                 occupancy &= !(Bitboard::from_square(captured_sq));
                 // get the squares attacked by the sliding pieces
@@ -333,29 +405,27 @@ impl MoveGenerator {
         // also, we need to take into account the pin directions
         let is_pinned = pinned_pieces.intersects(*square);
         let us = board.side_to_move();
-        let their_pieces = board.pieces(Side::opposite(us));
+        let their_pieces = board.pieces(PlayerSide::opposite(us));
         let direction = match us {
-            Side::White => NORTH as u8,
-            Side::Black => SOUTH as u8,
-            Side::Both => panic!("Both side not allowed"),
+            PlayerSide::White => NORTH as u8,
+            PlayerSide::Black => SOUTH as u8,
         };
         let from_square = square.to_square_index();
         let to_square = match us {
-            Side::White => {
+            PlayerSide::White => {
                 let (result, did_overflow) = from_square.overflowing_add(direction);
                 match did_overflow {
                     true => None,
                     false => Some(result),
                 }
             }
-            Side::Black => {
+            PlayerSide::Black => {
                 let (result, did_overflow) = from_square.overflowing_sub(direction);
                 match did_overflow {
                     true => None,
                     false => Some(result),
                 }
             }
-            Side::Both => panic!("Both side not allowed"),
         };
 
         let mut pushes: Bitboard = match to_square {
@@ -367,29 +437,27 @@ impl MoveGenerator {
         let is_unobstructed = pushes & !occupancy == Bitboard::default();
 
         let can_double_push = match us {
-            Side::White => square::is_square_on_rank(from_square, Rank::R2 as u8),
-            Side::Black => square::is_square_on_rank(from_square, Rank::R7 as u8),
-            Side::Both => panic!("Both side not allowed"),
+            PlayerSide::White => square::is_square_on_rank(from_square, Rank::R2 as u8),
+            PlayerSide::Black => square::is_square_on_rank(from_square, Rank::R7 as u8),
         };
 
         // if single push is obstructed, we can't double push
         if can_double_push && !is_unobstructed {
             let double_push_sq = match us {
-                Side::White => {
+                PlayerSide::White => {
                     let (result, did_overflow) = from_square.overflowing_add(2 * NORTH as u8);
                     match did_overflow {
                         true => None,
                         false => Some(result),
                     }
                 }
-                Side::Black => {
+                PlayerSide::Black => {
                     let (result, did_overflow) = from_square.overflowing_sub(2 * SOUTH as u8);
                     match did_overflow {
                         true => None,
                         false => Some(result),
                     }
                 }
-                Side::Both => panic!("Both side not allowed"),
             };
 
             if let Some(to) = double_push_sq {
@@ -456,7 +524,7 @@ impl MoveGenerator {
     ) -> Bitboard {
         let is_pinned = pinned_mask.intersects(*square);
         let us = board.side_to_move();
-        let their_pieces = board.pieces(Side::opposite(us));
+        let their_pieces = board.pieces(PlayerSide::opposite(us));
         let from_square = square.to_square_index();
         let occupancy = board.all_pieces();
         // TODO: properly use orthogonal and diagonal pin rays
@@ -557,9 +625,8 @@ impl MoveGenerator {
         let queen_side_castle = board.can_castle_queenside(us);
 
         let king_sq = match us {
-            Side::White => Squares::E1,
-            Side::Black => Squares::E8,
-            Side::Both => panic!("Both side not allowed"),
+            PlayerSide::White => Squares::E1,
+            PlayerSide::Black => Squares::E8,
         };
 
         // sanity check
@@ -570,9 +637,8 @@ impl MoveGenerator {
 
         if king_side_castle {
             let king_side_rook = match us {
-                Side::White => Squares::H1,
-                Side::Black => Squares::H8,
-                Side::Both => panic!("Both side not allowed"),
+                PlayerSide::White => Squares::H1,
+                PlayerSide::Black => Squares::H8,
             };
             // sanity check for the rook placement
             let maybe_rook = board.piece_on_square(king_side_rook);
@@ -582,19 +648,17 @@ impl MoveGenerator {
             };
 
             let king_side_empty = match us {
-                Side::White => {
+                PlayerSide::White => {
                     Bitboard::from_square(Squares::F1) | Bitboard::from_square(Squares::G1)
                 }
-                Side::Black => {
+                PlayerSide::Black => {
                     Bitboard::from_square(Squares::F8) | Bitboard::from_square(Squares::G8)
                 }
-                Side::Both => panic!("Both side not allowed"),
             };
 
             let king_side_target_sq = match us {
-                Side::White => Squares::G1,
-                Side::Black => Squares::G8,
-                Side::Both => panic!("Both side not allowed"),
+                PlayerSide::White => Squares::G1,
+                PlayerSide::Black => Squares::G8,
             };
 
             let is_king_ray_empty = king_side_empty & occupancy == Bitboard::default();
@@ -606,9 +670,8 @@ impl MoveGenerator {
 
         if queen_side_castle {
             let queen_side_rook = match us {
-                Side::White => Squares::A1,
-                Side::Black => Squares::A8,
-                Side::Both => panic!("Both side not allowed"),
+                PlayerSide::White => Squares::A1,
+                PlayerSide::Black => Squares::A8,
             };
             // sanity check for the rook placement
             let maybe_rook = board.piece_on_square(queen_side_rook);
@@ -618,24 +681,21 @@ impl MoveGenerator {
             };
 
             let queen_side_no_attack = match us {
-                Side::White => {
+                PlayerSide::White => {
                     Bitboard::from_square(Squares::C1) | Bitboard::from_square(Squares::D1)
                 }
-                Side::Black => {
+                PlayerSide::Black => {
                     Bitboard::from_square(Squares::C8) | Bitboard::from_square(Squares::D8)
                 }
-                Side::Both => panic!("Both side not allowed"),
             };
             let queen_side_empty = match us {
-                Side::White => queen_side_no_attack | Bitboard::from_square(Squares::B1),
-                Side::Black => queen_side_no_attack | Bitboard::from_square(Squares::B8),
-                Side::Both => panic!("Both side not allowed"),
+                PlayerSide::White => queen_side_no_attack | Bitboard::from_square(Squares::B1),
+                PlayerSide::Black => queen_side_no_attack | Bitboard::from_square(Squares::B8),
             };
 
             let queen_side_target_sq = match us {
-                Side::White => Squares::C1,
-                Side::Black => Squares::C8,
-                Side::Both => panic!("Both side not allowed"),
+                PlayerSide::White => Squares::C1,
+                PlayerSide::Black => Squares::C8,
             };
 
             let is_king_ray_empty = queen_side_empty & occupancy == Bitboard::default();
@@ -668,7 +728,7 @@ impl MoveGenerator {
         checkers: &Bitboard,
     ) -> Bitboard {
         let us = board.side_to_move();
-        let them = Side::opposite(us);
+        let them = PlayerSide::opposite(us);
         let our_pieces = board.pieces(us);
         let their_pieces = board.pieces(them);
         let occupancy = our_pieces | their_pieces;
@@ -682,7 +742,7 @@ impl MoveGenerator {
 
         // remove the king from the attacked squares occupancy
         let attacked_squares_occupancy = occupancy & !*king_bb;
-        let attacked_squares = self.get_attacked_squares(board, them, &attacked_squares_occupancy);
+        let attacked_squares = self.attacks_by(board, them, &attacked_squares_occupancy);
         let king_pushes = king_moves_bb & !attacked_squares & !our_pieces & !their_pieces;
 
         // also add castling if possible
@@ -797,6 +857,162 @@ impl MoveGenerator {
     /// assert_eq!(20, move_list.len())
     /// ```
     pub fn generate_legal_moves(&self, board: &Board, move_list: &mut MoveList) {
+        self.generate_legal_moves_into(board, move_list);
+    }
+
+    /// Generate all legal moves for the current [`Board`] state and return them in a new [`Vec`].
+    /// This allocates, so prefer [`MoveGenerator::generate_legal_moves`] in performance critical
+    /// code.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The current board state
+    ///
+    /// # Returns
+    ///
+    /// A list of legal moves for the current board state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::board::Board;
+    /// use chess::move_generation::MoveGenerator;
+    ///
+    /// let board = Board::default_board();
+    /// let moves = MoveGenerator::new().generate_legal_moves_vec(&board);
+    /// assert_eq!(20, moves.len());
+    /// assert!(moves.iter().any(|mv| mv.to_long_algebraic() == "e2e4"));
+    /// ```
+    pub fn generate_legal_moves_vec(&self, board: &Board) -> Vec<Move> {
+        let mut moves = Vec::new();
+        self.generate_legal_moves_into(board, &mut moves);
+        moves
+    }
+
+    /// Checks if a move is legal in the given position. Only the mobility of the moving piece is
+    /// generated, so this is cheaper than searching the full list of legal moves. Castling, en
+    /// passant and promotions have to match the moves the generator would produce exactly.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The current board state
+    /// - `mv` - The move to check
+    ///
+    /// # Returns
+    ///
+    /// `true` if the move is one of the legal moves of the position, otherwise `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::board::Board;
+    /// use chess::move_generation::MoveGenerator;
+    ///
+    /// let board = Board::default_board();
+    /// let move_gen = MoveGenerator::new();
+    /// let mv = move_gen.generate_legal_moves_vec(&board)[0];
+    /// assert!(move_gen.is_legal(&board, &mv));
+    /// ```
+    pub fn is_legal(&self, board: &Board, mv: &Move) -> bool {
+        if mv.is_null() {
+            return false;
+        }
+
+        // the moving piece has to belong to the side to move
+        let piece = mv.piece();
+        if board.piece_on_square(mv.from()) != Some((piece, board.side_to_move())) {
+            return false;
+        }
+
+        let (checkers, capture_mask, push_mask, pinned, orthogonal_pin_rays, diagonal_pin_rays) =
+            self.calculate_check_and_pin_metadata(board);
+
+        // with multiple checkers, only the king can move
+        if piece != Piece::King && checkers.number_of_occupied_squares() > 1 {
+            return false;
+        }
+
+        let from_square = Square::from_square_index(mv.from());
+        let mobility = self.generate_legal_mobility(
+            piece,
+            &from_square,
+            board,
+            &pinned,
+            &capture_mask,
+            &push_mask,
+            &orthogonal_pin_rays,
+            &diagonal_pin_rays,
+            &checkers,
+        );
+
+        // enumerate only the target square, this yields at most the four promotions
+        let target = mobility & Bitboard::from_square(mv.to());
+        let mut moves = Vec::with_capacity(4);
+        self.enumerate_moves(&target, &from_square, piece, board, &mut moves);
+        moves.contains(mv)
+    }
+
+    /// Verifies a list of generated legal moves. Every move must be unique and must not leave the
+    /// king of the side to move in check. This makes each move on a copy of the board, so it is
+    /// slow and meant for tests and debug builds.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The board the moves were generated for
+    /// - `moves` - The generated moves
+    ///
+    /// # Errors
+    ///
+    /// Returns an error describing the first duplicate or illegal move found.
+    pub fn verify_legal_moves(&self, board: &Board, moves: &[Move]) -> Result<()> {
+        let us = board.side_to_move();
+        for (i, mv) in moves.iter().enumerate() {
+            if moves[..i].contains(mv) {
+                bail!(
+                    "duplicate move {} in {}",
+                    mv.to_long_algebraic(),
+                    board.to_fen()
+                );
+            }
+
+            let mut board_copy = board.clone();
+            board_copy.make_move_unchecked(mv)?;
+            let king_square = Square::from_square_index(board_copy.king_square(us));
+            if self.is_square_attacked(&board_copy, &king_square, PlayerSide::opposite(us)) {
+                bail!(
+                    "move {} leaves the king in check in {}",
+                    mv.to_long_algebraic(),
+                    board.to_fen()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Generate all legal moves for the current [`Board`] state into any collection that
+    /// implements [`Extend<Move>`], such as an [`ArrayVec`](arrayvec::ArrayVec) or a [`Vec`].
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The current board state
+    /// - `move_list` - The collection of moves to append to
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayvec::ArrayVec;
+    /// use chess::board::Board;
+    /// use chess::definitions::MAX_MOVE_LIST_SIZE;
+    /// use chess::move_generation::MoveGenerator;
+    /// use chess::moves::Move;
+    ///
+    /// let board = Board::default_board();
+    /// let mut moves: ArrayVec<Move, MAX_MOVE_LIST_SIZE> = ArrayVec::new();
+    /// let movegen = MoveGenerator::new();
+    /// movegen.generate_legal_moves_into(&board, &mut moves);
+    /// assert_eq!(20, moves.len())
+    /// ```
+    pub fn generate_legal_moves_into(&self, board: &Board, move_list: &mut impl Extend<Move>) {
         // get board state info to make things simpler
         let us = board.side_to_move();
         let our_pieces = board.pieces(us);
@@ -857,7 +1073,141 @@ impl MoveGenerator {
 
 #[cfg(test)]
 mod tests {
+    use arrayvec::ArrayVec;
+
     use super::*;
+    use crate::definitions::MAX_MOVE_LIST_SIZE;
+
+    #[test]
+    fn in_check_info_counts_checkers() {
+        let move_gen = MoveGenerator::new();
+        let positions = [
+            // not in check
+            (crate::definitions::DEFAULT_FEN, (false, 0)),
+            // rook check
+            ("4k3/8/8/8/8/8/8/4K2r w - - 0 1", (true, 1)),
+            // knight check
+            ("4k3/8/8/8/8/3n4/8/4K3 w - - 0 1", (true, 1)),
+            // pawn check against black
+            ("8/8/8/8/3k4/4P3/8/7K b - - 0 1", (true, 1)),
+            // double check from a rook and a bishop
+            ("4k3/8/8/8/1b6/8/8/4K2r w - - 0 1", (true, 2)),
+            // double check from a knight and a queen
+            ("4q1k1/8/8/8/8/8/2n5/4K3 w - - 0 1", (true, 2)),
+        ];
+
+        for (fen, expected) in positions {
+            let board = Board::from_fen(fen).unwrap();
+            assert_eq!(move_gen.in_check_info(&board), expected, "{}", fen);
+            assert_eq!(board.checkers_count(&move_gen), expected.1, "{}", fen);
+            assert_eq!(board.is_in_check(&move_gen), expected.0, "{}", fen);
+        }
+    }
+
+    #[test]
+    fn verify_legal_moves_on_standard_positions() {
+        let path = format!("{}/../data/standard.epd", env!("CARGO_MANIFEST_DIR"));
+        let epd = std::fs::read_to_string(path).unwrap();
+        let move_gen = MoveGenerator::new();
+        for line in epd.lines() {
+            let fen = line.split(';').next().unwrap().trim();
+            let board = Board::from_fen(fen).unwrap();
+            let mut move_list = MoveList::new();
+            move_gen.generate_legal_moves(&board, &mut move_list);
+            move_gen
+                .verify_legal_moves(&board, move_list.as_slice())
+                .unwrap();
+
+            // also verify one ply deeper to cover positions in check
+            for mv in move_list.iter() {
+                let child = board.clone_and_make(mv, &move_gen).unwrap();
+                let mut child_moves = MoveList::new();
+                move_gen.generate_legal_moves(&child, &mut child_moves);
+                move_gen
+                    .verify_legal_moves(&child, child_moves.as_slice())
+                    .unwrap();
+            }
+        }
+
+        // duplicates and moves into check are rejected
+        let board = Board::from_fen("4k3/8/8/8/8/8/4r3/4K3 w - - 0 1").unwrap();
+        let mut move_list = MoveList::new();
+        move_gen.generate_legal_moves(&board, &mut move_list);
+        let mv = *move_list.at(0).unwrap();
+        assert!(move_gen.verify_legal_moves(&board, &[mv, mv]).is_err());
+        let mut pseudo_legal = MoveList::new();
+        move_gen.generate_moves(&board, &mut pseudo_legal, crate::moves::MoveType::All);
+        assert!(move_gen
+            .verify_legal_moves(&board, pseudo_legal.as_slice())
+            .is_err());
+    }
+
+    #[test]
+    fn is_legal_matches_generated_moves() {
+        let move_gen = MoveGenerator::new();
+        let path = format!("{}/../data/standard.epd", env!("CARGO_MANIFEST_DIR"));
+        let epd = std::fs::read_to_string(path).unwrap();
+        for line in epd.lines() {
+            let fen = line.split(';').next().unwrap().trim();
+            let board = Board::from_fen(fen).unwrap();
+            let legal = move_gen.generate_legal_moves_vec(&board);
+            let mut pseudo_legal = MoveList::new();
+            move_gen.generate_moves(&board, &mut pseudo_legal, crate::moves::MoveType::All);
+            for mv in pseudo_legal.iter() {
+                assert_eq!(
+                    move_gen.is_legal(&board, mv),
+                    legal.contains(mv),
+                    "{} {}",
+                    fen,
+                    mv.to_long_algebraic()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn is_legal_rejects_illegal_moves() {
+        let move_gen = MoveGenerator::new();
+        let find = |board: &Board, uci: &str| {
+            let mut move_list = MoveList::new();
+            move_gen.generate_moves(board, &mut move_list, crate::moves::MoveType::All);
+            let mv = *move_list.find_uci(uci).expect(uci);
+            mv
+        };
+
+        // the bishop on d2 is pinned by the rook on d8 and the rook on e7 controls the e-file
+        let board = Board::from_fen("3r3k/4r3/8/8/8/8/3B4/3K4 w - - 0 1").unwrap();
+        assert!(!move_gen.is_legal(&board, &find(&board, "d2e3")));
+        assert!(!move_gen.is_legal(&board, &find(&board, "d1e2")));
+        assert!(move_gen.is_legal(&board, &find(&board, "d1c2")));
+        assert!(!move_gen.is_legal(&board, &Move::NULL));
+
+        // castling through the attacked f1 square is illegal, queen side castling is fine
+        let king_side_castle = Move::new_castle(
+            &Square::from_square_index(Squares::E1),
+            &Square::from_square_index(Squares::G1),
+        );
+        let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K2R w KQ - 0 1").unwrap();
+        assert_eq!(find(&board, "e1g1"), king_side_castle);
+        assert!(move_gen.is_legal(&board, &king_side_castle));
+        let board = Board::from_fen("4kr2/8/8/8/8/8/8/R3K2R w KQ - 0 1").unwrap();
+        assert!(!move_gen.is_legal(&board, &king_side_castle));
+        assert!(move_gen.is_legal(&board, &find(&board, "e1c1")));
+
+        // en passant would expose the king on the fifth rank
+        let board = Board::from_fen("8/8/8/K2pP2r/8/8/8/4k3 w - d6 0 1").unwrap();
+        assert!(!move_gen.is_legal(&board, &find(&board, "e5d6")));
+        let board = Board::from_fen("8/8/8/3pP3/8/8/8/K3k3 w - d6 0 1").unwrap();
+        assert!(move_gen.is_legal(&board, &find(&board, "e5d6")));
+
+        // every promotion piece is legal, but only from the side to move
+        let board = Board::from_fen("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        for uci in ["b7b8q", "b7b8r", "b7b8b", "b7b8n"] {
+            assert!(move_gen.is_legal(&board, &find(&board, uci)), "{}", uci);
+        }
+        let black_to_move = Board::from_fen("4k3/1P6/8/8/8/8/8/4K3 b - - 0 1").unwrap();
+        assert!(!move_gen.is_legal(&black_to_move, &find(&board, "b7b8q")));
+    }
 
     #[test]
     fn calculate_pinned_pieces() {
@@ -1037,4 +1387,64 @@ mod tests {
         );
         assert!(rays == Bitboard::default());
     }
+
+    #[test]
+    fn generate_legal_moves_into_array_vec() {
+        let move_gen = MoveGenerator::new();
+        let board =
+            Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+                .unwrap();
+
+        let mut move_list = MoveList::new();
+        move_gen.generate_legal_moves(&board, &mut move_list);
+
+        let mut moves: ArrayVec<Move, MAX_MOVE_LIST_SIZE> = ArrayVec::new();
+        move_gen.generate_legal_moves_into(&board, &mut moves);
+        assert_eq!(moves.len(), 48);
+        assert!(moves.iter().eq(move_list.iter()));
+
+        // any collection that implements Extend<Move> works
+        let mut move_vec: Vec<Move> = Vec::new();
+        move_gen.generate_legal_moves_into(&board, &mut move_vec);
+        assert!(move_vec.iter().eq(move_list.iter()));
+    }
+
+    #[test]
+    fn public_checkers_and_pinned() {
+        let move_gen = MoveGenerator::new();
+        let board =
+            Board::from_fen("2kr3r/p1ppqpb1/bn2Qnp1/3PN3/1p2P3/2N5/PPPBBPPP/R3K2R b KQ - 3 2")
+                .unwrap();
+        assert_eq!(move_gen.checkers(&board), 0);
+        assert_eq!(
+            move_gen.pinned(&board, PlayerSide::Black),
+            Bitboard::from_square(Squares::D7)
+        );
+        assert_eq!(move_gen.pinned(&board, PlayerSide::White), 0);
+
+        let board = Board::from_fen("8/8/8/8/k2Pp2Q/8/8/3K4 b - d3 0 1").unwrap();
+        assert_eq!(move_gen.checkers(&board), 0);
+        assert_eq!(move_gen.pinned(&board, PlayerSide::Black), 0);
+
+        let board =
+            Board::from_fen("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQKR2 b Q - 2 8").unwrap();
+        assert_eq!(move_gen.checkers(&board), 0);
+        assert_eq!(move_gen.pinned(&board, PlayerSide::Black), 0);
+
+        // pins can be queried for the side not to move
+        let board =
+            Board::from_fen("r3k2r/Pppp1ppp/1b3nbN/nPB5/B1P1P3/5N2/q2P1KPP/b2Q1R2 b kq - 0 3")
+                .unwrap();
+        let pinned = move_gen.pinned(&board, PlayerSide::White);
+        assert_eq!(pinned.number_of_occupied_squares(), 2);
+        assert!(pinned.is_square_occupied(Squares::C5));
+        assert!(pinned.is_square_occupied(Squares::D2));
+
+        // double check from a rook and a knight
+        let board = Board::from_fen("4k3/8/3N4/8/8/8/8/4RK2 b - - 0 1").unwrap();
+        let checkers = move_gen.checkers(&board);
+        assert_eq!(checkers.number_of_occupied_squares(), 2);
+        assert!(checkers.is_square_occupied(Squares::E1));
+        assert!(checkers.is_square_occupied(Squares::D6));
+    }
 }
@@ -12,12 +12,14 @@
  *
  */
 
+pub mod attack_info;
 pub mod bitboard;
 pub mod bitboard_helpers;
 pub mod board;
 pub mod board_state;
 pub mod color;
 pub mod definitions;
+pub mod epd;
 pub mod fen;
 pub mod file;
 pub mod legal_move_generation;
@@ -33,3 +35,20 @@ pub mod rank;
 pub mod side;
 pub mod square;
 pub mod zobrist;
+
+pub use definitions::{NumberOf, Squares};
+
+// Canonical board geometry constants. Prefer these over reaching into the `definitions` module.
+
+/// Number of squares on the board.
+pub const SQUARES: usize = NumberOf::SQUARES;
+/// Number of files on the board.
+pub const FILES: usize = NumberOf::FILES;
+/// Number of ranks on the board.
+pub const RANKS: usize = NumberOf::RANKS;
+/// Number of piece types (pawn, knight, bishop, rook, queen and king).
+pub const PIECE_TYPES: usize = NumberOf::PIECE_TYPES;
+/// Number of sides (white and black).
+pub const SIDES: usize = NumberOf::SIDES;
+/// Number of distinct castling rights combinations.
+pub const CASTLING_OPTIONS: usize = NumberOf::CASTLING_OPTIONS;
@@ -13,7 +13,8 @@
  */
 
 use crate::{
-    bitboard::Bitboard,
+    attack_info::AttackInfo,
+    bitboard::{Bitboard, Direction},
     bitboard_helpers,
     board::Board,
     definitions::{
@@ -23,9 +24,9 @@ use crate::{
     magics::{MagicNumber, BISHOP_MAGIC_VALUES, ROOK_MAGIC_VALUES},
     move_list::MoveList,
     moves::{Move, MoveDescriptor, MoveType, PromotionDescriptor},
-    pieces::{Piece, SQUARE_NAME},
+    pieces::{Piece, ALL_PIECES, SQUARE_NAME},
     rank::Rank,
-    side::Side,
+    side::PlayerSide,
     square::{self, Square},
 };
 
@@ -57,100 +58,48 @@ pub(crate) const RANK_BITBOARDS: RankBitboards = [
 pub(crate) const NORTH: u64 = 8;
 pub(crate) const SOUTH: u64 = 8;
 
-const WEST: u64 = 1;
-const EAST: u64 = 1;
-const NORTH_EAST: u64 = 9;
-const NORTH_WEST: u64 = 7;
-const SOUTH_EAST: u64 = 7;
-const SOUTH_WEST: u64 = 9;
-const NORTH_NORTH_EAST: u64 = 17;
-const WEST_NORTH_WEST: u64 = 6;
-const NORTH_NORTH_WEST: u64 = 15;
-const EAST_NORTH_EAST: u64 = 10;
-const SOUTH_SOUTH_WEST: u64 = 17;
-const WEST_SOUTH_WEST: u64 = 10;
-const SOUTH_SOUTH_EAST: u64 = 15;
-const EAST_SOUTH_EAST: u64 = 6;
-
 fn initialize_king_attacks(square: u8, attacks: &mut [Bitboard; NumberOf::SQUARES]) {
-    let mut bb = Bitboard::default();
-    let mut attacks_bb = Bitboard::default();
+    let bb = Bitboard::from_square(square);
 
     // king can move 1 square in any direction
-    bb.set_square(square);
-
-    // with our bit board setup, "east" means right, and "west" means left
-    // so this means east means we move more towards the MSB, so shift.
-    // So all the east and north moves are shifted left, all south and west moves are shifted right
-
-    let not_h_file = !FILE_BITBOARDS[File::H as usize];
-    let not_a_file = !FILE_BITBOARDS[File::A as usize];
-    let not_r8_rank = !RANK_BITBOARDS[Rank::R8 as usize];
-    let not_r1_rank = !RANK_BITBOARDS[Rank::R1 as usize];
-
-    attacks_bb |= (bb & not_r8_rank) << NORTH;
-    attacks_bb |= (bb & not_a_file & not_r8_rank) << NORTH_WEST;
-    attacks_bb |= (bb & not_h_file & not_r8_rank) << NORTH_EAST;
-    attacks_bb |= (bb & not_h_file) << EAST;
-
-    attacks_bb |= (bb & not_r1_rank) >> SOUTH;
-    attacks_bb |= (bb & not_a_file & not_r1_rank) >> SOUTH_WEST;
-    attacks_bb |= (bb & not_h_file & not_r1_rank) >> SOUTH_EAST;
-    attacks_bb |= (bb & not_a_file) >> WEST;
-
-    attacks[square as usize] = attacks_bb;
+    attacks[square as usize] = Direction::ALL
+        .into_iter()
+        .fold(Bitboard::default(), |attacks_bb, direction| {
+            attacks_bb | bb.shift(direction)
+        });
 }
 
 fn initialize_knight_attacks(square: u8, attacks: &mut [Bitboard; NumberOf::SQUARES]) {
-    let mut bb = Bitboard::default();
-    let mut attacks_bb = Bitboard::default();
-
-    // knight can move 1 square in any direction
-    bb.set_square(square);
-
-    // with our bit board setup, "east" means right, and "west" means left
-    // so this means east means we move more towards the MSB, so shift.
-    // So all the east and north moves are shifted left, all south and west moves are shifted right
-    let not_h_file = !FILE_BITBOARDS[File::H as usize];
-    let not_gh_file = !FILE_BITBOARDS[File::G as usize] & !FILE_BITBOARDS[File::H as usize];
-    let not_ab_file = !FILE_BITBOARDS[File::A as usize] & !FILE_BITBOARDS[File::B as usize];
-    let not_a_file = !FILE_BITBOARDS[File::A as usize];
-
-    attacks_bb |= (bb & not_h_file) << NORTH_NORTH_EAST;
-    attacks_bb |= (bb & not_gh_file) << EAST_NORTH_EAST;
-    attacks_bb |= (bb & not_a_file) << NORTH_NORTH_WEST;
-    attacks_bb |= (bb & not_ab_file) << WEST_NORTH_WEST;
-
-    attacks_bb |= (bb & not_h_file) >> SOUTH_SOUTH_EAST;
-    attacks_bb |= (bb & not_gh_file) >> EAST_SOUTH_EAST;
-    attacks_bb |= (bb & not_a_file) >> SOUTH_SOUTH_WEST;
-    attacks_bb |= (bb & not_ab_file) >> WEST_SOUTH_WEST;
-
-    attacks[square as usize] = attacks_bb;
+    let bb = Bitboard::from_square(square);
+
+    // a knight moves one square orthogonally and then one square diagonally away from where it
+    // started, both shifts drop squares that would leave the board
+    let north = bb.shift(Direction::North);
+    let south = bb.shift(Direction::South);
+    let east = bb.shift(Direction::East);
+    let west = bb.shift(Direction::West);
+
+    attacks[square as usize] = north.shift(Direction::NorthEast)
+        | north.shift(Direction::NorthWest)
+        | south.shift(Direction::SouthEast)
+        | south.shift(Direction::SouthWest)
+        | east.shift(Direction::NorthEast)
+        | east.shift(Direction::SouthEast)
+        | west.shift(Direction::NorthWest)
+        | west.shift(Direction::SouthWest);
 }
 
 fn initialize_pawn_attacks(
     square: u8,
     attacks: &mut [[Bitboard; NumberOf::SQUARES]; NumberOf::SIDES],
 ) {
-    let mut bb = Bitboard::default();
-    bb.set_square(square);
-
-    let mut attacks_w_bb = Bitboard::default();
-    let mut attacks_b_bb = Bitboard::default();
-
-    let not_a_file = !FILE_BITBOARDS[File::A as usize];
-    let not_h_file = !FILE_BITBOARDS[File::H as usize];
+    let bb = Bitboard::from_square(square);
 
     // white is NORTH_WEST and NORTH_EAST
-    attacks_w_bb |= (bb & not_a_file) << NORTH_WEST;
-    attacks_w_bb |= (bb & not_h_file) << NORTH_EAST;
-
-    attacks_b_bb |= (bb & not_a_file) >> SOUTH_WEST;
-    attacks_b_bb |= (bb & not_h_file) >> SOUTH_EAST;
-
-    attacks[Side::White as usize][square as usize] = attacks_w_bb;
-    attacks[Side::Black as usize][square as usize] = attacks_b_bb;
+    attacks[PlayerSide::White as usize][square as usize] =
+        bb.shift(Direction::NorthWest) | bb.shift(Direction::NorthEast);
+    attacks[PlayerSide::Black as usize][square as usize] =
+        bb.shift(Direction::SouthWest) | bb.shift(Direction::SouthEast);
 }
 
 fn initialize_rays_between(rays_between: &mut [[Bitboard; NumberOf::SQUARES]; NumberOf::SQUARES]) {
@@ -311,102 +260,30 @@ impl MoveGenerator {
         MoveGenerator::edges(file, rank)
     }
 
-    fn orthogonal_ray_attacks(square: u8, occupied: u64) -> Bitboard {
+    /// Walks rays from `square` in each of the given directions until they leave the board or hit
+    /// an occupied square, which is included in the attacks.
+    fn ray_attacks(square: u8, occupied: u64, directions: &[Direction]) -> Bitboard {
         let mut attacks = Bitboard::default();
-        let bb = Bitboard::new(1u64 << square);
-        let not_a_file = !FILE_BITBOARDS[File::A as usize];
-        let not_h_file = !FILE_BITBOARDS[File::H as usize];
-
-        // North
-        let mut ray = bb;
-        while ray != 0 {
-            ray <<= 8;
-            attacks |= ray;
-            if ray & occupied != 0 {
-                break;
-            }
-        }
-
-        // South
-        let mut ray = bb;
-        while ray != 0 {
-            ray >>= 8;
-            attacks |= ray;
-            if ray & occupied != 0 {
-                break;
-            }
-        }
-
-        // East
-        let mut ray = bb;
-        while ray != 0 && ray & not_h_file != 0 {
-            ray <<= 1;
-            attacks |= ray;
-            if ray & occupied != 0 {
-                break;
-            }
-        }
-
-        // West
-        let mut ray = bb;
-        while ray != 0 && ray & not_a_file != 0 {
-            ray >>= 1;
-            attacks |= ray;
-            if ray & occupied != 0 {
-                break;
+        for &direction in directions {
+            let mut ray = Bitboard::from_square(square).shift(direction);
+            while ray != 0 {
+                attacks |= ray;
+                if ray & occupied != 0 {
+                    break;
+                }
+                ray = ray.shift(direction);
             }
         }
 
         attacks
     }
 
-    fn diagonal_ray_attacks(square: u8, occupied: u64) -> Bitboard {
-        let mut attacks = Bitboard::default();
-        let bb = Bitboard::new(1u64 << square);
-        let not_a_file = !FILE_BITBOARDS[File::A as usize];
-        let not_h_file = !FILE_BITBOARDS[File::H as usize];
-
-        // Northeast
-        let mut ray = bb;
-        while ray != 0 && ray & not_h_file != 0 {
-            ray <<= 9;
-            attacks |= ray;
-            if ray & occupied != 0 {
-                break;
-            }
-        }
-
-        // Northwest
-        let mut ray = bb;
-        while ray != 0 && ray & not_a_file != 0 {
-            ray <<= 7;
-            attacks |= ray;
-            if ray & occupied != 0 {
-                break;
-            }
-        }
-
-        // Southeast
-        let mut ray = bb;
-        while ray != 0 && ray & not_h_file != 0 {
-            ray >>= 7;
-            attacks |= ray;
-            if ray & occupied != 0 {
-                break;
-            }
-        }
-
-        // Southwest
-        let mut ray = bb;
-        while ray != 0 && ray & not_a_file != 0 {
-            ray >>= 9;
-            attacks |= ray;
-            if ray & occupied != 0 {
-                break;
-            }
-        }
+    fn orthogonal_ray_attacks(square: u8, occupied: u64) -> Bitboard {
+        MoveGenerator::ray_attacks(square, occupied, &Direction::ORTHOGONAL)
+    }
 
-        attacks
+    fn diagonal_ray_attacks(square: u8, occupied: u64) -> Bitboard {
+        MoveGenerator::ray_attacks(square, occupied, &Direction::DIAGONAL)
     }
 
     /// Calculate the "relevant" bits for rook attacks at a given square.
@@ -558,70 +435,173 @@ impl MoveGenerator {
         self.rays_between[from.to_square_index() as usize][to.to_square_index() as usize]
     }
 
-    /// Calculate all squares currently being attacked by a given side.
+    /// Calculate all squares currently being attacked by a given side. This includes squares
+    /// occupied by the side's own pieces, i.e. defended pieces, which is what mobility, king safety
+    /// and threat detection need. See [`MoveGenerator::attacks_by_excluding_own`] for only the
+    /// squares the side's pieces could move to or capture on.
     ///
     /// # Arguments
     /// - board - The current board state
     /// - side - The side to calculate the attacked squares for
+    /// - occupancy - The occupancy used to block slider attacks and to filter the attackers
     ///
     /// # Returns
     ///
     /// A bitboard representing all squares currently being attacked by the given side.
-    pub(crate) fn get_attacked_squares(
+    pub fn attacks_by(&self, board: &Board, side: PlayerSide, occupancy: &Bitboard) -> Bitboard {
+        ALL_PIECES
+            .iter()
+            .fold(Bitboard::default(), |attacks, piece| {
+                attacks | self.piece_attacks_by(board, *piece, side, occupancy)
+            })
+    }
+
+    /// Calculate all squares attacked by the pieces of one type of a given side, including squares
+    /// occupied by the side's own pieces. Pieces that are not part of the occupancy are treated as
+    /// removed from the board, so they don't attack anything.
+    ///
+    /// # Arguments
+    /// - board - The current board state
+    /// - piece - The type of the attacking pieces
+    /// - side - The side to calculate the attacked squares for
+    /// - occupancy - The occupancy used to block slider attacks and to filter the attackers
+    ///
+    /// # Returns
+    ///
+    /// A bitboard representing all squares attacked by the given pieces.
+    pub fn piece_attacks_by(
         &self,
         board: &Board,
-        side: Side,
+        piece: Piece,
+        side: PlayerSide,
         occupancy: &Bitboard,
     ) -> Bitboard {
         let mut attacks = Bitboard::default();
+        let mut piece_bb = *board.piece_bitboard(piece, side) & *occupancy;
+        while let Some(from) = piece_bb.pop_lsb() {
+            attacks |= match piece {
+                Piece::Pawn => self.pawn_attacks[side as usize][from as usize],
+                Piece::Knight | Piece::King => self.get_non_slider_attacks(piece, from),
+                _ => self.get_slider_attacks(piece, from, occupancy),
+            };
+        }
+        attacks
+    }
 
-        // get the squares attacked by each piece
-        for piece in [
-            Piece::Bishop,
-            Piece::Rook,
-            Piece::Queen,
-            Piece::King,
-            Piece::Knight,
-            Piece::Pawn,
-        ]
-        .iter()
-        {
-            let mut piece_bb = *board.piece_bitboard(*piece, side);
-            if piece_bb.as_number() == 0 {
-                continue;
-            }
+    /// Calculate all squares attacked by a given side that are not occupied by its own pieces.
+    /// Unlike [`MoveGenerator::attacks_by`], defended pieces are left out, so these are the
+    /// squares the side's pieces attack as potential move or capture targets.
+    ///
+    /// # Arguments
+    /// - board - The current board state
+    /// - side - The side to calculate the attacked squares for
+    /// - occupancy - The occupancy used to block slider attacks and to filter the attackers
+    ///
+    /// # Returns
+    ///
+    /// A bitboard of the attacked squares that are empty or occupied by the opponent.
+    pub fn attacks_by_excluding_own(
+        &self,
+        board: &Board,
+        side: PlayerSide,
+        occupancy: &Bitboard,
+    ) -> Bitboard {
+        self.attacks_by(board, side, occupancy) & !board.pieces(side)
+    }
 
-            while piece_bb.as_number() > 0 {
-                let from = bitboard_helpers::next_bit(&mut piece_bb) as u8;
-                let attacks_bb =
-                    if piece == &Piece::Bishop || piece == &Piece::Queen || piece == &Piece::Rook {
-                        self.get_slider_attacks(*piece, from, occupancy)
-                    } else if piece == &Piece::Pawn {
-                        self.pawn_attacks[side as usize][from as usize]
-                    } else {
-                        self.get_non_slider_attacks(*piece, from)
-                    };
+    /// Computes the attacks of every piece on the board in a single pass. The result can be shared
+    /// between evaluation terms so that slider attacks are only looked up once per piece.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The current board state
+    ///
+    /// # Returns
+    ///
+    /// The [`AttackInfo`] for the board.
+    pub fn compute_attack_info(&self, board: &Board) -> AttackInfo {
+        self.compute_attack_info_with_occupancy(board, &board.all_pieces())
+    }
 
-                attacks |= attacks_bb;
+    /// Computes the attacks of every piece in `occupancy`, see
+    /// [`MoveGenerator::compute_attack_info`]. Pieces that are not part of the occupancy are
+    /// treated as removed from the board.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The current board state
+    /// - `occupancy` - The occupancy used to block slider attacks and to filter the attackers
+    ///
+    /// # Returns
+    ///
+    /// The [`AttackInfo`] for the given occupancy.
+    pub fn compute_attack_info_with_occupancy(
+        &self,
+        board: &Board,
+        occupancy: &Bitboard,
+    ) -> AttackInfo {
+        let mut info = AttackInfo::default();
+        for side in [PlayerSide::White, PlayerSide::Black] {
+            for piece in ALL_PIECES {
+                let mut piece_bb = *board.piece_bitboard(piece, side) & *occupancy;
+                while piece_bb.as_number() > 0 {
+                    let from = bitboard_helpers::next_bit(&mut piece_bb) as u8;
+                    let attacks = match piece {
+                        Piece::Pawn => self.pawn_attacks[side as usize][from as usize],
+                        Piece::Knight | Piece::King => self.get_non_slider_attacks(piece, from),
+                        _ => self.get_slider_attacks(piece, from, occupancy),
+                    };
+                    info.add(from, piece, side, attacks);
+                }
             }
         }
-
-        attacks
+        info
     }
 
     /// Get attacks for a given piece.
     ///
+    /// For pawns, this returns the squares a pawn of `attacking_side` would have to stand on to
+    /// attack `square`, which is what attack detection from the target square needs.
+    ///
     /// # Arguments
     ///
     /// - piece - The piece to get the attacks for
     /// - square - The square the piece is on
     /// - attacking_side - The side that is attacking
-    /// - occupancy - The current occupancy of the board
-    pub(crate) fn get_piece_attacks(
+    /// - occupancy - The occupancy used to block slider attacks, doesn't have to match the board
+    ///
+    /// # Returns
+    ///
+    /// A bitboard of the attacked squares.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `piece` is [`Piece::None`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::{
+    ///     bitboard::Bitboard, definitions::Squares, move_generation::MoveGenerator, pieces::Piece,
+    ///     side::PlayerSide,
+    /// };
+    ///
+    /// let move_gen = MoveGenerator::new();
+    /// let white = PlayerSide::White;
+    /// let blocker = Bitboard::from_square(Squares::A4);
+    /// let blocked = move_gen.get_piece_attacks(Piece::Rook, Squares::A1, white, &blocker);
+    /// assert!(!blocked.is_square_occupied(Squares::A5));
+    ///
+    /// // without the blocker, the rook sees the whole file
+    /// let empty = Bitboard::default();
+    /// let open = move_gen.get_piece_attacks(Piece::Rook, Squares::A1, white, &empty);
+    /// assert!(open.is_square_occupied(Squares::A8));
+    /// ```
+    pub fn get_piece_attacks(
         &self,
         piece: Piece,
         square: u8,
-        attacking_side: Side,
+        attacking_side: PlayerSide,
         occupancy: &Bitboard,
     ) -> Bitboard {
         if piece.is_none() {
@@ -631,20 +611,34 @@ impl MoveGenerator {
         if piece.is_slider() {
             self.get_slider_attacks(piece, square, occupancy)
         } else if piece == Piece::Pawn {
-            self.pawn_attacks[Side::opposite(attacking_side) as usize][square as usize]
+            self.pawn_attacks[PlayerSide::opposite(attacking_side) as usize][square as usize]
         } else {
             self.get_non_slider_attacks(piece, square)
         }
     }
 
     /// Generates pseudo-legal moves for the current board state.
-    /// This function does not check for legality of the moves.
+    /// This function does not check for legality of the moves, except for [`MoveType::Evasion`]
+    /// which always generates legal check evasions.
     ///
     /// # Arguments
     /// - board - The current board state
     /// - move_list - The list of moves to append to.
     /// - move_type - The type of moves to generate
     pub fn generate_moves(&self, board: &Board, move_list: &mut MoveList, move_type: MoveType) {
+        if move_type == MoveType::Evasion {
+            // when in check, every legal move is an evasion
+            if board.is_in_check(self) {
+                self.generate_legal_moves(board, move_list);
+            }
+            return;
+        }
+
+        if move_type == MoveType::QuietChecks {
+            self.get_quiet_checks(board, move_list);
+            return;
+        }
+
         // get moves for each piece except pawns
         for piece in [
             Piece::King,
@@ -664,6 +658,41 @@ impl MoveGenerator {
         }
     }
 
+    /// Returns true if the given move gives check to the opponent. This handles direct checks as
+    /// well as discovered checks, castling and promotions.
+    ///
+    /// # Arguments
+    ///
+    /// - board - The current board state
+    /// - mv - The move to check
+    pub fn gives_check(&self, board: &Board, mv: &Move) -> bool {
+        let mut board = board.clone();
+        self.gives_check_unchecked(&mut board, mv)
+    }
+
+    /// Makes the move on the given board, checks if the opponent is in check and then unmakes it.
+    fn gives_check_unchecked(&self, board: &mut Board, mv: &Move) -> bool {
+        if board.make_move_unchecked(mv).is_err() {
+            return false;
+        }
+        let is_check = board.is_in_check(self);
+        board.unmake_move().unwrap();
+        is_check
+    }
+
+    /// Generates all non-capturing moves (including promotions) that give check.
+    fn get_quiet_checks(&self, board: &Board, move_list: &mut MoveList) {
+        let mut quiets = MoveList::new();
+        self.generate_moves(board, &mut quiets, MoveType::Quiet);
+
+        let mut board = board.clone();
+        for mv in quiets.iter() {
+            if self.gives_check_unchecked(&mut board, mv) {
+                move_list.push(*mv);
+            }
+        }
+    }
+
     fn get_castling_moves(&self, board: &Board, move_list: &mut MoveList) {
         /*
          * For castling, the king and rook must not have moved.
@@ -686,7 +715,8 @@ impl MoveGenerator {
         let occupancy = board.all_pieces();
 
         // white king side castling
-        if board.can_castle_kingside(Side::White) && board.side_to_move() == Side::White {
+        if board.can_castle_kingside(PlayerSide::White) && board.side_to_move() == PlayerSide::White
+        {
             let king_from = Square::from_square_index(Squares::E1); // e1
             let king_to = Square::from_square_index(Squares::G1); // g1
             let blockers = Bitboard::from_square(Squares::F1) | Bitboard::from_square(Squares::G1);
@@ -694,19 +724,25 @@ impl MoveGenerator {
 
             let is_blocked = (blockers & occupancy) > 0;
             let are_any_attacked = king_ray.iter().any(|&square| {
-                self.is_square_attacked(board, &Square::from_square_index(square), Side::Black)
+                self.is_square_attacked(
+                    board,
+                    &Square::from_square_index(square),
+                    PlayerSide::Black,
+                )
             });
 
             if !is_blocked
                 && !are_any_attacked
-                && !self.is_square_attacked(board, &king_from, Side::Black)
-                && !self.is_square_attacked(board, &king_to, Side::Black)
+                && !self.is_square_attacked(board, &king_from, PlayerSide::Black)
+                && !self.is_square_attacked(board, &king_to, PlayerSide::Black)
             {
                 move_list.push(Move::new_castle(&king_from, &king_to));
             }
         }
 
-        if board.can_castle_queenside(Side::White) && board.side_to_move() == Side::White {
+        if board.can_castle_queenside(PlayerSide::White)
+            && board.side_to_move() == PlayerSide::White
+        {
             let king_from = Square::from_square_index(Squares::E1);
             let king_to = Square::from_square_index(Squares::C1);
             let blockers = Bitboard::from_square(Squares::D1)
@@ -716,38 +752,49 @@ impl MoveGenerator {
 
             let is_blocked = (blockers & occupancy) > 0;
             let are_any_attacked = king_ray.iter().any(|&square| {
-                self.is_square_attacked(board, &Square::from_square_index(square), Side::Black)
+                self.is_square_attacked(
+                    board,
+                    &Square::from_square_index(square),
+                    PlayerSide::Black,
+                )
             });
 
             if !is_blocked
                 && !are_any_attacked
-                && !self.is_square_attacked(board, &king_from, Side::Black)
-                && !self.is_square_attacked(board, &king_to, Side::Black)
+                && !self.is_square_attacked(board, &king_from, PlayerSide::Black)
+                && !self.is_square_attacked(board, &king_to, PlayerSide::Black)
             {
                 move_list.push(Move::new_castle(&king_from, &king_to));
             }
         }
 
-        if board.can_castle_kingside(Side::Black) && board.side_to_move() == Side::Black {
+        if board.can_castle_kingside(PlayerSide::Black) && board.side_to_move() == PlayerSide::Black
+        {
             let king_from = Square::from_square_index(Squares::E8);
             let king_to = Square::from_square_index(Squares::G8);
             let blockers = Bitboard::from_square(Squares::F8) | Bitboard::from_square(Squares::G8);
             let king_ray = [Squares::E8, Squares::F8, Squares::G8];
             let is_blocked = (blockers & occupancy) > 0;
             let are_any_attacked = king_ray.iter().any(|&square| {
-                self.is_square_attacked(board, &Square::from_square_index(square), Side::White)
+                self.is_square_attacked(
+                    board,
+                    &Square::from_square_index(square),
+                    PlayerSide::White,
+                )
             });
 
             if !is_blocked
                 && !are_any_attacked
-                && !self.is_square_attacked(board, &king_from, Side::White)
-                && !self.is_square_attacked(board, &king_to, Side::White)
+                && !self.is_square_attacked(board, &king_from, PlayerSide::White)
+                && !self.is_square_attacked(board, &king_to, PlayerSide::White)
             {
                 move_list.push(Move::new_castle(&king_from, &king_to));
             }
         }
 
-        if board.can_castle_queenside(Side::Black) && board.side_to_move() == Side::Black {
+        if board.can_castle_queenside(PlayerSide::Black)
+            && board.side_to_move() == PlayerSide::Black
+        {
             let king_from = Square::from_square_index(Squares::E8);
             let king_to = Square::from_square_index(Squares::C8);
             let blockers = Bitboard::from_square(Squares::D8)
@@ -756,13 +803,17 @@ impl MoveGenerator {
             let king_ray = [Squares::E8, Squares::D8, Squares::C8];
             let is_blocked = (blockers & occupancy) > 0;
             let are_any_attacked = king_ray.iter().any(|&square| {
-                self.is_square_attacked(board, &Square::from_square_index(square), Side::White)
+                self.is_square_attacked(
+                    board,
+                    &Square::from_square_index(square),
+                    PlayerSide::White,
+                )
             });
 
             if !is_blocked
                 && !are_any_attacked
-                && !self.is_square_attacked(board, &king_from, Side::White)
-                && !self.is_square_attacked(board, &king_to, Side::White)
+                && !self.is_square_attacked(board, &king_from, PlayerSide::White)
+                && !self.is_square_attacked(board, &king_to, PlayerSide::White)
             {
                 move_list.push(Move::new_castle(&king_from, &king_to));
             }
@@ -777,7 +828,7 @@ impl MoveGenerator {
         move_type: &MoveType,
     ) {
         let us = board.side_to_move();
-        let them = Side::opposite(us);
+        let them = PlayerSide::opposite(us);
         let our_pieces = board.pieces(us);
         let their_pieces = board.pieces(them);
         let occupancy = board.all_pieces();
@@ -797,8 +848,10 @@ impl MoveGenerator {
 
             let bb_moves = match move_type {
                 MoveType::Capture => attack_bb & their_pieces,
-                MoveType::Quiet => attack_bb & empty,
-                MoveType::All => attack_bb & !our_pieces,
+                // quiet checks are filtered from the quiet moves in generate_moves
+                MoveType::Quiet | MoveType::QuietChecks => attack_bb & empty,
+                // evasions are generated by the legal move generator in generate_moves
+                MoveType::All | MoveType::Evasion => attack_bb & !our_pieces,
             };
 
             self.enumerate_moves(
@@ -854,11 +907,15 @@ impl MoveGenerator {
     #[cfg_attr(debug_assertions, inline(never))]
     fn get_pawn_moves(&self, board: &Board, move_list: &mut MoveList, move_type: &MoveType) {
         let us = board.side_to_move();
-        let them = Side::opposite(us);
+        let them = PlayerSide::opposite(us);
         let their_pieces = board.pieces(them);
         let occupancy = board.all_pieces();
         let empty = !occupancy;
-        let direction = if us == Side::White { NORTH } else { SOUTH };
+        let direction = if us == PlayerSide::White {
+            NORTH
+        } else {
+            SOUTH
+        };
         let pawns_bb = board.piece_bitboard(Piece::Pawn, us);
 
         let mut bb = *pawns_bb;
@@ -870,9 +927,8 @@ impl MoveGenerator {
 
             let mut bb_moves = Bitboard::default();
             let to_square = match us {
-                Side::White => from_square as u64 + direction,
-                Side::Black => from_square as u64 - direction,
-                Side::Both => panic!("Both side not allowed"),
+                PlayerSide::White => from_square as u64 + direction,
+                PlayerSide::Black => from_square as u64 - direction,
             };
 
             // pawn non-capture moves
@@ -880,14 +936,13 @@ impl MoveGenerator {
                 let bb_push = Bitboard::new(1u64 << to_square);
                 let bb_single_push = bb_push & empty;
                 let can_double_push = match us {
-                    Side::White => square::is_square_on_rank(from_square, Rank::R2 as u8),
-                    Side::Black => square::is_square_on_rank(from_square, Rank::R7 as u8),
-                    Side::Both => panic!("Both side not allowed"),
+                    PlayerSide::White => square::is_square_on_rank(from_square, Rank::R2 as u8),
+                    PlayerSide::Black => square::is_square_on_rank(from_square, Rank::R7 as u8),
                 };
 
                 let double_push_square = if can_double_push {
                     match us {
-                        Side::White => {
+                        PlayerSide::White => {
                             let (value, did_overflow) = to_square.overflowing_add(direction);
                             if did_overflow {
                                 None
@@ -895,7 +950,7 @@ impl MoveGenerator {
                                 Some(value)
                             }
                         }
-                        Side::Black => {
+                        PlayerSide::Black => {
                             let (value, did_overflow) = to_square.overflowing_sub(direction);
                             if did_overflow {
                                 None
@@ -903,7 +958,6 @@ impl MoveGenerator {
                                 Some(value)
                             }
                         }
-                        Side::Both => panic!("Both side not allowed"),
                     }
                 } else {
                     None
@@ -958,7 +1012,8 @@ impl MoveGenerator {
         }
     }
 
-    /// Enumerate all moves in a given bitboard and add them to the given [`MoveList`]
+    /// Enumerate all moves in a given bitboard and add them to the given move list.
+    /// Any collection that implements [`Extend<Move>`] can be used, such as a [`MoveList`]
     ///
     /// # Arguments
     ///
@@ -973,7 +1028,7 @@ impl MoveGenerator {
         from: &Square,
         piece: Piece,
         board: &Board,
-        move_list: &mut MoveList,
+        move_list: &mut impl Extend<Move>,
     ) {
         if bitboard.as_number() == 0 {
             return;
@@ -981,7 +1036,7 @@ impl MoveGenerator {
 
         let mut bb = *bitboard;
         let us = board.side_to_move();
-        let them = Side::opposite(us);
+        let them = PlayerSide::opposite(us);
         let enemy_pieces = board.pieces(them);
         let promotion_rank = Rank::promotion_rank(us);
         while bb > 0 {
@@ -1042,14 +1097,14 @@ impl MoveGenerator {
                         capture_piece,
                         Some(promotion_type.to_piece()),
                     );
-                    move_list.push(mv);
+                    move_list.extend(std::iter::once(mv));
                 }
             } else if is_castle {
                 let mv = Move::new_castle(from, &to_square);
-                move_list.push(mv);
+                move_list.extend(std::iter::once(mv));
             } else {
                 let mv = Move::new(from, &to_square, move_desc, piece, capture_piece, None);
-                move_list.push(mv);
+                move_list.extend(std::iter::once(mv));
             }
         }
     }
@@ -1065,6 +1120,7 @@ impl MoveGenerator {
     /// - board: the current board state
     /// - square: the square to check if it is attacked
     /// - attacking_side: the side that is potentially attacking the square
+    /// - occupancy: the occupancy used to block slider attacks and to filter the attackers
     ///
     /// # Returns
     /// - true if the square is attacked, false otherwise
@@ -1072,15 +1128,15 @@ impl MoveGenerator {
         &self,
         board: &Board,
         square: &Square,
-        attacking_side: Side,
+        attacking_side: PlayerSide,
         occupancy: &Bitboard,
     ) -> bool {
-        let king_bb = board.piece_bitboard(Piece::King, attacking_side);
-        let knight_bb = board.piece_bitboard(Piece::Knight, attacking_side);
-        let bishop_bb = board.piece_bitboard(Piece::Bishop, attacking_side);
-        let rook_bb = board.piece_bitboard(Piece::Rook, attacking_side);
-        let queen_bb = board.piece_bitboard(Piece::Queen, attacking_side);
-        let pawn_bb: &Bitboard = board.piece_bitboard(Piece::Pawn, attacking_side);
+        let king_bb = *board.piece_bitboard(Piece::King, attacking_side) & *occupancy;
+        let knight_bb = *board.piece_bitboard(Piece::Knight, attacking_side) & *occupancy;
+        let bishop_bb = *board.piece_bitboard(Piece::Bishop, attacking_side) & *occupancy;
+        let rook_bb = *board.piece_bitboard(Piece::Rook, attacking_side) & *occupancy;
+        let queen_bb = *board.piece_bitboard(Piece::Queen, attacking_side) & *occupancy;
+        let pawn_bb = *board.piece_bitboard(Piece::Pawn, attacking_side) & *occupancy;
 
         let king_attacks = self.get_piece_attacks(
             Piece::King,
@@ -1108,15 +1164,15 @@ impl MoveGenerator {
         );
         let queen_attacks = rook_attacks | bishop_attacks;
         // note we use the opposite side for the pawn attacks
-        let pawn_attacks = self.pawn_attacks[Side::opposite(attacking_side) as usize]
+        let pawn_attacks = self.pawn_attacks[PlayerSide::opposite(attacking_side) as usize]
             [square.to_square_index() as usize];
 
-        let is_king_attacker = (king_attacks & *king_bb) > 0;
-        let is_knight_attacker = (knight_attacks & *knight_bb) > 0;
-        let is_rook_attacker = (rook_attacks & *rook_bb) > 0;
-        let is_bishop_attacker = (bishop_attacks & *bishop_bb) > 0;
-        let is_queen_attacker = (queen_attacks & *queen_bb) > 0;
-        let is_pawn_attacker = (pawn_attacks & *pawn_bb) > 0;
+        let is_king_attacker = (king_attacks & king_bb) > 0;
+        let is_knight_attacker = (knight_attacks & knight_bb) > 0;
+        let is_rook_attacker = (rook_attacks & rook_bb) > 0;
+        let is_bishop_attacker = (bishop_attacks & bishop_bb) > 0;
+        let is_queen_attacker = (queen_attacks & queen_bb) > 0;
+        let is_pawn_attacker = (pawn_attacks & pawn_bb) > 0;
 
         is_king_attacker
             || is_knight_attacker
@@ -1126,7 +1182,45 @@ impl MoveGenerator {
             || is_pawn_attacker
     }
 
-    pub fn is_square_attacked(&self, board: &Board, square: &Square, attacking_side: Side) -> bool {
+    /// Returns all pieces of both sides that attack `square`, given the `occupancy`. Pieces that are
+    /// not part of the occupancy are ignored and sliders see through them, which makes this useful
+    /// for exchange evaluation where pieces are removed from the board one by one.
+    ///
+    /// # Arguments
+    ///
+    /// - board - The current board state
+    /// - square - The square to find the attackers of
+    /// - occupancy - The occupancy used for slider attacks and to filter the attackers
+    ///
+    /// # Returns
+    ///
+    /// A bitboard of all attackers of the square.
+    pub fn attackers_to(&self, board: &Board, square: u8, occupancy: &Bitboard) -> Bitboard {
+        let rook_attacks = self.get_slider_attacks(Piece::Rook, square, occupancy);
+        let bishop_attacks = self.get_slider_attacks(Piece::Bishop, square, occupancy);
+
+        let mut attackers = Bitboard::default();
+        for side in [PlayerSide::White, PlayerSide::Black] {
+            let queens = *board.piece_bitboard(Piece::Queen, side);
+            attackers |= self.pawn_attacks[PlayerSide::opposite(side) as usize][square as usize]
+                & *board.piece_bitboard(Piece::Pawn, side);
+            attackers |=
+                self.knight_attacks[square as usize] & *board.piece_bitboard(Piece::Knight, side);
+            attackers |=
+                self.king_attacks[square as usize] & *board.piece_bitboard(Piece::King, side);
+            attackers |= bishop_attacks & (*board.piece_bitboard(Piece::Bishop, side) | queens);
+            attackers |= rook_attacks & (*board.piece_bitboard(Piece::Rook, side) | queens);
+        }
+
+        attackers & *occupancy
+    }
+
+    pub fn is_square_attacked(
+        &self,
+        board: &Board,
+        square: &Square,
+        attacking_side: PlayerSide,
+    ) -> bool {
         self.is_square_attacked_with_occupancy(board, square, attacking_side, &board.all_pieces())
     }
 }
@@ -1147,7 +1241,7 @@ mod tests {
         let mut sq = bitboard_helpers::next_bit(&mut occupancy);
         while sq > 0 {
             let square = Square::from_square_index(sq as u8);
-            let is_attacked = move_gen.is_square_attacked(&board, &square, Side::White);
+            let is_attacked = move_gen.is_square_attacked(&board, &square, PlayerSide::White);
             assert!(!is_attacked);
             sq = bitboard_helpers::next_bit(&mut occupancy);
         }
@@ -1172,11 +1266,11 @@ mod tests {
             let board = Board::from_fen("r6r/1b2k1bq/8/8/7B/8/8/R3K2R b KQ - 3 2").unwrap();
             let mut king_bb = *board.piece_bitboard(Piece::King, board.side_to_move());
             let square = bitboard_helpers::next_bit(&mut king_bb) as u8;
-            assert_eq!(board.side_to_move(), Side::Black);
+            assert_eq!(board.side_to_move(), PlayerSide::Black);
             assert!(move_gen.is_square_attacked(
                 &board,
                 &Square::from_square_index(square),
-                Side::opposite(board.side_to_move())
+                PlayerSide::opposite(board.side_to_move())
             ));
         }
 
@@ -1185,21 +1279,18 @@ mod tests {
                 Board::from_fen("rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8")
                     .unwrap();
             move_gen.generate_moves(&board, &mut move_list, MoveType::All);
-            let mv = move_list
-                .iter()
-                .find(|mv| mv.to_long_algebraic() == "b1c3")
-                .unwrap();
+            let mv = move_list.find_uci("b1c3").unwrap();
             assert!(board.make_move(mv, &move_gen).is_ok());
 
             // did we leave the king in check?
-            let mut king_bb = *board.piece_bitboard(Piece::King, Side::White);
+            let mut king_bb = *board.piece_bitboard(Piece::King, PlayerSide::White);
             let square = bitboard_helpers::next_bit(&mut king_bb) as u8;
-            assert_eq!(board.side_to_move(), Side::Black);
+            assert_eq!(board.side_to_move(), PlayerSide::Black);
             // there should be no attacks on the king
             assert!(!move_gen.is_square_attacked(
                 &board,
                 &Square::from_square_index(square),
-                Side::Black
+                PlayerSide::Black
             ));
         }
     }
@@ -1509,8 +1600,8 @@ mod tests {
             0,
         ];
         for square in 0..NumberOf::SQUARES {
-            let attacks_b_bb = pawn_attacks[Side::Black as usize][square];
-            let attacks_w_bb = pawn_attacks[Side::White as usize][square];
+            let attacks_b_bb = pawn_attacks[PlayerSide::Black as usize][square];
+            let attacks_w_bb = pawn_attacks[PlayerSide::White as usize][square];
             assert_eq!(
                 attacks_b_bb.as_number(),
                 expected_black_pawn_attacks[square]
@@ -1792,7 +1883,7 @@ mod tests {
         let board = Board::from_fen("8/8/8/2k5/2pP4/8/B7/4K3 b - d3 0 3").unwrap();
         assert!(board.en_passant_square().is_some());
 
-        assert_eq!(board.side_to_move(), Side::Black);
+        assert_eq!(board.side_to_move(), PlayerSide::Black);
         let mut move_list = MoveList::new();
         let move_gen = MoveGenerator::new();
         move_gen.generate_moves(&board, &mut move_list, MoveType::All);
@@ -1800,4 +1891,261 @@ mod tests {
         assert!(en_passant_move.is_some());
         assert!(move_list.len() >= 8);
     }
+
+    #[test]
+    fn generate_quiet_checks() {
+        let move_gen = MoveGenerator::new();
+        // the knight can check (and fork) the king from d6 or f6, the pawn checks by promoting
+        // to a queen or rook
+        let board = Board::from_fen("r3k3/1P6/8/8/4N3/8/8/4K3 w - - 0 1").unwrap();
+        let mut move_list = MoveList::new();
+        move_gen.generate_moves(&board, &mut move_list, MoveType::QuietChecks);
+
+        let mut moves = move_list
+            .iter()
+            .map(|mv| mv.to_long_algebraic())
+            .collect::<Vec<_>>();
+        moves.sort();
+        assert_eq!(moves, vec!["b7b8q", "b7b8r", "e4d6", "e4f6"]);
+
+        for mv in move_list.iter() {
+            assert!(!mv.is_capture());
+            assert!(move_gen.gives_check(&board, mv));
+        }
+
+        // no quiet move gives check from the starting position
+        move_list.clear();
+        move_gen.generate_moves(
+            &Board::default_board(),
+            &mut move_list,
+            MoveType::QuietChecks,
+        );
+        assert!(move_list.is_empty());
+    }
+
+    #[test]
+    fn generate_evasions() {
+        let move_gen = MoveGenerator::new();
+        for fen in [
+            // single checks by a rook, a queen and a pawn
+            "4k3/8/8/8/8/8/3P4/R3K2r w Q - 0 1",
+            "r3k2r/p1pp1pb1/bn2Qnp1/2qPN3/1p2P3/2N5/PPPBBPPP/R3K2R b KQkq - 3 2",
+            "8/8/8/8/3k4/4P3/8/7K b - - 0 1",
+            // double check, only king moves are legal
+            "4k3/8/8/8/1b6/8/8/4K2r w - - 0 1",
+        ] {
+            let board = Board::from_fen(fen).unwrap();
+            assert!(board.is_in_check(&move_gen), "{}", fen);
+
+            let mut evasions = MoveList::new();
+            move_gen.generate_moves(&board, &mut evasions, MoveType::Evasion);
+            let mut legal = MoveList::new();
+            move_gen.generate_legal_moves(&board, &mut legal);
+
+            assert!(!evasions.is_empty(), "{}", fen);
+            let mut evasions = evasions
+                .iter()
+                .map(|mv| mv.to_long_algebraic())
+                .collect::<Vec<_>>();
+            let mut legal = legal
+                .iter()
+                .map(|mv| mv.to_long_algebraic())
+                .collect::<Vec<_>>();
+            evasions.sort();
+            legal.sort();
+            assert_eq!(evasions, legal, "{}", fen);
+        }
+
+        // not in check, so there is nothing to evade
+        let mut move_list = MoveList::new();
+        move_gen.generate_moves(&Board::default_board(), &mut move_list, MoveType::Evasion);
+        assert!(move_list.is_empty());
+    }
+
+    #[test]
+    fn gives_check_with_discovered_check() {
+        let move_gen = MoveGenerator::new();
+        // moving the bishop off the e-file discovers a check from the rook
+        let board = Board::from_fen("4k3/8/8/8/4B3/8/8/K3R3 w - - 0 1").unwrap();
+        let mut move_list = MoveList::new();
+        move_gen.generate_moves(&board, &mut move_list, MoveType::QuietChecks);
+        let bishop_moves = move_list
+            .iter()
+            .filter(|mv| mv.piece() == Piece::Bishop)
+            .count();
+        // every bishop move leaves the e-file
+        assert_eq!(bishop_moves, 13);
+    }
+
+    #[test]
+    fn compute_attack_info_matches_attacked_squares() {
+        let move_gen = MoveGenerator::new();
+        for fen in [
+            crate::definitions::DEFAULT_FEN,
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        ] {
+            let board = Board::from_fen(fen).unwrap();
+            let info = move_gen.compute_attack_info(&board);
+            for side in [PlayerSide::White, PlayerSide::Black] {
+                assert_eq!(
+                    info.attacks(side),
+                    move_gen.attacks_by(&board, side, &board.all_pieces())
+                );
+            }
+        }
+
+        // the rook and the king both defend d1, every other square is attacked at most once
+        let board = Board::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 0 1").unwrap();
+        let info = move_gen.compute_attack_info(&board);
+        assert_eq!(
+            info.attacked_by_two(PlayerSide::White),
+            Bitboard::from(Squares::D1)
+        );
+        assert_eq!(
+            info.square_attacks(Squares::E1),
+            info.piece_attacks(Piece::King, PlayerSide::White)
+        );
+        assert_eq!(info.square_attacks(Squares::E4), Bitboard::default());
+    }
+
+    #[test]
+    fn attacks_by_start_position() {
+        let move_gen = MoveGenerator::new();
+        let board = Board::default_board();
+        let occupancy = board.all_pieces();
+
+        // everything on the first three ranks except the corners, nothing attacks the rooks
+        let white_attacks = move_gen.attacks_by(&board, PlayerSide::White, &occupancy);
+        assert_eq!(white_attacks, Bitboard::new(0x0000_0000_00FF_FF7E));
+        let black_attacks = move_gen.attacks_by(&board, PlayerSide::Black, &occupancy);
+        assert_eq!(black_attacks, Bitboard::new(0x7EFF_FF00_0000_0000));
+        assert!(white_attacks == board.attacks_by(PlayerSide::White, &move_gen));
+
+        // without defended pieces only the third and sixth rank remain
+        assert_eq!(
+            move_gen.attacks_by_excluding_own(&board, PlayerSide::White, &occupancy),
+            Bitboard::new(0x0000_0000_00FF_0000)
+        );
+        assert_eq!(
+            move_gen.attacks_by_excluding_own(&board, PlayerSide::Black, &occupancy),
+            Bitboard::new(0x0000_FF00_0000_0000)
+        );
+    }
+
+    #[test]
+    fn removing_a_blocker_extends_slider_attacks() {
+        let move_gen = MoveGenerator::new();
+        // the white rook on a1 is blocked by its own knight on a4, behind it the black king on a8
+        let board = Board::from_fen("k7/8/8/8/N7/8/8/R3K3 w - - 0 1").unwrap();
+        let occupancy = board.all_pieces();
+        let without_knight = occupancy & !Bitboard::from_square(Squares::A4);
+
+        let blocked =
+            move_gen.get_piece_attacks(Piece::Rook, Squares::A1, PlayerSide::White, &occupancy);
+        assert!(blocked.is_square_occupied(Squares::A4));
+        assert!(!blocked.is_square_occupied(Squares::A5));
+        let open = move_gen.get_piece_attacks(
+            Piece::Rook,
+            Squares::A1,
+            PlayerSide::White,
+            &without_knight,
+        );
+        assert!(open.is_square_occupied(Squares::A8));
+
+        // the same applies to the attacked squares of a side, and the removed knight no longer
+        // attacks anything
+        let rook_attacks = |occupancy: &Bitboard| {
+            move_gen.piece_attacks_by(&board, Piece::Rook, PlayerSide::White, occupancy)
+        };
+        assert_eq!(
+            rook_attacks(&without_knight),
+            rook_attacks(&occupancy) | open
+        );
+        assert!(move_gen
+            .attacks_by(&board, PlayerSide::White, &occupancy)
+            .is_square_occupied(Squares::B6));
+        assert!(!move_gen
+            .attacks_by(&board, PlayerSide::White, &without_knight)
+            .is_square_occupied(Squares::B6));
+        let info = move_gen.compute_attack_info_with_occupancy(&board, &without_knight);
+        assert_eq!(
+            info.attacks(PlayerSide::White),
+            move_gen.attacks_by(&board, PlayerSide::White, &without_knight)
+        );
+
+        // the king on a8 is only attacked once the knight is out of the way
+        let king = Square::from_square_index(Squares::A8);
+        assert!(!move_gen.is_square_attacked_with_occupancy(
+            &board,
+            &king,
+            PlayerSide::White,
+            &occupancy
+        ));
+        assert!(move_gen.is_square_attacked_with_occupancy(
+            &board,
+            &king,
+            PlayerSide::White,
+            &without_knight
+        ));
+        assert_eq!(
+            move_gen.attackers_to(&board, Squares::A8, &occupancy),
+            Bitboard::default()
+        );
+        assert_eq!(
+            move_gen.attackers_to(&board, Squares::A8, &without_knight),
+            Bitboard::from_square(Squares::A1)
+        );
+    }
+
+    #[test]
+    fn slider_attacks_match_reference_attacks() {
+        let move_gen = MoveGenerator::new();
+
+        // occupancies from real positions, these include bits outside of the relevant masks
+        let path = format!("{}/../data/standard.epd", env!("CARGO_MANIFEST_DIR"));
+        let epd = std::fs::read_to_string(path).unwrap();
+        let board_occupancies = epd
+            .lines()
+            .map(|line| Board::from_fen(line.split(';').next().unwrap().trim()).unwrap())
+            .map(|board| board.all_pieces())
+            .collect::<Vec<_>>();
+
+        for square in 0..NumberOf::SQUARES as u8 {
+            let edges = MoveGenerator::edges_from_square(square);
+            for (piece, relevant_bits) in [
+                (Piece::Rook, MoveGenerator::relevant_rook_bits(square)),
+                (Piece::Bishop, MoveGenerator::relevant_bishop_bits(square)),
+            ] {
+                // every relevant blocker permutation, with and without pieces on the edges
+                let permutations = MoveGenerator::create_blocker_permutations(relevant_bits);
+                let occupancies = permutations
+                    .iter()
+                    .flat_map(|blockers| [*blockers, *blockers | edges])
+                    .chain(board_occupancies.iter().copied());
+
+                for occupancy in occupancies {
+                    let expected = match piece {
+                        Piece::Rook => MoveGenerator::calculate_rook_attack(square, &occupancy),
+                        _ => MoveGenerator::calculate_bishop_attack(square, &occupancy),
+                    };
+                    assert_eq!(
+                        move_gen.get_slider_attacks(piece, square, &occupancy),
+                        expected,
+                        "{} on {} with occupancy {:#x}",
+                        piece,
+                        square,
+                        occupancy.as_number()
+                    );
+                }
+            }
+
+            for occupancy in &board_occupancies {
+                assert_eq!(
+                    move_gen.get_slider_attacks(Piece::Queen, square, occupancy),
+                    MoveGenerator::calculate_rook_attack(square, occupancy)
+                        | MoveGenerator::calculate_bishop_attack(square, occupancy)
+                );
+            }
+        }
+    }
 }
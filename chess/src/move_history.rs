@@ -44,6 +44,16 @@ impl BoardHistory {
         self.board_states.pop()
     }
 
+    /// Returns the number of board states in the history.
+    pub fn len(&self) -> usize {
+        self.board_states.len()
+    }
+
+    /// Drops all board states after the first `len` ones.
+    pub fn truncate(&mut self, len: usize) {
+        self.board_states.truncate(len);
+    }
+
     /// Get an iterator to the board history
     pub fn iter(&self) -> std::slice::Iter<BoardState> {
         self.board_states.iter()
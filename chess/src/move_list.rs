@@ -4,7 +4,7 @@
  * Created Date: Monday, November 25th 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Tue Nov 26 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -16,8 +16,9 @@ use arrayvec::ArrayVec;
 
 use crate::{definitions::MAX_MOVE_LIST_SIZE, moves::Move};
 
-/// A list of moves used in move generation. This is a fixed-size list that can hold up to 218 moves.
-/// If more moves are added, the program will panic.
+/// A list of moves used in move generation. This is a fixed-size list that can hold up to
+/// [`MAX_MOVE_LIST_SIZE`] moves, enough for the pseudo-legal moves of any position. If more moves are
+/// added, the program will panic.
 pub struct MoveList {
     moves: ArrayVec<Move, MAX_MOVE_LIST_SIZE>,
 }
@@ -29,7 +30,7 @@ impl Default for MoveList {
 }
 
 impl MoveList {
-    /// Create a new [MoveList] with a capacity of 218 moves.
+    /// Create a new [MoveList] with a capacity of [`MAX_MOVE_LIST_SIZE`] moves.
     /// Note that no default assignment is done for the moves in the list.
     /// The intention is for the items in the [`MoveList`] to be overwritten.
     pub fn new() -> Self {
@@ -62,6 +63,11 @@ impl MoveList {
         self.moves.iter()
     }
 
+    /// Returns the moves in the list as a slice.
+    pub fn as_slice(&self) -> &[Move] {
+        self.moves.as_slice()
+    }
+
     /// Get the move at the given index. Returns None if the index is out of bounds.
     pub fn at(&self, index: usize) -> Option<&Move> {
         self.moves.get(index)
@@ -71,4 +77,45 @@ impl MoveList {
     pub fn clear(&mut self) {
         self.moves.clear();
     }
+
+    /// Returns true if the list contains the given move.
+    pub fn contains(&self, mv: &Move) -> bool {
+        self.moves.contains(mv)
+    }
+
+    /// Returns the first move in the list that matches the `predicate`, if any.
+    pub fn find(&self, predicate: impl Fn(&Move) -> bool) -> Option<&Move> {
+        self.moves.iter().find(|mv| predicate(mv))
+    }
+
+    /// Returns the move with the given long algebraic notation (e.g. `e2e4` or `a7a8q`), if it is
+    /// in the list. This doesn't allocate, see [`Move::matches_long_algebraic`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::{board::Board, move_generation::MoveGenerator, move_list::MoveList};
+    ///
+    /// let board = Board::default_board();
+    /// let mut move_list = MoveList::new();
+    /// MoveGenerator::new().generate_legal_moves(&board, &mut move_list);
+    ///
+    /// let e4 = move_list.find_uci("e2e4").unwrap();
+    /// assert!(move_list.contains(e4));
+    /// assert!(e4.is_pawn_two_up());
+    /// assert!(move_list.find_uci("e2e5").is_none());
+    /// ```
+    pub fn find_uci(&self, uci: &str) -> Option<&Move> {
+        self.find(|mv| mv.matches_long_algebraic(uci))
+    }
+}
+
+impl Extend<Move> for MoveList {
+    /// Extend the list with the moves from the given iterator. If the list is full, the program
+    /// will panic (see [`MoveList::push`]).
+    fn extend<T: IntoIterator<Item = Move>>(&mut self, iter: T) {
+        for mv in iter {
+            self.push(mv);
+        }
+    }
 }
@@ -15,11 +15,12 @@
 use crate::{
     board::Board,
     definitions::{CastlingAvailability, Squares},
+    file::File,
     move_generation::MoveGenerator,
     moves::{self, Move},
     pieces::{Piece, SQUARE_NAME},
     rank::Rank,
-    side::Side,
+    side::PlayerSide,
     square::{self, Square},
 };
 use anyhow::{bail, Result};
@@ -29,6 +30,8 @@ impl Board {
     ///
     /// This function will make a move on the board using UCI notation. It will first parse the move and then try to determine
     /// the move type and other information about it. It will then make the move on the board and update the board state.
+    /// Castling can be given as either the king's move (`e1g1`) or, as in Chess960 mode, the king capturing its own rook
+    /// (`e1h1`), see [`Move::to_uci`].
     ///
     /// # Arguments
     ///
@@ -62,6 +65,23 @@ impl Board {
         let (piece, side) = self
             .piece_on_square(from.to_square_index())
             .ok_or_else(|| anyhow::anyhow!("No piece on square"))?;
+        if side != self.side_to_move() {
+            bail!("Piece does not belong to the side to move");
+        }
+
+        // the king "capturing" its own rook is Chess960 castling notation, convert it to the
+        // square the king ends up on
+        let to = match self.piece_on_square(to.to_square_index()) {
+            Some((Piece::Rook, rook_side)) if piece == Piece::King && rook_side == side => {
+                let file = if to.file as u8 > from.file as u8 {
+                    File::G
+                } else {
+                    File::C
+                };
+                Square::new(file, to.rank)
+            }
+            _ => to,
+        };
         let captured_piece = self
             .piece_on_square(to.to_square_index())
             .map(|(piece, _)| piece);
@@ -114,7 +134,7 @@ impl Board {
         let piece = mv.piece();
 
         let us = self.side_to_move();
-        let them = Side::opposite(us);
+        let them = PlayerSide::opposite(us);
 
         let piece_and_side = self.piece_on_square(from);
         if piece_and_side.is_none() {
@@ -189,7 +209,7 @@ impl Board {
         let captured_piece = mv.captured_piece();
 
         let us = self.side_to_move();
-        let them = Side::opposite(us);
+        let them = PlayerSide::opposite(us);
         let can_castle = self.castling_rights() > 0;
         let update_zobrist_hash = true;
 
@@ -197,7 +217,7 @@ impl Board {
         if captured_piece.is_some() && !mv.is_en_passant_capture() {
             let cap = captured_piece.unwrap();
             // remove the captured piece from the board
-            self.remove_piece(them, cap, to, update_zobrist_hash);
+            self.remove_piece_from_square(them, cap, to, update_zobrist_hash);
             // reset half move clock
             self.set_half_move_clock(0);
             //check for need to update castling rights
@@ -217,7 +237,7 @@ impl Board {
             // reset half move clock
             self.set_half_move_clock(0);
 
-            self.remove_piece(us, piece, from, update_zobrist_hash);
+            self.remove_piece_from_square(us, piece, from, update_zobrist_hash);
             // take into account the promotion piece if any
             let piece_to_add = if mv.is_promotion() {
                 mv.promotion_piece().unwrap()
@@ -232,14 +252,14 @@ impl Board {
                 // depending on the side to move
                 // if white, the pawn is one rank below the destination square
                 // if black, the pawn is one rank above the destination square
-                let en_passant_pawn_location = if us == Side::White {
+                let en_passant_pawn_location = if us == PlayerSide::White {
                     to - 8u8
                 } else {
                     to + 8u8
                 };
                 let pawns = self.piece_bitboard(Piece::Pawn, them);
                 debug_assert!(pawns.is_square_occupied(en_passant_pawn_location));
-                self.remove_piece(
+                self.remove_piece_from_square(
                     them,
                     Piece::Pawn,
                     en_passant_pawn_location,
@@ -252,7 +272,7 @@ impl Board {
                 // get the en passant square from the new move
                 // if white, the en passant square is one rank below the destination square
                 // if black, the en passant square is one rank above the destination square
-                let en_passant_square = if us == Side::White {
+                let en_passant_square = if us == PlayerSide::White {
                     to - 8u8
                 } else {
                     to + 8u8
@@ -319,7 +339,7 @@ impl Board {
         self.switch_side();
 
         // update full move number
-        if us == Side::Black {
+        if us == PlayerSide::Black {
             self.set_full_move_number(self.full_move_number() + 1);
         }
 
@@ -337,7 +357,7 @@ impl Board {
     #[cfg_attr(debug_assertions, inline(never))]
     pub fn make_move(&mut self, mv: &Move, move_gen: &MoveGenerator) -> Result<()> {
         let us = self.side_to_move();
-        let them = Side::opposite(us);
+        let them = PlayerSide::opposite(us);
         self.make_move_unchecked(mv)?;
 
         // check if the move is legal
@@ -354,6 +374,23 @@ impl Board {
         Ok(())
     }
 
+    /// Makes a move on a copy of this [`Board`] and returns the resulting position. This board is
+    /// left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// - `mv` - The move to make.
+    /// - `move_gen` - The move generator used to check the legality of the move.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the move is illegal, see [`Board::make_move`].
+    pub fn clone_and_make(&self, mv: &Move, move_gen: &MoveGenerator) -> Result<Board> {
+        let mut board = self.clone();
+        board.make_move(mv, move_gen)?;
+        Ok(board)
+    }
+
     /// Undo the last move made on this [`Board`].
     /// In general, each undo() should be preceded by a [Board::make_move()]. But this isn't a hard requirement.
     ///
@@ -377,12 +414,12 @@ impl Board {
         self.set_board_state(state);
 
         let us = self.side_to_move();
-        let them = Side::opposite(us);
+        let them = PlayerSide::opposite(us);
         // this is move that we're unmaking
         let chess_move = state.next_move;
 
         // handle null moves
-        if chess_move.is_null_move() {
+        if chess_move.is_null() {
             //nothing else to undo...
             return Ok(());
         }
@@ -395,7 +432,7 @@ impl Board {
         if let Some(promoted_piece) = promoted_piece {
             // remove the promoted piece
             // note that we don't update the zobrist hash here
-            self.remove_piece(us, promoted_piece, to, update_zobrist_hash);
+            self.remove_piece_from_square(us, promoted_piece, to, update_zobrist_hash);
             // put the pawn back
             self.add_piece(us, Piece::Pawn, from, update_zobrist_hash);
         } else {
@@ -420,7 +457,7 @@ impl Board {
         if let Some(captured_piece) = captured_piece {
             match chess_move.is_en_passant_capture() {
                 true => {
-                    let en_passant_square: u8 = if us == Side::White {
+                    let en_passant_square: u8 = if us == PlayerSide::White {
                         to - 8u8
                     } else {
                         to + 8u8
@@ -443,16 +480,23 @@ impl Board {
     /// This basically updates the history state and switches the side to move.
     pub fn null_move(&mut self) {
         let mut current_state = *self.board_state();
-        current_state.next_move = Move::default();
+        current_state.next_move = Move::NULL;
         // update history before modifying the current state
         self.history.push(current_state);
 
         self.switch_side();
     }
 
-    /// Undo a move on the board. Passthrough call to [`Board::remove_piece`] and [`Board::add_piece`].
-    fn undo_move(&mut self, side: Side, piece: Piece, from: u8, to: u8, update_zobrist_hash: bool) {
-        self.remove_piece(side, piece, to, update_zobrist_hash);
+    /// Undo a move on the board. Passthrough call to [`Board::remove_piece_from_square`] and [`Board::add_piece`].
+    fn undo_move(
+        &mut self,
+        side: PlayerSide,
+        piece: Piece,
+        from: u8,
+        to: u8,
+        update_zobrist_hash: bool,
+    ) {
+        self.remove_piece_from_square(side, piece, to, update_zobrist_hash);
         self.add_piece(side, piece, from, update_zobrist_hash);
     }
 
@@ -464,7 +508,7 @@ impl Board {
     /// * `piece` - The piece to add.
     /// * `square` - The square to add the piece to.
     /// * `update_zobrist_hash` - Whether to update the zobrist hash for the addition of the piece.
-    fn add_piece(&mut self, side: Side, piece: Piece, square: u8, update_zobrist_hash: bool) {
+    fn add_piece(&mut self, side: PlayerSide, piece: Piece, square: u8, update_zobrist_hash: bool) {
         let bb = self.mut_piece_bitboard(piece, side);
         bb.set_square(square);
         if update_zobrist_hash {
@@ -480,7 +524,13 @@ impl Board {
     /// * `piece` - The piece to remove.
     /// * `square` - The square to remove the piece from.
     /// * `update_zobrist_hash` - Whether to update the zobrist hash for the removal of the piece.
-    fn remove_piece(&mut self, side: Side, piece: Piece, square: u8, update_zobrist_hash: bool) {
+    fn remove_piece_from_square(
+        &mut self,
+        side: PlayerSide,
+        piece: Piece,
+        square: u8,
+        update_zobrist_hash: bool,
+    ) {
         let bb = self.mut_piece_bitboard(piece, side);
         if !bb.is_square_occupied(square) {
             println!(
@@ -499,26 +549,26 @@ impl Board {
     ///  removal and addition of the piece for the to and from square.
     fn move_piece(
         &mut self,
-        side: Side,
+        side: PlayerSide,
         piece: Piece,
         from: u8,
         to: u8,
         update_zobrist_hash: bool,
     ) {
-        self.remove_piece(side, piece, from, update_zobrist_hash);
+        self.remove_piece_from_square(side, piece, from, update_zobrist_hash);
         self.add_piece(side, piece, to, update_zobrist_hash);
     }
 
     /// Switch the side to move, and update the zobrist hash (see [Board::set_side_to_move]).
     fn switch_side(&mut self) {
-        self.set_side_to_move(Side::opposite(self.side_to_move()));
+        self.set_side_to_move(PlayerSide::opposite(self.side_to_move()));
     }
 }
 
 /// Helper function to get what castling rights to remove based on the square the piece moved from.
-fn get_castling_right_to_remove(us: Side, from: u8) -> u8 {
+fn get_castling_right_to_remove(us: PlayerSide, from: u8) -> u8 {
     match us {
-        Side::White => match from {
+        PlayerSide::White => match from {
             // rook moves
             Squares::A1 => CastlingAvailability::WHITE_QUEENSIDE,
             Squares::H1 => CastlingAvailability::WHITE_KINGSIDE,
@@ -527,7 +577,7 @@ fn get_castling_right_to_remove(us: Side, from: u8) -> u8 {
             }
             _ => 0,
         },
-        Side::Black => match from {
+        PlayerSide::Black => match from {
             // rook moves
             Squares::A8 => CastlingAvailability::BLACK_QUEENSIDE,
             Squares::H8 => CastlingAvailability::BLACK_KINGSIDE,
@@ -536,7 +586,6 @@ fn get_castling_right_to_remove(us: Side, from: u8) -> u8 {
             }
             _ => 0,
         },
-        _ => panic!("Invalid piece"),
     }
 }
 
@@ -546,6 +595,67 @@ mod tests {
         board::Board, definitions::Squares, move_generation::MoveGenerator, move_list::MoveList,
     };
 
+    #[test]
+    fn castling_uci_round_trip() {
+        let move_gen = MoveGenerator::new();
+        for (fen, standard, chess960) in [
+            ("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1", "e1g1", "e1h1"),
+            ("r3k2r/8/8/8/8/8/8/R3K2R w KQkq - 0 1", "e1c1", "e1a1"),
+            ("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1", "e8g8", "e8h8"),
+            ("r3k2r/8/8/8/8/8/8/R3K2R b KQkq - 0 1", "e8c8", "e8a8"),
+        ] {
+            let board = Board::from_fen(fen).unwrap();
+            let castle = *move_gen
+                .generate_legal_moves_vec(&board)
+                .iter()
+                .find(|mv| mv.is_castle() && mv.to_long_algebraic() == standard)
+                .unwrap();
+            assert_eq!(castle.to_uci(false), standard);
+            assert_eq!(castle.to_uci(true), chess960);
+
+            // both notations are parsed back into the same castling move
+            let expected = board.clone_and_make(&castle, &move_gen).unwrap();
+            for notation in [standard, chess960] {
+                let mut parsed = board.clone();
+                parsed.make_uci_move(notation).unwrap();
+                assert_eq!(
+                    parsed.zobrist_hash(),
+                    expected.zobrist_hash(),
+                    "{}",
+                    notation
+                );
+                assert!(parsed == expected, "{}", notation);
+            }
+        }
+
+        // other moves are written the same way in both modes
+        let board = Board::default_board();
+        for mv in move_gen.generate_legal_moves_vec(&board) {
+            assert_eq!(mv.to_uci(true), mv.to_long_algebraic());
+        }
+    }
+
+    #[test]
+    fn clone_and_make_leaves_original_unchanged() {
+        let board = Board::default_board();
+        let move_gen = MoveGenerator::new();
+        let mut move_list = MoveList::new();
+        move_gen.generate_legal_moves(&board, &mut move_list);
+        let mv = move_list.find_uci("e2e4").unwrap();
+
+        let fen = board.to_fen();
+        let hash = board.zobrist_hash();
+        let new_board = board.clone_and_make(mv, &move_gen).unwrap();
+
+        assert_eq!(board.to_fen(), fen);
+        assert_eq!(board.zobrist_hash(), hash);
+        assert_eq!(
+            new_board.to_fen(),
+            "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+        );
+        assert_ne!(new_board.zobrist_hash(), hash);
+    }
+
     #[test]
     fn test_making_en_passant_move() {
         let mut board = Board::from_fen("8/2k5/8/2Pp3r/K7/8/8/8 w - d6 0 1").unwrap();
@@ -71,7 +71,12 @@ impl PromotionDescriptor {
 pub enum MoveType {
     Quiet,
     Capture,
+    /// Non-capturing moves (including promotions) that give check.
+    QuietChecks,
     All,
+    /// Legal moves that get the side to move out of check. This is only meaningful when the side
+    /// to move is in check, otherwise no moves are generated.
+    Evasion,
 }
 
 /// Compact, 32-bit move representation
@@ -80,15 +85,17 @@ pub enum MoveType {
 #[derive(Default, Debug, Clone, Copy)]
 pub struct Move {
     /// The move information, from LSB to MSB:
-    /// The first 2 bits represent the move descriptor
-    /// The next 1 bit tells us if the move is a promotion or not
-    /// The next 2 bits represent the promotion descriptor
-    /// The next 6 bits represent the to square.
-    /// The next 6 bits represent the from square.
+    /// The first 2 bits represent the move descriptor (see [`MoveDescriptor`]).
+    /// The next 1 bit tells us if the move is a promotion or not.
+    /// The next 2 bits represent the promotion descriptor (see [`PromotionDescriptor`]).
+    /// The next 6 bits represent the to square index.
+    /// The next 6 bits represent the from square index.
     /// The next 3 bits represent the piece doing the move.
-    /// The next 3 bits represent the captured piece (if any).
+    /// The next 3 bits represent the captured piece ([`Piece::None`] if there is no capture).
     /// The last 9 bits are unused.
-    /// 000 000 000 ccc ppp fffff tttttt pp P mm
+    /// 000000000 ccc ppp ffffff tttttt pp P mm
+    ///
+    /// A move with all bits set to zero is a null move.
     move_info: u32,
 }
 
@@ -120,6 +127,15 @@ impl PartialOrd for Move {
 
 impl Move {
     /// Creates a new [`Move`].
+    ///
+    /// # Arguments
+    ///
+    /// - `from` - The square the piece moves from.
+    /// - `to` - The square the piece moves to.
+    /// - `descriptor` - Describes special moves (en passant, castling and pawn double pushes).
+    /// - `piece` - The piece that is moving.
+    /// - `captured_piece` - The piece that is captured, if any. For en passant this is [`Piece::Pawn`].
+    /// - `promotion_piece` - The piece to promote to, if any. Must be a queen, rook, bishop or knight.
     pub fn new(
         from: &Square,
         to: &Square,
@@ -131,6 +147,13 @@ impl Move {
         let from_index = to_square(from.file as u8, from.rank as u8) as u32;
         let to_index = to_square(to.file as u8, to.rank as u8) as u32;
 
+        debug_assert!(
+            promotion_piece.is_none_or(|p| matches!(
+                p,
+                Piece::Queen | Piece::Rook | Piece::Bishop | Piece::Knight
+            )),
+            "Invalid promotion piece"
+        );
         let is_promotion = promotion_piece.is_some();
         let promotion_descriptor = match promotion_piece {
             Some(Piece::Queen) => PromotionDescriptor::Queen as u32,
@@ -151,9 +174,14 @@ impl Move {
         Self { move_info }
     }
 
+    /// The null move, which passes the turn to the opponent without moving a piece. All of its
+    /// move information is zero, so its from and to squares are both `a1`; no real move can have
+    /// that. Prints as `0000` in long algebraic notation, following the UCI convention.
+    pub const NULL: Move = Move { move_info: 0 };
+
     /// Checks if the underlying move information is valid (i.e. non-zero).
     pub fn is_valid(&self) -> bool {
-        !self.is_null_move()
+        !self.is_null()
     }
 
     /// Create a new castle move
@@ -184,12 +212,14 @@ impl Move {
         )
     }
 
-    /// Returns the from [`Square`] of the move.
+    /// Returns the square index (0 - 63) the piece moves from.
+    /// Use [`Square::from_square_index`] to convert it to a [`Square`].
     pub fn from(&self) -> u8 {
         ((self.move_info & MOVE_INFO_FROM_MASK) >> MOVE_INFO_FROM_SHIFT) as u8
     }
 
-    /// Returns the to [`Square`] of the move.
+    /// Returns the square index (0 - 63) the piece moves to.
+    /// Use [`Square::from_square_index`] to convert it to a [`Square`].
     pub fn to(&self) -> u8 {
         ((self.move_info & MOVE_INFO_TO_MASK) >> MOVE_INFO_TO_SHIFT) as u8
     }
@@ -220,7 +250,7 @@ impl Move {
         self.move_descriptor() == MoveDescriptor::PawnTwoUp
     }
 
-    /// Returns the promotion descriptor of the move.
+    /// Returns the promotion descriptor of the move. Only meaningful if [`Move::is_promotion`] is true.
     pub fn promotion_description(&self) -> PromotionDescriptor {
         match (self.move_info & MOVE_PROMOTION_DESCRIPTOR_MASK)
             >> MOVE_INFO_PROMOTION_DESCRIPTOR_SHIFT
@@ -273,6 +303,7 @@ impl Move {
         }
     }
 
+    /// Checks if the move is quiet, meaning it is neither a capture nor a promotion.
     pub fn is_quiet(&self) -> bool {
         let mv_desc = self.move_descriptor();
         mv_desc != MoveDescriptor::EnPassantCapture
@@ -280,6 +311,7 @@ impl Move {
             && !self.is_promotion()
     }
 
+    /// Checks if the move captures a piece, including en passant captures.
     pub fn is_capture(&self) -> bool {
         self.captured_piece_value() != Piece::None as u32 || self.is_en_passant_capture()
     }
@@ -306,15 +338,21 @@ impl Move {
         Piece::try_from(piece_value as u8).unwrap()
     }
 
-    /// Return true if the move is a null move
-    pub(crate) fn is_null_move(&self) -> bool {
+    /// Returns true if this is the null move, see [`Move::NULL`].
+    pub fn is_null(&self) -> bool {
         // this is the default value, and should be interpreted as a null move
         // the reason for this is that a move at a minimum should always have a to and from square
         // and a piece. So if there is no information about the move, it is a null move
-        self.move_info == 0
+        self.move_info == Move::NULL.move_info
     }
 
+    /// Returns the move in long algebraic notation as used by UCI, i.e. `e2e4` or `a7a8q`.
+    /// The null move is `0000`.
     pub fn to_long_algebraic(&self) -> String {
+        if self.is_null() {
+            return "0000".to_string();
+        }
+
         let from = SQUARE_NAME[self.from() as usize];
         let to = SQUARE_NAME[self.to() as usize];
         // handle promotion too
@@ -328,13 +366,73 @@ impl Move {
         .trim()
         .to_string()
     }
+
+    /// Returns `true` if `long_algebraic` is this move in long algebraic notation, see
+    /// [`Move::to_long_algebraic`]. Unlike comparing with [`Move::to_long_algebraic`], this doesn't
+    /// allocate a string. The promotion piece may be upper or lower case.
+    pub fn matches_long_algebraic(&self, long_algebraic: &str) -> bool {
+        if self.is_null() {
+            return long_algebraic == "0000";
+        }
+
+        let squares_match = long_algebraic.get(0..2) == Some(SQUARE_NAME[self.from() as usize])
+            && long_algebraic.get(2..4) == Some(SQUARE_NAME[self.to() as usize]);
+        let mut promotion = long_algebraic.get(4..).unwrap_or_default().chars();
+        let promotion_matches = match self.promotion_piece() {
+            Some(piece) => {
+                promotion.next().map(|c| c.to_ascii_lowercase()) == Some(piece.as_char())
+                    && promotion.next().is_none()
+            }
+            None => promotion.next().is_none(),
+        };
+        squares_match && promotion_matches
+    }
+
+    /// Returns the move in UCI notation. In Chess960 mode UCI writes castling as the king
+    /// capturing its own rook, i.e. `e1h1` instead of `e1g1`. Otherwise this is the same as
+    /// [`Move::to_long_algebraic`].
+    ///
+    /// # Arguments
+    ///
+    /// - `chess960` - Whether to use the Chess960 castling notation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::{definitions::Squares, moves::Move, square::Square};
+    ///
+    /// let castle = Move::new_castle(
+    ///     &Square::from_square_index(Squares::E1),
+    ///     &Square::from_square_index(Squares::C1),
+    /// );
+    /// assert_eq!(castle.to_uci(false), "e1c1");
+    /// assert_eq!(castle.to_uci(true), "e1a1");
+    /// ```
+    pub fn to_uci(&self, chess960: bool) -> String {
+        if chess960 && self.is_castle() {
+            // the rooks start in the corners, next to the king's target square on the king side
+            // and two squares further on the queen side
+            let rook_square = if self.to() > self.from() {
+                self.to() + 1
+            } else {
+                self.to() - 2
+            };
+            return format!(
+                "{}{}",
+                SQUARE_NAME[self.from() as usize],
+                SQUARE_NAME[rook_square as usize]
+            );
+        }
+
+        self.to_long_algebraic()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::file::File;
     use crate::moves::{Move, MoveDescriptor};
-    use crate::pieces::Piece;
+    use crate::pieces::{Piece, ALL_PIECES};
     use crate::rank::Rank;
     use crate::square::Square;
     #[test]
@@ -506,7 +604,7 @@ mod tests {
         assert!(!mv.is_pawn_two_up());
         assert!(!mv.is_castle());
         assert!(!mv.is_promotion());
-        assert!(!mv.is_null_move());
+        assert!(!mv.is_null());
         assert!(mv.move_descriptor() == MoveDescriptor::None);
         assert_eq!(mv.from(), from.to_square_index());
         assert_eq!(mv.to(), to.to_square_index());
@@ -525,9 +623,118 @@ mod tests {
         assert!(mv.is_pawn_two_up());
         assert!(!mv.is_castle());
         assert!(!mv.is_promotion());
-        assert!(!mv.is_null_move());
+        assert!(!mv.is_null());
         assert!(mv.move_descriptor() == MoveDescriptor::PawnTwoUp);
         assert_eq!(mv.from(), from.to_square_index());
         assert_eq!(mv.to(), to.to_square_index());
     }
+
+    #[test]
+    fn matches_long_algebraic_without_allocating() {
+        let e2 = Square::try_from("e2").unwrap();
+        let e4 = Square::try_from("e4").unwrap();
+        let push = Move::new(&e2, &e4, MoveDescriptor::PawnTwoUp, Piece::Pawn, None, None);
+        assert!(push.matches_long_algebraic("e2e4"));
+        assert!(!push.matches_long_algebraic("e2e3"));
+        assert!(!push.matches_long_algebraic("e2e4q"));
+        assert!(!push.matches_long_algebraic("e2"));
+
+        let a7 = Square::try_from("a7").unwrap();
+        let a8 = Square::try_from("a8").unwrap();
+        let promotion = Move::new(
+            &a7,
+            &a8,
+            MoveDescriptor::None,
+            Piece::Pawn,
+            None,
+            Some(Piece::Knight),
+        );
+        assert!(promotion.matches_long_algebraic("a7a8n"));
+        assert!(promotion.matches_long_algebraic("a7a8N"));
+        assert!(!promotion.matches_long_algebraic("a7a8"));
+        assert!(!promotion.matches_long_algebraic("a7a8q"));
+        assert!(!promotion.matches_long_algebraic("a7a8nn"));
+
+        assert!(Move::NULL.matches_long_algebraic("0000"));
+    }
+
+    #[test]
+    fn null_move() {
+        assert!(Move::NULL.is_null());
+        assert!(Move::default().is_null());
+        assert!(!Move::NULL.is_valid());
+        assert_eq!(Move::NULL.to_long_algebraic(), "0000");
+
+        // a real move from and to a1 can't exist, but any other move information makes it non-null
+        let a1 = Square::from_square_index(0);
+        let mv = Move::new(&a1, &a1, MoveDescriptor::None, Piece::Rook, None, None);
+        assert!(!mv.is_null());
+        assert_ne!(mv, Move::NULL);
+    }
+
+    #[test]
+    fn accessors_round_trip() {
+        let descriptors = [
+            MoveDescriptor::None,
+            MoveDescriptor::EnPassantCapture,
+            MoveDescriptor::Castle,
+            MoveDescriptor::PawnTwoUp,
+        ];
+        let captures = [None]
+            .into_iter()
+            .chain(ALL_PIECES.into_iter().map(Some))
+            .collect::<Vec<_>>();
+        let promotions = [
+            None,
+            Some(Piece::Queen),
+            Some(Piece::Rook),
+            Some(Piece::Bishop),
+            Some(Piece::Knight),
+        ];
+
+        for from in 0..64u8 {
+            for to in 0..64u8 {
+                let from_square = Square::from_square_index(from);
+                let to_square = Square::from_square_index(to);
+                for descriptor in descriptors {
+                    for piece in ALL_PIECES {
+                        for captured in &captures {
+                            for promotion in promotions {
+                                let m = Move::new(
+                                    &from_square,
+                                    &to_square,
+                                    descriptor,
+                                    piece,
+                                    *captured,
+                                    promotion,
+                                );
+                                assert_eq!(m.from(), from);
+                                assert_eq!(m.to(), to);
+                                assert_eq!(m.move_descriptor(), descriptor);
+                                assert_eq!(m.piece(), piece);
+                                assert_eq!(m.captured_piece(), *captured);
+                                assert_eq!(m.promotion_piece(), promotion);
+                                assert_eq!(m.is_promotion(), promotion.is_some());
+                                assert_eq!(
+                                    m.is_en_passant_capture(),
+                                    descriptor == MoveDescriptor::EnPassantCapture
+                                );
+                                assert_eq!(m.is_castle(), descriptor == MoveDescriptor::Castle);
+                                assert_eq!(
+                                    m.is_pawn_two_up(),
+                                    descriptor == MoveDescriptor::PawnTwoUp
+                                );
+                                assert_eq!(
+                                    m.is_capture(),
+                                    captured.is_some()
+                                        || descriptor == MoveDescriptor::EnPassantCapture
+                                );
+                                assert_eq!(m.is_quiet(), !m.is_capture() && promotion.is_none());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
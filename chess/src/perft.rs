@@ -12,6 +12,8 @@
  *
  */
 
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use crate::{board::Board, move_generation::MoveGenerator, move_list::MoveList, moves::Move};
 use anyhow::{bail, Result};
 
@@ -115,12 +117,313 @@ pub fn perft(
     Ok(nodes)
 }
 
+/// Perform perft by copying the board for every move instead of making and unmaking moves on a
+/// single board. This is the simple reference implementation that [`perft`] is checked against
+/// while the make/unmake path is still being optimized, it will be removed once that has settled.
+///
+/// # Arguments
+///
+/// - `board` - The board to perform perft on.
+/// - `move_gen` - The move generator to use.
+/// - `depth` - The depth to perform perft to.
+///
+/// # Returns
+///
+/// The number of leaf nodes.
+///
+/// # Errors
+///
+/// Returns an error if a generated move could not be made.
+pub fn perft_copy_make(board: &Board, move_gen: &MoveGenerator, depth: usize) -> Result<u64> {
+    if depth == 0 {
+        return Ok(1);
+    }
+
+    let mut move_list = MoveList::new();
+    move_gen.generate_legal_moves(board, &mut move_list);
+    if depth == 1 {
+        return Ok(move_list.len() as u64);
+    }
+
+    let mut nodes = 0;
+    for mv in move_list.iter() {
+        let mut child = board.clone();
+        child.make_move_unchecked(mv)?;
+        nodes += perft_copy_make(&child, move_gen, depth - 1)?;
+    }
+
+    Ok(nodes)
+}
+
+/// Perform perft while checking the zobrist hash at every node. After each move the incrementally
+/// updated hash is compared to a hash computed from scratch, and after unmaking the move the board
+/// and its hash must match the original position. This is much slower than [`perft`], but catches
+/// make/unmake and incremental hashing bugs that do not show up in the node counts.
+///
+/// # Arguments
+///
+/// - `board` - The board to perform perft on.
+/// - `move_gen` - The move generator to use.
+/// - `depth` - The depth to perform perft to.
+///
+/// # Returns
+///
+/// The number of leaf nodes.
+///
+/// # Errors
+///
+/// Returns an error describing the move and position if a hash or board mismatch is found.
+pub fn perft_hash_check(board: &mut Board, move_gen: &MoveGenerator, depth: usize) -> Result<u64> {
+    if depth == 0 {
+        return Ok(1);
+    }
+
+    let mut move_list = MoveList::new();
+    move_gen.generate_legal_moves(board, &mut move_list);
+
+    let original = board.clone();
+    let mut nodes = 0;
+    for mv in move_list.iter() {
+        board.make_move_unchecked(mv)?;
+        let expected_hash = board.compute_zobrist_hash();
+        if board.zobrist_hash() != expected_hash {
+            bail!(
+                "hash mismatch after {} in {}: incremental {:#x} != computed {:#x}",
+                mv.to_long_algebraic(),
+                original.to_fen(),
+                board.zobrist_hash(),
+                expected_hash
+            );
+        }
+
+        nodes += perft_hash_check(board, move_gen, depth - 1)?;
+        board.unmake_move()?;
+        if board.zobrist_hash() != original.zobrist_hash() || *board != original {
+            bail!(
+                "unmaking {} did not restore {}, got {}",
+                mv.to_long_algebraic(),
+                original.to_fen(),
+                board.to_fen()
+            );
+        }
+    }
+
+    Ok(nodes)
+}
+
+/// Parses a line of a perft EPD file into the FEN and the expected node counts. Entries are
+/// separated by `;` and the depth may be written with or without a `D` prefix, so both
+/// `fen ;D1 20 ;D2 400` and `fen ;1 20 ;2 400` are accepted. Whitespace around the separators is
+/// ignored.
+///
+/// # Arguments
+///
+/// - `line` - The EPD line to parse.
+///
+/// # Returns
+///
+/// The FEN and a list of `(depth, expected nodes)` pairs, in the order they appear in the line.
+///
+/// # Errors
+///
+/// Returns an error if the FEN is missing or an entry is not a depth followed by a node count.
+///
+/// # Examples
+///
+/// ```
+/// use chess::perft::parse_perft_epd_line;
+///
+/// let (fen, entries) = parse_perft_epd_line("4k3/8/8/8/8/8/8/4K2R w K - 0 1 ;D1 15 ;D2 66").unwrap();
+/// assert_eq!(fen, "4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+/// assert_eq!(entries, vec![(1, 15), (2, 66)]);
+/// ```
+pub fn parse_perft_epd_line(line: &str) -> Result<(String, Vec<(usize, u64)>)> {
+    let mut parts = line.split(';');
+    let fen = parts.next().unwrap_or_default().trim();
+    if fen.is_empty() {
+        bail!("missing FEN in perft EPD line \"{}\"", line);
+    }
+
+    let mut entries = Vec::new();
+    for part in parts.map(str::trim).filter(|part| !part.is_empty()) {
+        let tokens = part.split_whitespace().collect::<Vec<_>>();
+        let [depth, nodes] = tokens.as_slice() else {
+            bail!(
+                "invalid perft entry \"{}\", expected a depth and a node count",
+                part
+            );
+        };
+
+        let depth = depth
+            .strip_prefix(['D', 'd'])
+            .unwrap_or(depth)
+            .parse::<usize>()
+            .map_err(|e| anyhow::anyhow!("invalid depth in perft entry \"{}\": {}", part, e))?;
+        let nodes = nodes.parse::<u64>().map_err(|e| {
+            anyhow::anyhow!("invalid node count in perft entry \"{}\": {}", part, e)
+        })?;
+        entries.push((depth, nodes));
+    }
+
+    Ok((fen.to_string(), entries))
+}
+
+/// The outcome of a cancellable perft run, see [`perft_with_callback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerftOutcome {
+    /// The run finished and this is the full node count.
+    Complete(u64),
+    /// The run was stopped early. This is only the number of nodes counted before stopping.
+    Cancelled(u64),
+}
+
+impl PerftOutcome {
+    /// Returns the number of nodes counted, which is partial if the run was cancelled.
+    pub fn nodes(&self) -> u64 {
+        match self {
+            PerftOutcome::Complete(nodes) | PerftOutcome::Cancelled(nodes) => *nodes,
+        }
+    }
+
+    /// Returns `true` if the run finished without being cancelled.
+    pub fn is_complete(&self) -> bool {
+        matches!(self, PerftOutcome::Complete(_))
+    }
+}
+
+/// Perform perft that can be cancelled and reports progress after every root move.
+///
+/// # Arguments
+///
+/// - `board` - The board to perform perft on.
+/// - `move_gen` - The move generator to use.
+/// - `depth` - The depth to perform perft to.
+/// - `stop` - Set this to `true` from another thread to cancel the run.
+/// - `on_root` - Called with each root move and its node count once that move is fully searched.
+///
+/// # Returns
+///
+/// [`PerftOutcome::Complete`] with the total node count, or [`PerftOutcome::Cancelled`] with the
+/// nodes counted so far if `stop` was set. The board is restored to its original state either way.
+pub fn perft_with_callback(
+    board: &mut Board,
+    move_gen: &MoveGenerator,
+    depth: usize,
+    stop: &AtomicBool,
+    mut on_root: impl FnMut(Move, u64),
+) -> Result<PerftOutcome> {
+    if depth == 0 {
+        return Ok(PerftOutcome::Complete(1));
+    }
+
+    let mut move_list = MoveList::new();
+    move_gen.generate_legal_moves(board, &mut move_list);
+
+    let mut total = 0;
+    for mv in move_list.iter() {
+        if stop.load(Ordering::Relaxed) {
+            return Ok(PerftOutcome::Cancelled(total));
+        }
+
+        board.make_move_unchecked(mv)?;
+        let mut nodes = 0;
+        let completed = perft_until_stopped(board, move_gen, depth - 1, stop, &mut nodes);
+        board.unmake_move()?;
+        total += nodes;
+
+        if !completed? {
+            return Ok(PerftOutcome::Cancelled(total));
+        }
+        on_root(*mv, nodes);
+    }
+
+    Ok(PerftOutcome::Complete(total))
+}
+
+/// Counts leaf nodes into `nodes`, returning `false` if `stop` was set before finishing.
+fn perft_until_stopped(
+    board: &mut Board,
+    move_gen: &MoveGenerator,
+    depth: usize,
+    stop: &AtomicBool,
+    nodes: &mut u64,
+) -> Result<bool> {
+    if depth == 0 {
+        *nodes += 1;
+        return Ok(true);
+    }
+
+    let mut move_list = MoveList::new();
+    move_gen.generate_legal_moves(board, &mut move_list);
+
+    if depth == 1 {
+        // bulk counting
+        *nodes += move_list.len() as u64;
+        return Ok(true);
+    }
+
+    for mv in move_list.iter() {
+        if stop.load(Ordering::Relaxed) {
+            return Ok(false);
+        }
+        board.make_move_unchecked(mv)?;
+        let completed = perft_until_stopped(board, move_gen, depth - 1, stop, nodes);
+        board.unmake_move()?;
+        if !completed? {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::side::Side;
+    use crate::{
+        definitions::{MAX_LEGAL_MOVES, MAX_PSEUDO_LEGAL_MOVES},
+        moves::MoveType,
+        side::Side,
+    };
 
     use super::*;
 
+    #[test]
+    fn parse_perft_epd_lines() {
+        let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let expected = (fen.to_string(), vec![(1, 20), (2, 400)]);
+        for line in [
+            format!("{} ;D1 20 ;D2 400", fen),
+            format!("{} ;1 20 ;2 400", fen),
+            format!("{}; D1 20; D2 400", fen),
+            format!("  {} ;D1 20;D2 400 ; ", fen),
+        ] {
+            assert_eq!(parse_perft_epd_line(&line).unwrap(), expected, "{}", line);
+        }
+
+        // a FEN without any entries is fine
+        assert_eq!(
+            parse_perft_epd_line(fen).unwrap(),
+            (fen.to_string(), Vec::new())
+        );
+    }
+
+    #[test]
+    fn parse_malformed_perft_epd_lines() {
+        let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        for line in [
+            String::new(),
+            " ;D1 20".to_string(),
+            format!("{} ;D1", fen),
+            format!("{} ;D1 20 400", fen),
+            format!("{} ;Dx 20", fen),
+            format!("{} ;D1 -20", fen),
+            format!("{} ;X1 20", fen),
+            format!("{} ;D1 20 ;D2", fen),
+        ] {
+            assert!(parse_perft_epd_line(&line).is_err(), "{}", line);
+        }
+    }
+
     #[test]
     fn default_board() {
         let mut board = Board::default_board();
@@ -751,7 +1054,13 @@ mod tests {
         let mut move_list = MoveList::new();
 
         move_gen.generate_legal_moves(&board, &mut move_list);
-        assert_eq!(move_list.len(), 218);
+        assert_eq!(move_list.len(), MAX_LEGAL_MOVES);
+
+        // the pseudo-legal moves of the same position also fit, and are never fewer
+        move_list.clear();
+        move_gen.generate_moves(&board, &mut move_list, MoveType::All);
+        assert!(move_list.len() >= MAX_LEGAL_MOVES);
+        assert!(move_list.len() <= MAX_PSEUDO_LEGAL_MOVES);
     }
 
     #[test]
@@ -787,4 +1096,88 @@ mod tests {
         let move_gen = MoveGenerator::new();
         run_epd_test(&tests, &move_gen);
     }
+
+    #[test]
+    fn perft_with_callback_reports_root_moves() {
+        let mut board = Board::default_board();
+        let move_gen = MoveGenerator::new();
+        let stop = AtomicBool::new(false);
+        let mut roots = Vec::new();
+        let outcome = perft_with_callback(&mut board, &move_gen, 3, &stop, |mv, nodes| {
+            roots.push((mv, nodes))
+        })
+        .unwrap();
+
+        assert_eq!(outcome, PerftOutcome::Complete(8902));
+        assert_eq!(roots.len(), 20);
+        assert_eq!(roots.iter().map(|(_, nodes)| nodes).sum::<u64>(), 8902);
+
+        let split = split_perft(&mut board, &move_gen, 3, false).unwrap();
+        for result in split {
+            assert!(roots.contains(&(result.mv, result.nodes)));
+        }
+    }
+
+    #[test]
+    fn perft_with_callback_can_be_cancelled() {
+        let mut board = Board::default_board();
+        let fen = board.to_fen();
+        let move_gen = MoveGenerator::new();
+        let stop = AtomicBool::new(false);
+        let mut reported = 0;
+        let outcome = perft_with_callback(&mut board, &move_gen, 3, &stop, |_, nodes| {
+            reported += nodes;
+            // cancel after the first root move
+            stop.store(true, Ordering::Relaxed);
+        })
+        .unwrap();
+
+        assert!(!outcome.is_complete());
+        assert_eq!(outcome.nodes(), reported);
+        assert!(outcome.nodes() < 8902);
+        // the board is left untouched
+        assert_eq!(board.to_fen(), fen);
+
+        // already stopped before starting
+        let outcome = perft_with_callback(&mut board, &move_gen, 3, &stop, |_, _| {}).unwrap();
+        assert_eq!(outcome, PerftOutcome::Cancelled(0));
+    }
+
+    #[test]
+    fn copy_make_matches_make_unmake() {
+        let move_gen = MoveGenerator::new();
+        let path = format!("{}/../data/standard.epd", env!("CARGO_MANIFEST_DIR"));
+        let epd = std::fs::read_to_string(path).unwrap();
+        for line in epd.lines().filter(|line| !line.trim().is_empty()) {
+            let (fen, entries) = parse_perft_epd_line(line).unwrap();
+            let mut board = Board::from_fen(&fen).unwrap();
+            for (depth, expected) in entries.into_iter().filter(|(depth, _)| *depth <= 3) {
+                let make_unmake = perft(&mut board, &move_gen, depth, false).unwrap();
+                let copy_make = perft_copy_make(&board, &move_gen, depth).unwrap();
+                assert_eq!(make_unmake, expected, "{} depth {}", fen, depth);
+                assert_eq!(copy_make, make_unmake, "{} depth {}", fen, depth);
+            }
+        }
+    }
+
+    #[test]
+    fn perft_hash_check_matches_perft() {
+        let move_gen = MoveGenerator::new();
+        for fen in [
+            crate::definitions::DEFAULT_FEN,
+            // castling, en passant and promotions
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        ] {
+            let mut board = Board::from_fen(fen).unwrap();
+            let expected = perft(&mut board, &move_gen, 3, false).unwrap();
+            assert_eq!(
+                perft_hash_check(&mut board, &move_gen, 3).unwrap(),
+                expected,
+                "{}",
+                fen
+            );
+        }
+    }
 }
@@ -38,6 +38,12 @@ pub const PIECE_NAMES: [&str; NumberOf::PIECE_TYPES] =
 pub const PIECE_SHORT_NAMES: [char; NumberOf::PIECE_TYPES + 1] =
     ['K', 'Q', 'R', 'B', 'N', 'P', ' '];
 
+/// Material values of the pieces in centipawns. Use [Piece] to index into this array.
+///
+/// These are the canonical material values for tools, GUIs and exchange evaluation. The king has
+/// no material value as it can never be captured.
+pub const PIECE_VALUES: [i32; NumberOf::PIECE_TYPES] = [0, 900, 500, 330, 320, 100];
+
 pub const SLIDER_PIECES: [Piece; 3] = [Piece::Rook, Piece::Bishop, Piece::Queen];
 pub const ALL_PIECES: [Piece; 6] = [
     Piece::King,
@@ -131,6 +137,16 @@ impl Piece {
         self.is_rook() || self.is_bishop() || self.is_queen()
     }
 
+    /// Returns the material value of the piece in centipawns (see [`PIECE_VALUES`]).
+    /// [`Piece::None`] has no value.
+    #[must_use]
+    pub fn value(&self) -> i32 {
+        match self {
+            Piece::None => 0,
+            _ => PIECE_VALUES[*self as usize],
+        }
+    }
+
     /// Returns the short name of the piece as a lowercase character.
     pub fn as_char(&self) -> char {
         PIECE_SHORT_NAMES[*self as usize].to_ascii_lowercase()
@@ -181,3 +197,23 @@ impl TryFrom<char> for Piece {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn piece_value_ordering() {
+        assert!(Piece::Queen.value() > Piece::Rook.value());
+        assert!(Piece::Rook.value() > Piece::Bishop.value());
+        assert!(Piece::Bishop.value() >= Piece::Knight.value());
+        assert!((Piece::Bishop.value() - Piece::Knight.value()).abs() <= Piece::Pawn.value() / 2);
+        assert!(Piece::Knight.value() > Piece::Pawn.value());
+        assert_eq!(Piece::King.value(), 0);
+        assert_eq!(Piece::None.value(), 0);
+
+        for piece in ALL_PIECES {
+            assert_eq!(piece.value(), PIECE_VALUES[piece as usize]);
+        }
+    }
+}
@@ -14,7 +14,7 @@
 
 use std::ops::Sub;
 
-use crate::side::Side;
+use crate::side::PlayerSide;
 use anyhow::Result;
 
 /// Represents a rank on the chess board.
@@ -33,20 +33,18 @@ pub enum Rank {
 
 impl Rank {
     /// Returns the rank of the promotion square for the given side.
-    pub const fn promotion_rank(side: Side) -> Rank {
+    pub const fn promotion_rank(side: PlayerSide) -> Rank {
         match side {
-            Side::White => Rank::R8,
-            Side::Black => Rank::R1,
-            _ => Rank::R1,
+            PlayerSide::White => Rank::R8,
+            PlayerSide::Black => Rank::R1,
         }
     }
 
     /// Returns the starting rank for pawns of a given side.
-    pub const fn pawn_start_rank(side: Side) -> Rank {
+    pub const fn pawn_start_rank(side: PlayerSide) -> Rank {
         match side {
-            Side::White => Rank::R2,
-            Side::Black => Rank::R7,
-            _ => panic!("Invalid side"),
+            PlayerSide::White => Rank::R2,
+            PlayerSide::Black => Rank::R7,
         }
     }
 
@@ -55,6 +53,46 @@ impl Rank {
         *self as u8
     }
 
+    /// Parses a rank from its character, `1` - `8`.
+    ///
+    /// Returns `None` if the character is not a valid rank.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chess::rank::Rank;
+    ///
+    /// assert_eq!(Rank::from_char('4'), Some(Rank::R4));
+    /// assert_eq!(Rank::from_char('9'), None);
+    /// ```
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '1' => Some(Self::R1),
+            '2' => Some(Self::R2),
+            '3' => Some(Self::R3),
+            '4' => Some(Self::R4),
+            '5' => Some(Self::R5),
+            '6' => Some(Self::R6),
+            '7' => Some(Self::R7),
+            '8' => Some(Self::R8),
+            _ => None,
+        }
+    }
+
+    /// Returns the character representation of the rank, `1` - `8`.
+    pub fn to_char(&self) -> char {
+        match self {
+            Self::R1 => '1',
+            Self::R2 => '2',
+            Self::R3 => '3',
+            Self::R4 => '4',
+            Self::R5 => '5',
+            Self::R6 => '6',
+            Self::R7 => '7',
+            Self::R8 => '8',
+        }
+    }
+
     /// Offset the rank by the given delta.
     ///
     /// Returns `None` if the resulting rank is out of bounds.
@@ -97,6 +135,14 @@ impl TryFrom<u8> for Rank {
     }
 }
 
+impl TryFrom<char> for Rank {
+    type Error = anyhow::Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        Self::from_char(value).ok_or_else(|| anyhow::Error::msg(format!("Invalid rank {}", value)))
+    }
+}
+
 impl Sub for Rank {
     type Output = i8;
 
@@ -116,4 +162,21 @@ mod tests {
         assert_eq!(Rank::R8.offset(1), None);
         assert_eq!(Rank::R8.offset(-1), Some(Rank::R7));
     }
+
+    #[test]
+    fn char_conversions() {
+        for (i, c) in ('1'..='8').enumerate() {
+            let rank = Rank::from_char(c).unwrap();
+            assert_eq!(rank.as_number() as usize, i);
+            assert_eq!(rank.to_char(), c);
+            assert_eq!(Rank::try_from(c).unwrap(), rank);
+            assert_eq!(Rank::try_from(i as u8).unwrap(), rank);
+        }
+
+        for c in ['0', '9', 'a', ' '] {
+            assert_eq!(Rank::from_char(c), None);
+            assert!(Rank::try_from(c).is_err());
+        }
+        assert!(Rank::try_from(8_u8).is_err());
+    }
 }
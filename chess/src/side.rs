@@ -4,7 +4,7 @@
  * Created Date: Monday, November 25th 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Tue Dec 10 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -14,7 +14,8 @@
 
 use std::fmt::Display;
 
-/// Represents a side to play in chess.
+/// Represents a side to play in chess, or both sides at once. Use [`PlayerSide`] when only one of
+/// the two players makes sense, and [`Color`](crate::color::Color) for the color of a square.
 #[repr(usize)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Side {
@@ -86,3 +87,95 @@ impl TryFrom<u8> for Side {
         }
     }
 }
+
+/// Represents one of the two players. Unlike [`Side`], this can never be [`Side::Both`], so it is
+/// used wherever only a playing side makes sense, such as the side to move.
+#[repr(usize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PlayerSide {
+    #[default]
+    White = 0,
+    Black = 1,
+}
+
+impl PlayerSide {
+    /// Returns the opposite side.
+    pub fn opposite(side: PlayerSide) -> PlayerSide {
+        match side {
+            PlayerSide::White => PlayerSide::Black,
+            PlayerSide::Black => PlayerSide::White,
+        }
+    }
+
+    /// Returns `true` if the side is [`White`].
+    ///
+    /// [`White`]: PlayerSide::White
+    #[must_use]
+    pub fn is_white(&self) -> bool {
+        matches!(self, Self::White)
+    }
+
+    /// Returns `true` if the side is [`Black`].
+    ///
+    /// [`Black`]: PlayerSide::Black
+    #[must_use]
+    pub fn is_black(&self) -> bool {
+        matches!(self, Self::Black)
+    }
+}
+
+impl Display for PlayerSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Side::from(*self).fmt(f)
+    }
+}
+
+impl From<PlayerSide> for Side {
+    fn from(side: PlayerSide) -> Self {
+        match side {
+            PlayerSide::White => Side::White,
+            PlayerSide::Black => Side::Black,
+        }
+    }
+}
+
+impl TryFrom<Side> for PlayerSide {
+    type Error = ();
+
+    fn try_from(side: Side) -> Result<Self, Self::Error> {
+        match side {
+            Side::White => Ok(Self::White),
+            Side::Black => Ok(Self::Black),
+            Side::Both => Err(()),
+        }
+    }
+}
+
+impl PartialEq<Side> for PlayerSide {
+    fn eq(&self, other: &Side) -> bool {
+        Side::from(*self) == *other
+    }
+}
+
+impl PartialEq<PlayerSide> for Side {
+    fn eq(&self, other: &PlayerSide) -> bool {
+        *self == Side::from(*other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PlayerSide, Side};
+
+    #[test]
+    fn player_side_conversions() {
+        for side in [PlayerSide::White, PlayerSide::Black] {
+            let general: Side = side.into();
+            assert_eq!(PlayerSide::try_from(general), Ok(side));
+            assert_eq!(side, general);
+            assert_eq!(Side::opposite(general), PlayerSide::opposite(side));
+            assert_eq!(side as usize, general as usize);
+        }
+        assert!(PlayerSide::try_from(Side::Both).is_err());
+    }
+}
@@ -192,13 +192,10 @@ impl TryFrom<&str> for Square {
             )));
         }
 
-        // file can match directly to a char so we don't alter it
-        let file = value.chars().nth(0).unwrap();
-        // read the raw rank value (1-8)
-        let rank = value.chars().nth(1).unwrap();
-        // rank values are 1-8, so we need to convert to 0-7
-        let rank_digit = rank.to_digit(10).unwrap() - 1;
-        Square::from_file_rank(file, rank_digit as u8)
+        let mut chars = value.chars();
+        let file = File::try_from(chars.next().unwrap())?;
+        let rank = Rank::try_from(chars.next().unwrap())?;
+        Ok(Square::new(file, rank))
     }
 }
 
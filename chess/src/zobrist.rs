@@ -16,26 +16,27 @@ use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 
 use crate::definitions::NumberOf;
+use crate::file::File;
+use crate::pieces::Piece;
+use crate::side::PlayerSide;
 
 /// A Zobrist hash value.
 pub type ZobristHash = u64;
 
+/// The random key tables used to compute Zobrist hashes.
+///
+/// The tables are generated from a fixed seed, so every instance holds the same keys. A position's
+/// hash is the XOR of:
+/// - [`ZobristRandomValues::piece_key`] for every piece on the board.
+/// - [`ZobristRandomValues::side_key`] for the side to move.
+/// - [`ZobristRandomValues::castling_key`] for the current castling rights.
+/// - [`ZobristRandomValues::ep_file_key`] for the en passant file (or `None` if there is no en passant square).
+#[derive(Clone)]
 pub struct ZobristRandomValues {
-    pub piece_values: [[[u64; NumberOf::SQUARES]; NumberOf::PIECE_TYPES]; NumberOf::SIDES],
-    pub castling_values: [u64; NumberOf::CASTLING_OPTIONS],
-    pub en_passant_values: [u64; NumberOf::SQUARES + 1],
-    pub side_values: [u64; NumberOf::SIDES],
-}
-
-impl Clone for ZobristRandomValues {
-    fn clone(&self) -> Self {
-        Self {
-            piece_values: self.piece_values,
-            castling_values: self.castling_values,
-            en_passant_values: self.en_passant_values,
-            side_values: self.side_values,
-        }
-    }
+    piece_values: [[[u64; NumberOf::SQUARES]; NumberOf::PIECE_TYPES]; NumberOf::SIDES],
+    castling_values: [u64; NumberOf::CASTLING_OPTIONS],
+    en_passant_values: [u64; NumberOf::FILES + 1],
+    side_values: [u64; NumberOf::SIDES],
 }
 
 const RANDOM_SEED: [u8; 32] = [115; 32];
@@ -53,7 +54,7 @@ impl ZobristRandomValues {
         let mut random_values = Self {
             piece_values: [[[0; NumberOf::SQUARES]; NumberOf::PIECE_TYPES]; NumberOf::SIDES],
             castling_values: [0; NumberOf::CASTLING_OPTIONS],
-            en_passant_values: [0; NumberOf::SQUARES + 1],
+            en_passant_values: [0; NumberOf::FILES + 1],
             side_values: [0; NumberOf::SIDES],
         };
 
@@ -86,26 +87,69 @@ impl ZobristRandomValues {
         random_values
     }
 
-    /// Returns the Zobrist hash value for the given piece, side, and square.
-    pub fn get_piece_value(&self, piece: usize, side: usize, square: usize) -> u64 {
-        self.piece_values[side][piece][square]
+    /// Returns the Zobrist key for the given piece of the given side on the given square.
+    ///
+    /// # Arguments
+    ///
+    /// - `piece` - The piece. Must not be [`Piece::None`].
+    /// - `side` - The side the piece belongs to.
+    /// - `square` - The square index (0 - 63) of the piece.
+    pub fn piece_key(&self, piece: Piece, side: PlayerSide, square: u8) -> ZobristHash {
+        self.piece_values[side as usize][piece as usize][square as usize]
+    }
+
+    /// Returns the Zobrist key for the given side to move.
+    pub fn side_key(&self, side: PlayerSide) -> ZobristHash {
+        self.side_values[side as usize]
     }
 
-    /// Returns the Zobrist hash value for the given castling option.
-    pub fn get_castling_value(&self, castling: usize) -> u64 {
-        self.castling_values[castling]
+    /// Returns the Zobrist key for the given castling rights bit mask.
+    pub fn castling_key(&self, rights: u8) -> ZobristHash {
+        self.castling_values[rights as usize]
     }
 
-    /// Returns the Zobrist hash value for the given en passant square.
-    pub fn get_en_passant_value(&self, square: Option<u8>) -> u64 {
-        match square {
-            None => self.en_passant_values[NumberOf::SQUARES],
-            Some(square) => self.en_passant_values[square as usize],
+    /// Returns the Zobrist key for the file of the en passant square. `None` means there is no
+    /// en passant square, which also has a key.
+    pub fn ep_file_key(&self, file: Option<File>) -> ZobristHash {
+        match file {
+            None => self.en_passant_values[NumberOf::FILES],
+            Some(file) => self.en_passant_values[file as usize],
         }
     }
 
-    /// Returns the Zobrist hash value for the given side.
-    pub fn get_side_value(&self, side: usize) -> u64 {
-        self.side_values[side]
+    /// Returns the Zobrist key for the given en passant square, if any.
+    pub(crate) fn en_passant_key(&self, square: Option<u8>) -> ZobristHash {
+        self.ep_file_key(square.map(|sq| File::try_from(sq % NumberOf::FILES as u8).unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ZobristRandomValues;
+    use crate::{board::Board, definitions::NumberOf, pieces::ALL_PIECES, side::PlayerSide};
+
+    #[test]
+    fn start_position_hash_from_keys() {
+        let board = Board::default_board();
+        let keys = ZobristRandomValues::new();
+
+        let mut hash = 0;
+        for side in [PlayerSide::White, PlayerSide::Black] {
+            for piece in ALL_PIECES {
+                let mut bb = *board.piece_bitboard(piece, side);
+                while let Some(square) = bb.pop_lsb() {
+                    hash ^= keys.piece_key(piece, side, square);
+                }
+            }
+        }
+        hash ^= keys.side_key(PlayerSide::White);
+        hash ^= keys.castling_key(board.castling_rights());
+        hash ^= keys.ep_file_key(None);
+
+        assert_eq!(hash, board.zobrist_hash());
+        assert_eq!(
+            NumberOf::CASTLING_OPTIONS - 1,
+            board.castling_rights() as usize
+        );
     }
 }
@@ -1,8 +1,11 @@
+use std::fmt::Display;
+
 use crate::{
     score::{Score, ScoreType},
     tuneable::{ASPIRATION_WINDOW, MIN_ASPIRATION_DEPTH},
 };
 
+#[derive(Clone, Copy, Debug)]
 pub(crate) struct AspirationWindow {
     alpha: Score,
     beta: Score,
@@ -56,17 +59,29 @@ impl AspirationWindow {
 
     pub(crate) fn widen_down(&mut self, score: Score, depth: ScoreType) {
         // Note that we do not alter beta here, as we are widening the window downwards.
-        let margin = Self::window_size(depth) + self.alpha_fails as ScoreType * ASPIRATION_WINDOW;
-        self.alpha = (score - margin).max(-Score::INF);
+        self.alpha = if score.is_mate() {
+            // a mate bound won't settle with a small margin, open the window fully
+            -Score::INF
+        } else {
+            let margin = Self::window_size(depth).0 as i32
+                + self.alpha_fails as i32 * ASPIRATION_WINDOW as i32;
+            let new_alpha = (score.0 as i32 - margin).max(-Score::INF.0 as i32);
+            Score::new(new_alpha as ScoreType)
+        };
         // save that this was a fail low
         self.alpha_fails += 1;
     }
 
     pub(crate) fn widen_up(&mut self, score: Score, depth: ScoreType) {
         // Note that we do not alter alpha here, as we are widening the window upwards.
-        let margin = Self::window_size(depth) + self.beta_fails as ScoreType * ASPIRATION_WINDOW;
-        let new_beta = (score.0 as i32 + margin.0 as i32).min(Score::INF.0 as i32);
-        self.beta = Score::new(new_beta as ScoreType);
+        self.beta = if score.is_mate() {
+            Score::INF
+        } else {
+            let margin = Self::window_size(depth).0 as i32
+                + self.beta_fails as i32 * ASPIRATION_WINDOW as i32;
+            let new_beta = (score.0 as i32 + margin).min(Score::INF.0 as i32);
+            Score::new(new_beta as ScoreType)
+        };
         // save that this was a fail high
         self.beta_fails += 1;
     }
@@ -76,3 +91,35 @@ impl AspirationWindow {
         Score::new(ASPIRATION_WINDOW)
     }
 }
+
+impl Display for AspirationWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.alpha.0, self.beta.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AspirationWindow;
+    use crate::score::Score;
+
+    #[test]
+    fn mate_scores_open_the_window() {
+        let window = AspirationWindow::around(Score::mate_in(3), 5);
+        assert_eq!(window.alpha(), -Score::INF);
+        assert_eq!(window.beta(), Score::INF);
+
+        // failing low with a mated score near -INF must not overflow
+        let mut window = AspirationWindow::around(Score::new(100), 5);
+        window.widen_down(Score::mated_in(4), 5);
+        assert_eq!(window.alpha(), -Score::INF);
+        let mut window = AspirationWindow::around(Score::new(100), 5);
+        window.widen_up(Score::mate_in(4), 5);
+        assert_eq!(window.beta(), Score::INF);
+
+        // regular scores widen by the window size
+        let mut window = AspirationWindow::around(Score::new(100), 5);
+        window.widen_down(Score::new(40), 5);
+        assert!(window.alpha() < Score::new(40) && window.alpha() > -Score::INF);
+    }
+}
@@ -0,0 +1,100 @@
+/*
+ * bad_bishop.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use chess::{
+    bitboard::Bitboard, board::Board, definitions::DARK_SQUARES, pieces::Piece, side::PlayerSide,
+};
+
+use crate::{
+    phased_score::{PhasedScore, S},
+    score::ScoreType,
+    traits::EvalValues,
+};
+
+/// Returns the pawns of `side` that can't advance because the square in front of them is occupied.
+pub(crate) fn blocked_pawns(board: &Board, side: PlayerSide) -> u64 {
+    let pawns = board.piece_bitboard(Piece::Pawn, side).as_number();
+    let occupancy = board.all_pieces().as_number();
+    match side {
+        PlayerSide::White => pawns & (occupancy >> 8),
+        _ => pawns & (occupancy << 8),
+    }
+}
+
+/// Returns how many friendly pawns restrict a bishop on `square`: every pawn of `side` on the
+/// bishop's square color counts once, and blocked ones count twice since they won't get out of the
+/// way.
+pub(crate) fn restricting_pawns(board: &Board, side: PlayerSide, square: u8) -> u32 {
+    let dark = Bitboard::from(DARK_SQUARES).is_square_occupied(square);
+    let same_color = if dark { DARK_SQUARES } else { !DARK_SQUARES };
+    let pawns = board.piece_bitboard(Piece::Pawn, side).as_number() & same_color;
+    pawns.count_ones() + (blocked_pawns(board, side) & same_color).count_ones()
+}
+
+/// Evaluates the bishops of `side`: each bishop is penalized for the friendly pawns on its square
+/// color, see [`restricting_pawns`].
+///
+/// # Arguments
+///
+/// - `board` - The board to evaluate.
+/// - `side` - The side whose bishops are evaluated.
+/// - `values` - The evaluation values to use.
+pub(crate) fn evaluate<Values: EvalValues<ReturnScore = PhasedScore>>(
+    board: &Board,
+    side: PlayerSide,
+    values: &Values,
+) -> PhasedScore {
+    let penalty = values.bad_bishop();
+    let mut score = S(0, 0);
+    let mut bishops = *board.piece_bitboard(Piece::Bishop, side);
+    while let Some(square) = bishops.pop_lsb() {
+        let count = restricting_pawns(board, side, square) as ScoreType;
+        score += S(penalty.mg() * count, penalty.eg() * count);
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use chess::{board::Board, definitions::Squares, side::PlayerSide};
+
+    use super::restricting_pawns;
+    use crate::{evaluation::ByteKnightEvaluation, traits::Eval};
+
+    #[test]
+    fn counts_pawns_on_the_bishops_color() {
+        // the c1 bishop is on a dark square like all white pawns, d4 and e3 are blocked
+        let board = Board::from_fen("4k3/8/8/2Pp4/3Pp3/4P3/8/2B1K3 w - - 0 1").unwrap();
+        assert_eq!(restricting_pawns(&board, PlayerSide::White, Squares::C1), 5);
+        // black's pawns are both blocked on light squares, they don't hinder a dark squared bishop
+        assert_eq!(restricting_pawns(&board, PlayerSide::Black, Squares::C8), 4);
+        assert_eq!(restricting_pawns(&board, PlayerSide::Black, Squares::F8), 0);
+    }
+
+    #[test]
+    fn bad_bishop_scores_worse_than_good_bishop() {
+        let eval = ByteKnightEvaluation::default();
+
+        // the same locked pawn chain with all white pawns on light squares. White's bishop is on
+        // a light square in one position and on a dark square in the other, black's bishop stays.
+        let bad = Board::from_fen("4k3/2b5/8/p1p1p3/PpPpPp2/1P1P1P2/8/3BK3 w - - 0 1").unwrap();
+        let good = Board::from_fen("4k3/2b5/8/p1p1p3/PpPpPp2/1P1P1P2/8/2B1K3 w - - 0 1").unwrap();
+        assert!(eval.eval(&bad) < eval.eval(&good));
+
+        let bad_breakdown = eval.evaluate_debug(&bad);
+        let good_breakdown = eval.evaluate_debug(&good);
+        assert!(bad_breakdown.bad_bishop.mg() < good_breakdown.bad_bishop.mg());
+        assert!(bad_breakdown.bad_bishop.eg() < good_breakdown.bad_bishop.eg());
+    }
+}
@@ -0,0 +1,257 @@
+/*
+ * endgame.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use chess::{
+    bitboard::Bitboard,
+    board::Board,
+    definitions::{NumberOf, DARK_SQUARES},
+    pieces::Piece,
+    side::PlayerSide,
+};
+
+use crate::score::{Score, ScoreType};
+
+/// Base score of a known elementary mate, well above any regular material advantage but far below
+/// the mate scores.
+pub const KNOWN_WIN: Score = Score(10_000);
+/// Bonus per step the lone king is pushed from the center towards the edge.
+const LONE_KING_EDGE: ScoreType = 40;
+/// Bonus per step the lone king is closer to a mating corner in KBNK.
+const KBNK_CORNER: ScoreType = 20;
+/// Bonus per step the kings are closer to each other, the strong king has to help to mate.
+const KING_PROXIMITY: ScoreType = 10;
+
+/// Endgames where the strong side only has to drive the lone enemy king to the right place to mate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ElementaryMate {
+    /// King, bishop and knight against a lone king. Mate is only possible in a corner of the
+    /// bishop's color.
+    Kbnk,
+    /// King and queen against a lone king.
+    Kqk,
+    /// King and rook against a lone king.
+    Krk,
+}
+
+/// Returns a key for the material of `side`, excluding the king. Each piece type has its own four
+/// bits holding the piece count, so two sides have the same key exactly when they have the same
+/// pieces.
+pub fn material_key(board: &Board, side: PlayerSide) -> u32 {
+    [
+        Piece::Pawn,
+        Piece::Knight,
+        Piece::Bishop,
+        Piece::Rook,
+        Piece::Queen,
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(i, piece)| {
+        let count = board
+            .piece_bitboard(piece, side)
+            .number_of_occupied_squares()
+            .min(15);
+        count << (4 * i)
+    })
+    .sum()
+}
+
+/// Returns the material key for the given piece counts, see [`material_key`].
+const fn key(pawns: u32, knights: u32, bishops: u32, rooks: u32, queens: u32) -> u32 {
+    pawns | knights << 4 | bishops << 8 | rooks << 12 | queens << 16
+}
+
+const KBN_KEY: u32 = key(0, 1, 1, 0, 0);
+const KQ_KEY: u32 = key(0, 0, 0, 0, 1);
+const KR_KEY: u32 = key(0, 0, 0, 1, 0);
+
+/// Returns the elementary mate on the board and the side that is mating, if there is one.
+pub fn elementary_mate(board: &Board) -> Option<(ElementaryMate, PlayerSide)> {
+    for strong in [PlayerSide::White, PlayerSide::Black] {
+        if material_key(board, PlayerSide::opposite(strong)) != 0 {
+            continue;
+        }
+        let mate = match material_key(board, strong) {
+            KBN_KEY => ElementaryMate::Kbnk,
+            KQ_KEY => ElementaryMate::Kqk,
+            KR_KEY => ElementaryMate::Krk,
+            _ => continue,
+        };
+        return Some((mate, strong));
+    }
+    None
+}
+
+/// Returns the file and rank of a square.
+fn coordinates(square: u8) -> (i32, i32) {
+    (
+        (square as usize % NumberOf::FILES) as i32,
+        (square as usize / NumberOf::FILES) as i32,
+    )
+}
+
+/// Returns how many king steps the square is away from the four center squares, 0 to 3.
+pub(crate) fn center_distance(square: u8) -> i32 {
+    let (file, rank) = coordinates(square);
+    let distance = |x: i32| (3 - x).max(x - 4);
+    distance(file).max(distance(rank))
+}
+
+/// Returns the number of king moves between two squares.
+fn king_distance(a: u8, b: u8) -> i32 {
+    let (a_file, a_rank) = coordinates(a);
+    let (b_file, b_rank) = coordinates(b);
+    (a_file - b_file).abs().max((a_rank - b_rank).abs())
+}
+
+/// Returns the Manhattan distance from `square` to the closest corner of the same color as the
+/// bishop, the only corners where KBNK can be mated. The Manhattan distance keeps a gradient along
+/// the edge from the wrong corner to the right one, which the king distance would not.
+pub(crate) fn kbnk_corner_distance(square: u8, bishop_on_dark: bool) -> i32 {
+    // a1 and h8 are dark, a8 and h1 are light
+    let corners: [u8; 2] = if bishop_on_dark { [0, 63] } else { [56, 7] };
+    let (file, rank) = coordinates(square);
+    corners
+        .into_iter()
+        .map(|corner| {
+            let (corner_file, corner_rank) = coordinates(corner);
+            (file - corner_file).abs() + (rank - corner_rank).abs()
+        })
+        .min()
+        .unwrap()
+}
+
+/// Evaluates an elementary mate from the point of view of the side to move. The score is
+/// [`KNOWN_WIN`] for the strong side, plus bonuses for pushing the lone king to the edge (or the
+/// right corner for KBNK) and for bringing the kings together, so the search makes progress
+/// without having to see the mate.
+///
+/// # Arguments
+///
+/// - `board` - The board to evaluate.
+///
+/// # Returns
+///
+/// The score, or `None` if the position is not an elementary mate.
+pub fn evaluate(board: &Board) -> Option<Score> {
+    let (mate, strong) = elementary_mate(board)?;
+    let weak = PlayerSide::opposite(strong);
+    let strong_king = board.king_square(strong);
+    let weak_king = board.king_square(weak);
+
+    let mut score =
+        KNOWN_WIN.0 + KING_PROXIMITY * (7 - king_distance(strong_king, weak_king)) as ScoreType;
+    score += LONE_KING_EDGE * center_distance(weak_king) as ScoreType;
+    if mate == ElementaryMate::Kbnk {
+        // once on the edge, the king also has to be driven to a corner of the bishop's color
+        let bishop_on_dark = board
+            .piece_bitboard(Piece::Bishop, strong)
+            .intersects(Bitboard::from(DARK_SQUARES));
+        score += KBNK_CORNER * (14 - kbnk_corner_distance(weak_king, bishop_on_dark)) as ScoreType;
+    }
+
+    Some(if board.side_to_move() == strong {
+        Score::new(score)
+    } else {
+        Score::new(-score)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chess::{board::Board, definitions::Squares, side::PlayerSide};
+
+    use super::{
+        center_distance, elementary_mate, evaluate, kbnk_corner_distance, ElementaryMate, KNOWN_WIN,
+    };
+    use crate::{
+        score::Score,
+        search::{Search, SearchParameters},
+    };
+
+    #[test]
+    fn detects_elementary_mates() {
+        for (fen, expected) in [
+            (
+                "8/8/8/4k3/8/8/8/1NB1K3 w - - 0 1",
+                Some((ElementaryMate::Kbnk, PlayerSide::White)),
+            ),
+            (
+                "8/8/8/4k3/8/8/8/3QK3 b - - 0 1",
+                Some((ElementaryMate::Kqk, PlayerSide::White)),
+            ),
+            (
+                "3rk3/8/8/8/8/8/8/4K3 w - - 0 1",
+                Some((ElementaryMate::Krk, PlayerSide::Black)),
+            ),
+            // the weak side still has a pawn
+            ("8/8/4p3/4k3/8/8/8/3QK3 w - - 0 1", None),
+            // two bishops are not KBNK
+            ("8/8/8/4k3/8/8/8/2BBK3 w - - 0 1", None),
+            ("4k3/8/8/8/8/8/8/4K3 w - - 0 1", None),
+        ] {
+            let board = Board::from_fen(fen).unwrap();
+            assert_eq!(elementary_mate(&board), expected, "{}", fen);
+        }
+    }
+
+    #[test]
+    fn lone_king_is_driven_to_the_edge() {
+        let center = Board::from_fen("8/8/8/4k3/8/8/8/3QK3 w - - 0 1").unwrap();
+        let edge = Board::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        let center_score = evaluate(&center).unwrap();
+        assert!(center_score > KNOWN_WIN);
+        assert!(evaluate(&edge).unwrap() > center_score);
+        assert_eq!(center_distance(Squares::E5), 0);
+        assert_eq!(center_distance(Squares::E8), 3);
+
+        // the lone king's side sees the negated score
+        let mut flipped = center.clone();
+        flipped.flip_side_to_move();
+        assert_eq!(evaluate(&flipped).unwrap(), -center_score);
+    }
+
+    #[test]
+    fn kbnk_makes_progress_towards_the_right_corner() {
+        // the bishop on c1 is on a dark square, so black has to be mated on a1 or h8
+        let mut board = Board::from_fen("8/8/8/4k3/8/8/8/1NB1K3 w - - 0 1").unwrap();
+        let corner_distance =
+            |board: &Board| kbnk_corner_distance(board.king_square(PlayerSide::Black), true);
+        let start_distance = corner_distance(&board);
+        let start_score = evaluate(&board).unwrap();
+
+        let config = SearchParameters {
+            max_depth: 5,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        for _ in 0..40 {
+            let result =
+                Search::new(&config, &mut ttable, &mut history_table).search(&mut board, None);
+            board
+                .make_move_unchecked(&result.best_move.unwrap())
+                .unwrap();
+        }
+
+        // white is to move again, no material was lost and the king is closer to a mating corner
+        assert_eq!(
+            elementary_mate(&board),
+            Some((ElementaryMate::Kbnk, PlayerSide::White))
+        );
+        assert!(corner_distance(&board) < start_distance);
+        assert!(evaluate(&board).unwrap() > start_score);
+        assert!(start_score > Score::DRAW);
+    }
+}
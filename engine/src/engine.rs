@@ -4,7 +4,7 @@
  * Created Date: Friday, November 15th 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Tue Dec 10 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -15,36 +15,72 @@
 use std::{
     io::{self, Write},
     sync::{Arc, Mutex},
+    time::Duration,
 };
 
+use anyhow::{anyhow, bail};
 use chess::board::Board;
-use uci_parser::{UciCommand, UciInfo, UciOption, UciResponse};
+use uci_parser::{UciCommand, UciMove, UciOption, UciResponse};
 
 use crate::{
     defs::About,
+    evaluation::ByteKnightEvaluation,
     history_table::HistoryTable,
     input_handler::{CommandProxy, EngineCommand, InputHandler},
-    search::SearchParameters,
+    log_level::{log_info_string, LogLevel},
+    score::Score,
+    search::{self, SearchParameters},
     search_thread::SearchThread,
+    traits::Eval,
     ttable::{self, TranspositionTable},
+    tuneable::DRAW_JITTER,
 };
 
 pub struct ByteKnight {
-    input_handler: InputHandler,
-    search_thread: SearchThread,
+    board: Board,
+    search_thread: Option<SearchThread>,
     transposition_table: Arc<Mutex<TranspositionTable>>,
     history_table: Arc<Mutex<HistoryTable>>,
     debug: bool,
+    log_level: LogLevel,
+    move_overhead: Duration,
+    draw_jitter: bool,
 }
 
 impl ByteKnight {
     pub fn new() -> ByteKnight {
         ByteKnight {
-            input_handler: InputHandler::new(),
-            search_thread: SearchThread::new(),
+            board: Board::default_board(),
+            search_thread: None,
             transposition_table: Default::default(),
             history_table: Default::default(),
             debug: false,
+            log_level: LogLevel::default(),
+            move_overhead: search::DEFAULT_MOVE_OVERHEAD,
+            draw_jitter: DRAW_JITTER,
+        }
+    }
+
+    /// Sets the log level for diagnostic `info string` messages. Enabling UCI debug mode
+    /// (`debug on`) temporarily raises the level to [`LogLevel::Debug`].
+    pub fn set_log_level(&mut self, log_level: LogLevel) {
+        self.log_level = log_level;
+    }
+
+    /// The effective log level, taking UCI debug mode into account.
+    fn log_level(&self) -> LogLevel {
+        if self.debug {
+            LogLevel::Debug
+        } else {
+            self.log_level
+        }
+    }
+
+    /// Stops the running search, if any, and waits for it to write its best move.
+    fn stop_search(&mut self) {
+        if let Some(search_thread) = self.search_thread.take() {
+            search_thread.stop();
+            search_thread.join();
         }
     }
 
@@ -58,6 +94,114 @@ impl ByteKnight {
         }
     }
 
+    /// Resets the engine for a new game. Any running search is stopped, the board is reset to the
+    /// starting position and all persistent search state (transposition table, history table) is
+    /// cleared.
+    pub fn new_game(&mut self) {
+        self.stop_search();
+        self.board = Board::default_board();
+        self.clear_hash_tables();
+    }
+
+    /// Sets a UCI option, as sent by the `setoption` command. Option names are case insensitive.
+    ///
+    /// Besides the options advertised by the engine, `MultiPV`, `OwnBook` and `UCI_Chess960` are
+    /// accepted for GUIs that send them anyway, but only with the value that disables them.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the option.
+    /// - `value` - The new value of the option.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the option is unknown or the value is invalid. The current value of
+    /// the option is kept in that case.
+    pub fn set_option(&mut self, name: &str, value: &str) -> anyhow::Result<()> {
+        match name.to_lowercase().as_str() {
+            "hash" => {
+                let hash_size = value
+                    .parse::<usize>()
+                    .map_err(|_| anyhow!("invalid hash size {}", value))?;
+                self.transposition_table = Arc::new(Mutex::new(
+                    TranspositionTable::try_from_size_in_mb(hash_size)?,
+                ));
+            }
+            "threads" => {
+                if value.parse::<usize>().ok() != Some(1) {
+                    bail!("invalid number of threads, only 1 is supported");
+                }
+            }
+            "move overhead" => match value.parse::<u64>() {
+                Ok(overhead) if overhead <= search::MAX_MOVE_OVERHEAD.as_millis() as u64 => {
+                    self.move_overhead = Duration::from_millis(overhead);
+                }
+                _ => bail!(
+                    "invalid move overhead, must be between 0 and {} ms",
+                    search::MAX_MOVE_OVERHEAD.as_millis()
+                ),
+            },
+            "draw jitter" => {
+                self.draw_jitter = value
+                    .parse::<bool>()
+                    .map_err(|_| anyhow!("invalid draw jitter, must be true or false"))?;
+            }
+            "multipv" => {
+                if value.parse::<usize>().ok() != Some(1) {
+                    bail!("invalid multipv, only 1 is supported");
+                }
+            }
+            "ownbook" | "uci_chess960" => {
+                if value.parse::<bool>().ok() != Some(false) {
+                    bail!("{} is not supported", name);
+                }
+            }
+            _ => bail!("unknown option {}", name),
+        }
+        Ok(())
+    }
+
+    /// Statically evaluates a position without searching it.
+    ///
+    /// # Arguments
+    ///
+    /// - `fen` - The FEN of the position to evaluate.
+    ///
+    /// # Returns
+    ///
+    /// The evaluation of the position from the point of view of the side to move.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FEN can't be parsed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use engine::engine::ByteKnight;
+    ///
+    /// let score = ByteKnight::evaluate_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+    /// assert!(score.0 > 0);
+    /// ```
+    pub fn evaluate_fen(fen: &str) -> anyhow::Result<Score> {
+        Self::evaluate_fen_with(&ByteKnightEvaluation::default(), fen)
+    }
+
+    /// Statically evaluates a position with the given evaluation, see [`ByteKnight::evaluate_fen`].
+    ///
+    /// # Arguments
+    ///
+    /// - `evaluation` - The evaluation to use.
+    /// - `fen` - The FEN of the position to evaluate.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FEN can't be parsed.
+    pub fn evaluate_fen_with(evaluation: &impl Eval<Board>, fen: &str) -> anyhow::Result<Score> {
+        let board = Board::from_fen(fen)?;
+        Ok(evaluation.eval(&board))
+    }
+
     /// Run the engine loop. This will block until the engine is told to quit by the input handler.
     pub fn run(&mut self) -> anyhow::Result<()> {
         println!("{}", About::BANNER);
@@ -68,136 +212,200 @@ impl ByteKnight {
             About::AUTHORS,
             About::EMAIL
         );
-        let stdout: io::Stdout = io::stdout();
-        let mut board = Board::default_board();
-        'engine_loop: while let Ok(command) = &self.input_handler.receiver().recv() {
-            let mut stdout = stdout.lock();
-
-            match command {
-                CommandProxy::Uci(uci_command) => match uci_command {
-                    UciCommand::Debug(debug) => {
-                        self.debug = *debug;
-                    }
-                    UciCommand::Quit => {
-                        // clean up
-                        self.search_thread.exit();
-                        self.input_handler.exit();
-                        break 'engine_loop;
-                    }
-                    UciCommand::IsReady => {
-                        writeln!(stdout, "{}", UciResponse::<String>::ReadyOk).unwrap();
+        // stdout is not locked while handling a command, stopping a search waits for the search
+        // thread to write its best move
+        let mut stdout: io::Stdout = io::stdout();
+        let mut input_handler = InputHandler::new();
+        while let Ok(command) = &input_handler.receiver().recv() {
+            if !self.handle_command(command, &mut stdout) {
+                input_handler.exit();
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets the current position from an optional FEN string (the start position if `None`) and a
+    /// list of moves to apply. The current board is only replaced if the whole position is valid.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the FEN string is invalid or if any of the moves cannot be made.
+    fn set_position(&mut self, fen: Option<&str>, moves: &[UciMove]) -> anyhow::Result<()> {
+        let mut board = match fen {
+            None => Board::default_board(),
+            Some(fen) => Board::from_fen(fen)?,
+        };
+
+        for mv in moves {
+            board.make_uci_move(&mv.to_string())?;
+        }
+
+        self.board = board;
+        Ok(())
+    }
+
+    /// Handles a single command from the input handler, writing any responses to `stdout`.
+    ///
+    /// # Returns
+    ///
+    /// `false` if the engine should quit, `true` otherwise.
+    fn handle_command(&mut self, command: &CommandProxy, stdout: &mut impl Write) -> bool {
+        match command {
+            CommandProxy::Uci(uci_command) => match uci_command {
+                UciCommand::Debug(debug) => {
+                    self.debug = *debug;
+                    // only shown when debug mode was turned on
+                    log_info_string(
+                        stdout,
+                        self.log_level(),
+                        LogLevel::Debug,
+                        format!("debug mode on, log level {}", self.log_level()),
+                    );
+                }
+                UciCommand::Register { .. } => {
+                    // the engine is free, so per the UCI spec it never asks for registration and
+                    // there is nothing to reply
+                    log_info_string(
+                        stdout,
+                        self.log_level(),
+                        LogLevel::Debug,
+                        "registration is not required, ignoring register",
+                    );
+                }
+                UciCommand::Quit => {
+                    // clean up
+                    self.stop_search();
+                    return false;
+                }
+                UciCommand::IsReady => {
+                    writeln!(stdout, "{}", UciResponse::<String>::ReadyOk).unwrap();
+                }
+                UciCommand::Uci => {
+                    let id = UciResponse::Id {
+                        name: About::NAME,
+                        author: About::AUTHORS,
+                    };
+
+                    let options = vec![
+                        UciOption::spin(
+                            "Hash",
+                            ttable::DEFAULT_TABLE_SIZE_MB as i32,
+                            ttable::MIN_TABLE_SIZE_MB as i32,
+                            ttable::MAX_TABLE_SIZE_MB as i32,
+                        ),
+                        UciOption::spin("Threads", 1, 1, 1),
+                        UciOption::spin(
+                            "Move Overhead",
+                            search::DEFAULT_MOVE_OVERHEAD.as_millis() as i32,
+                            0,
+                            search::MAX_MOVE_OVERHEAD.as_millis() as i32,
+                        ),
+                        UciOption::check("Draw Jitter", DRAW_JITTER),
+                    ];
+                    for option in options {
+                        writeln!(stdout, "{}", UciResponse::Option(option)).unwrap();
                     }
-                    UciCommand::Uci => {
-                        let id = UciResponse::Id {
-                            name: About::NAME,
-                            author: About::AUTHORS,
-                        };
-
-                        let options = vec![
-                            UciOption::spin("Hash", 16, 1, 1024),
-                            UciOption::spin("Threads", 1, 1, 1),
-                        ];
-                        // TODO: Actually implement the hash option
-                        for option in options {
-                            writeln!(stdout, "{}", UciResponse::Option(option)).unwrap();
-                        }
-                        writeln!(stdout, "{}", id).unwrap();
-                        writeln!(stdout, "{}", UciResponse::<String>::UciOk).unwrap();
+                    writeln!(stdout, "{}", id).unwrap();
+                    writeln!(stdout, "{}", UciResponse::<String>::UciOk).unwrap();
+                }
+                UciCommand::UciNewGame => {
+                    self.new_game();
+                }
+                UciCommand::Position { fen, moves } => {
+                    // keep the previous board if the new position is invalid
+                    if let Err(error) = self.set_position(fen.as_deref(), moves) {
+                        log_info_string(
+                            stdout,
+                            self.log_level(),
+                            LogLevel::Error,
+                            format!("invalid position: {}", error),
+                        );
                     }
-                    UciCommand::UciNewGame => {
-                        board = Board::default_board();
-                        self.clear_hash_tables();
+                }
+                UciCommand::Go(search_options) => {
+                    if self
+                        .search_thread
+                        .as_ref()
+                        .is_some_and(SearchThread::is_running)
+                    {
+                        log_info_string(
+                            stdout,
+                            self.log_level(),
+                            LogLevel::Warning,
+                            "attempting to start a search while already searching",
+                        );
                     }
-                    UciCommand::Position { fen, moves } => {
-                        match fen {
-                            None => {
-                                board = Board::default_board();
-                            }
-                            Some(fen) => {
-                                board = Board::from_fen(fen.as_str()).unwrap();
-                            }
-                        }
+                    self.stop_search();
 
-                        for mv in moves {
-                            board.make_uci_move(&mv.to_string()).unwrap();
-                        }
-                    }
-                    UciCommand::Go(search_options) => {
-                        if self.search_thread.is_searching() {
-                            eprintln!("Attempting to start a search while already searching");
-                            self.search_thread.stop_search();
-                        }
+                    log_info_string(
+                        stdout,
+                        self.log_level(),
+                        LogLevel::Info,
+                        format!("searching {}", self.board.to_fen()),
+                    );
 
-                        let info =
-                            UciInfo::default().string(format!("searching {}", board.to_fen()));
-                        writeln!(stdout, "{}", UciResponse::info(info)).unwrap();
-
-                        // create the search parameters
-                        let search_params = SearchParameters::new(search_options, &board);
-                        // send them and the current board to the search thread
-                        self.search_thread.start_search(
-                            &board,
-                            search_params,
-                            self.transposition_table.clone(),
-                            self.history_table.clone(),
-                        );
-                    }
-                    UciCommand::SetOption { name, value } => {
-                        if name.to_lowercase() == "hash" {
-                            if let Some(val) = value {
-                                // set the hash size, making sure it is within the bounds we have set.
-                                if let Ok(hash_size) = val.parse::<usize>() {
-                                    if hash_size < ttable::MIN_TABLE_SIZE_MB {
-                                        eprintln!(
-                                            "Hash size too small. Must be at least {} MB",
-                                            ttable::MIN_TABLE_SIZE_MB
-                                        );
-                                        continue;
-                                    } else if hash_size > ttable::MAX_TABLE_SIZE_MB {
-                                        eprintln!(
-                                            "Hash size too large. Must be at most {} MB",
-                                            ttable::MAX_TABLE_SIZE_MB
-                                        );
-                                        continue;
-                                    }
-
-                                    self.transposition_table = Arc::new(Mutex::new(
-                                        TranspositionTable::from_size_in_mb(hash_size),
-                                    ));
-                                }
-                            }
+                    // create the search parameters
+                    let mut search_params =
+                        SearchParameters::new(search_options, &self.board, self.move_overhead);
+                    search_params.log_level = self.log_level();
+                    search_params.draw_jitter = self.draw_jitter;
+                    // search the current board on a new thread
+                    self.search_thread = Some(SearchThread::spawn(
+                        &self.board,
+                        search_params,
+                        self.transposition_table.clone(),
+                        self.history_table.clone(),
+                    ));
+                }
+                UciCommand::SetOption { name, value } => {
+                    let value = value.as_deref().unwrap_or_default();
+                    match self.set_option(name, value) {
+                        Ok(()) => log_info_string(
+                            stdout,
+                            self.log_level(),
+                            LogLevel::Debug,
+                            format!("option {} set to {}", name, value),
+                        ),
+                        Err(error) => {
+                            log_info_string(stdout, self.log_level(), LogLevel::Error, error)
                         }
                     }
-                    UciCommand::Stop => {
-                        self.search_thread.stop_search();
-                    }
-                    _ => {}
-                },
-                CommandProxy::Engine(engine_command) => match engine_command {
-                    EngineCommand::HashInfo => {
-                        if let Ok(tt) = self.transposition_table.lock() {
-                            writeln!(
-                                stdout,
-                                "full: {:.2}% hits: {} access: {} collisions: {} cap: {}",
-                                tt.fullness(),
-                                tt.hits,
-                                tt.accesses,
-                                tt.collisions,
-                                tt.size(),
-                            )
-                            .unwrap();
-                        }
+                }
+                UciCommand::Stop => {
+                    self.stop_search();
+                }
+                _ => {}
+            },
+            CommandProxy::Engine(engine_command) => match engine_command {
+                EngineCommand::HashInfo => {
+                    if let Ok(tt) = self.transposition_table.lock() {
+                        writeln!(
+                            stdout,
+                            "full: {:.2}% hits: {} access: {} collisions: {} cap: {}",
+                            tt.fullness(),
+                            tt.hits,
+                            tt.accesses,
+                            tt.collisions,
+                            tt.size(),
+                        )
+                        .unwrap();
                     }
-                    EngineCommand::History => {
-                        if let Ok(ht) = self.history_table.lock() {
-                            ht.print_for_side(board.side_to_move());
-                        }
+                }
+                EngineCommand::Eval => {
+                    let breakdown = ByteKnightEvaluation::default().evaluate_debug(&self.board);
+                    writeln!(stdout, "{}", breakdown).unwrap();
+                }
+                EngineCommand::History => {
+                    if let Ok(ht) = self.history_table.lock() {
+                        ht.print_for_side(self.board.side_to_move());
                     }
-                },
-            }
+                }
+            },
         }
 
-        Ok(())
+        true
     }
 }
 
@@ -206,3 +414,269 @@ impl Default for ByteKnight {
         ByteKnight::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, time::Duration};
+
+    use chess::{board::Board, definitions::Squares, moves::Move};
+    use uci_parser::UciCommand;
+
+    use super::ByteKnight;
+    use crate::{
+        input_handler::CommandProxy,
+        log_level::LogLevel,
+        score::Score,
+        search,
+        ttable::{EntryFlag, TranspositionTableEntry},
+        tuneable::DRAW_JITTER,
+    };
+
+    #[test]
+    fn evaluate_start_position() {
+        let fen = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        let score = ByteKnight::evaluate_fen(fen).unwrap();
+        assert!(score.0.abs() <= 50, "{}", score);
+
+        assert!(ByteKnight::evaluate_fen("not a fen").is_err());
+    }
+
+    #[test]
+    fn new_game_clears_search_state() {
+        let mut engine = ByteKnight::new();
+        let board =
+            Board::from_fen("r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3")
+                .unwrap();
+        let zobrist = board.zobrist_hash();
+        engine.board = board;
+
+        engine
+            .transposition_table
+            .lock()
+            .unwrap()
+            .store_entry(TranspositionTableEntry::new(
+                zobrist,
+                4,
+                Score::new(35),
+                EntryFlag::Exact,
+                Move::NULL,
+            ));
+        assert!(engine
+            .transposition_table
+            .lock()
+            .unwrap()
+            .get_entry(zobrist)
+            .is_some());
+
+        engine.new_game();
+
+        assert!(engine
+            .transposition_table
+            .lock()
+            .unwrap()
+            .get_entry(zobrist)
+            .is_none());
+        assert_eq!(engine.board.to_fen(), Board::default_board().to_fen());
+    }
+
+    #[test]
+    fn position_with_moves_applies_moves_in_order() {
+        let mut engine = ByteKnight::new();
+        let mut output = Vec::new();
+
+        let command = |line: &str| CommandProxy::Uci(UciCommand::from_str(line).unwrap());
+        assert!(engine.handle_command(
+            &command("position startpos moves e2e4 e7e5 g1f3 b8c6 f1c4 g8f6 e1g1"),
+            &mut output
+        ));
+        assert_eq!(
+            engine.board.to_fen(),
+            "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQ1RK1 b kq - 5 4"
+        );
+
+        // moves must alternate between the sides, starting with the side to move
+        assert!(engine.handle_command(&command("position startpos moves e7e5"), &mut output));
+        assert_eq!(
+            engine.board.to_fen(),
+            "r1bqkb1r/pppp1ppp/2n2n2/4p3/2B1P3/5N2/PPPP1PPP/RNBQ1RK1 b kq - 5 4"
+        );
+    }
+
+    #[test]
+    fn position_moves_set_en_passant_square() {
+        let mut engine = ByteKnight::new();
+        let mut output = Vec::new();
+
+        let command = |line: &str| CommandProxy::Uci(UciCommand::from_str(line).unwrap());
+        assert!(engine.handle_command(&command("position startpos moves e2e4"), &mut output));
+        assert_eq!(engine.board.en_passant_square(), Some(Squares::E3));
+        let from_fen = Board::from_fen(&engine.board.to_fen()).unwrap();
+        assert_eq!(engine.board.zobrist_hash(), from_fen.zobrist_hash());
+
+        // the FEN has no en passant square, but the double push in the moves allows e5xf6
+        assert!(engine.handle_command(
+            &command(
+                "position fen rnbqkbnr/pppppppp/8/4P3/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 2 moves f7f5 e5f6"
+            ),
+            &mut output
+        ));
+        assert_eq!(
+            engine.board.to_fen(),
+            "rnbqkbnr/ppppp1pp/5P2/8/8/8/PPPP1PPP/RNBQKBNR b KQkq - 0 3"
+        );
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn invalid_position_keeps_previous_board() {
+        let mut engine = ByteKnight::new();
+        let mut output = Vec::new();
+        let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - 2 3";
+
+        let command = |line: &str| CommandProxy::Uci(UciCommand::from_str(line).unwrap());
+        assert!(engine.handle_command(&command(&format!("position fen {}", fen)), &mut output));
+        assert_eq!(engine.board.to_fen(), fen);
+
+        // malformed FEN strings and illegal moves should not replace the current board
+        for position in [
+            "position fen rnbqkbnr/pppppppp/9/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "position fen rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - x 1",
+            "position startpos moves e2e4 e2e4",
+        ] {
+            assert!(engine.handle_command(&command(position), &mut output));
+            assert_eq!(engine.board.to_fen(), fen);
+        }
+
+        // the engine is still responsive
+        assert!(engine.handle_command(&command("isready"), &mut output));
+        let output = String::from_utf8(output).unwrap();
+        let lines = output.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        assert!(lines[..3]
+            .iter()
+            .all(|line| line.starts_with("info string invalid position")));
+        assert_eq!(lines[3], "readyok");
+
+        assert!(!engine.handle_command(&command("quit"), &mut Vec::new()));
+    }
+
+    #[test]
+    fn debug_mode_raises_log_level() {
+        let mut engine = ByteKnight::new();
+        let mut output = Vec::new();
+        assert_eq!(engine.log_level(), LogLevel::Info);
+
+        let command = |line: &str| CommandProxy::Uci(UciCommand::from_str(line).unwrap());
+        assert!(engine.handle_command(&command("debug on"), &mut output));
+        assert_eq!(engine.log_level(), LogLevel::Debug);
+        assert_eq!(
+            String::from_utf8(output.clone()).unwrap().trim_end(),
+            "info string debug mode on, log level debug"
+        );
+
+        // register is acknowledged in debug mode and otherwise ignored
+        output.clear();
+        assert!(engine.handle_command(&command("register later"), &mut output));
+        assert!(String::from_utf8(output.clone())
+            .unwrap()
+            .starts_with("info string registration is not required"));
+
+        output.clear();
+        assert!(engine.handle_command(&command("debug off"), &mut output));
+        assert_eq!(engine.log_level(), LogLevel::Info);
+        assert!(engine.handle_command(
+            &command("register name Stefan MK code 4359874324"),
+            &mut output
+        ));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn set_move_overhead_option() {
+        let mut engine = ByteKnight::new();
+        let mut output = Vec::new();
+        assert_eq!(engine.move_overhead, search::DEFAULT_MOVE_OVERHEAD);
+
+        let command = |line: &str| CommandProxy::Uci(UciCommand::from_str(line).unwrap());
+        assert!(engine.handle_command(
+            &command("setoption name Move Overhead value 100"),
+            &mut output
+        ));
+        assert_eq!(engine.move_overhead, Duration::from_millis(100));
+        assert!(output.is_empty());
+
+        // invalid values are rejected and the previous value is kept
+        for value in ["-5", "abc", "100000"] {
+            assert!(engine.handle_command(
+                &command(&format!("setoption name Move Overhead value {}", value)),
+                &mut output
+            ));
+            assert_eq!(engine.move_overhead, Duration::from_millis(100));
+        }
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().count(), 3);
+        assert!(output
+            .lines()
+            .all(|line| line.starts_with("info string invalid move overhead")));
+    }
+
+    #[test]
+    fn set_option_resizes_hash_and_rejects_unknown_options() {
+        let mut engine = ByteKnight::new();
+        let default_size = engine.transposition_table.lock().unwrap().size();
+        engine.set_option("Hash", "32").unwrap();
+        let size = engine.transposition_table.lock().unwrap().size();
+        assert_eq!(size, default_size * 2);
+
+        // invalid values keep the current table
+        assert!(engine.set_option("hash", "1").is_err());
+        assert!(engine.set_option("Hash", "lots").is_err());
+        assert_eq!(engine.transposition_table.lock().unwrap().size(), size);
+
+        assert!(engine.set_option("Threads", "1").is_ok());
+        assert!(engine.set_option("Threads", "4").is_err());
+        assert!(engine.set_option("UCI_Chess960", "false").is_ok());
+        assert!(engine.set_option("UCI_Chess960", "true").is_err());
+        assert!(engine.set_option("Contempt", "10").is_err());
+
+        // the UCI loop reports errors instead of crashing
+        let mut output = Vec::new();
+        let command = CommandProxy::Uci(
+            UciCommand::from_str("setoption name Not An Option value 3").unwrap(),
+        );
+        assert!(engine.handle_command(&command, &mut output));
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output.trim_end(),
+            "info string unknown option Not An Option"
+        );
+    }
+
+    #[test]
+    fn set_draw_jitter_option() {
+        let mut engine = ByteKnight::new();
+        let mut output = Vec::new();
+        assert_eq!(engine.draw_jitter, DRAW_JITTER);
+
+        let command = |line: &str| CommandProxy::Uci(UciCommand::from_str(line).unwrap());
+        assert!(engine.handle_command(
+            &command("setoption name Draw Jitter value true"),
+            &mut output
+        ));
+        assert!(engine.draw_jitter);
+        assert!(engine.handle_command(
+            &command("setoption name Draw Jitter value maybe"),
+            &mut output
+        ));
+        assert!(engine.draw_jitter);
+        assert!(engine.handle_command(
+            &command("setoption name Draw Jitter value false"),
+            &mut output
+        ));
+        assert!(!engine.draw_jitter);
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().count(), 1);
+        assert!(output.starts_with("info string invalid draw jitter"));
+    }
+}
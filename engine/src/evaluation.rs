@@ -4,7 +4,7 @@
  * Created Date: Thursday, November 21st 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Wed Dec 18 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -12,18 +12,139 @@
  *
  */
 
-use chess::{bitboard_helpers, board::Board, moves::Move, pieces::Piece, side::Side};
+use std::fmt::Display;
+
+use chess::{
+    bitboard::Bitboard, board::Board, definitions::DARK_SQUARES, pieces::Piece, side::PlayerSide,
+};
 
 use crate::{
+    bad_bishop, endgame,
     hce_values::ByteKnightValues,
-    history_table,
-    phased_score::{PhaseType, PhasedScore},
+    king_safety, pawn_structure,
+    phased_score::{PhaseType, PhasedScore, MAX_PHASE},
     psqt::GAMEPHASE_INC,
     score::{LargeScoreType, Score, ScoreType},
     traits::{Eval, EvalValues},
-    ttable::TranspositionTableEntry,
+    tuneable::OCB_DRAW_DIVISOR,
 };
 
+/// Middle and end game sums of an evaluation term for each side. Sums are kept as `i32` so that
+/// they can't overflow before being clamped.
+#[derive(Clone, Copy, Default)]
+struct TermScore {
+    mg: [i32; 2],
+    eg: [i32; 2],
+}
+
+impl TermScore {
+    fn add(&mut self, side: PlayerSide, score: PhasedScore) {
+        self.mg[side as usize] += score.mg() as i32;
+        self.eg[side as usize] += score.eg() as i32;
+    }
+
+    /// Returns the middle and end game score from the point of view of `side`.
+    fn relative_to(&self, side: PlayerSide) -> (i32, i32) {
+        let us = side as usize;
+        let them = PlayerSide::opposite(side) as usize;
+        (self.mg[us] - self.mg[them], self.eg[us] - self.eg[them])
+    }
+}
+
+/// Returns `true` if the position is an opposite colored bishop ending: each side has a single
+/// bishop, the bishops are on squares of different colors and there are no other pieces besides
+/// pawns and kings.
+pub(crate) fn is_opposite_colored_bishop_ending(board: &Board) -> bool {
+    let white_bishops = *board.piece_bitboard(Piece::Bishop, PlayerSide::White);
+    let black_bishops = *board.piece_bitboard(Piece::Bishop, PlayerSide::Black);
+    if white_bishops.number_of_occupied_squares() != 1
+        || black_bishops.number_of_occupied_squares() != 1
+    {
+        return false;
+    }
+
+    let no_other_pieces = [Piece::Knight, Piece::Rook, Piece::Queen]
+        .into_iter()
+        .all(|piece| {
+            [PlayerSide::White, PlayerSide::Black]
+                .into_iter()
+                .all(|side| {
+                    board
+                        .piece_bitboard(piece, side)
+                        .number_of_occupied_squares()
+                        == 0
+                })
+        });
+
+    let dark_squares = Bitboard::from(DARK_SQUARES);
+    no_other_pieces
+        && white_bishops.intersects(dark_squares) != black_bishops.intersects(dark_squares)
+}
+
+/// Returns the divisor that scales the evaluation of drawish endings towards a draw, 1 for all
+/// other positions.
+fn draw_divisor(board: &Board) -> ScoreType {
+    if is_opposite_colored_bishop_ending(board) {
+        OCB_DRAW_DIVISOR
+    } else {
+        1
+    }
+}
+
+/// Clamps a middle and end game score to [`Score::MAX_EVAL`] and narrows it to a [`PhasedScore`].
+fn clamped_phased_score((mg, eg): (i32, i32)) -> PhasedScore {
+    // clamp before narrowing to the score type so extreme sums can't wrap around
+    let max_eval = Score::MAX_EVAL.0 as i32;
+    PhasedScore::new(
+        mg.clamp(-max_eval, max_eval) as ScoreType,
+        eg.clamp(-max_eval, max_eval) as ScoreType,
+    )
+}
+
+/// The contribution of each evaluation term, see [`Evaluation::evaluate_debug`]. All scores are
+/// from the point of view of the side to move.
+#[derive(Clone, Copy, Debug)]
+pub struct EvalBreakdown {
+    /// Material and piece square tables.
+    pub psqt: PhasedScore,
+    /// Doubled, isolated, backward and passed pawns.
+    pub pawn_structure: PhasedScore,
+    /// Pawn shield and pawn storm around the king.
+    pub king_safety: PhasedScore,
+    /// Bishops hindered by friendly pawns on their square color.
+    pub bad_bishop: PhasedScore,
+    /// The game phase used to taper the score, [`MAX_PHASE`] is the opening and 0 the endgame.
+    pub game_phase: PhaseType,
+    /// The tapered score is divided by this in drawish endings, otherwise it is 1.
+    pub draw_divisor: ScoreType,
+    /// The score of a known elementary mate such as KBNK, which replaces all other terms.
+    pub elementary_mate: Option<Score>,
+    /// The tapered total, the same as the regular evaluation.
+    pub total: Score,
+}
+
+impl EvalBreakdown {
+    /// Returns the sum of all terms, before tapering.
+    pub fn sum(&self) -> PhasedScore {
+        self.psqt + self.pawn_structure + self.king_safety + self.bad_bishop
+    }
+}
+
+impl Display for EvalBreakdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "psqt:           {}", self.psqt)?;
+        writeln!(f, "pawn structure: {}", self.pawn_structure)?;
+        writeln!(f, "king safety:    {}", self.king_safety)?;
+        writeln!(f, "bad bishop:     {}", self.bad_bishop)?;
+        writeln!(f, "game phase:     {}", self.game_phase)?;
+        writeln!(f, "draw divisor:   {}", self.draw_divisor)?;
+        if let Some(score) = self.elementary_mate {
+            writeln!(f, "known mate:     {}", score)?;
+        }
+        write!(f, "total:          {}", self.total)
+    }
+}
+
 /// Provides static evaluation of a given chess position.
 pub struct Evaluation<Values>
 where
@@ -37,42 +158,7 @@ impl<Values: EvalValues + Default> Evaluation<Values> {
         Evaluation { values }
     }
 
-    /// Scores a move for ordering. This will return the _negative_ score of
-    /// the move so that if you sort moves by their score, the best move will
-    /// be first (at index 0).
-    ///
-    /// # Arguments
-    ///
-    /// - `mv`: The move to score.
-    /// - `tt_entry`: The transposition table entry for the current position.
-    ///
-    /// # Returns
-    ///
-    /// The score of the move.
-    pub(crate) fn score_move_for_ordering(
-        stm: Side,
-        mv: &Move,
-        tt_entry: &Option<TranspositionTableEntry>,
-        history_table: &history_table::HistoryTable,
-    ) -> LargeScoreType {
-        if tt_entry.is_some_and(|tt| *mv == tt.board_move) {
-            return LargeScoreType::MIN;
-        }
-
-        let mut score = 0;
-        if mv.is_quiet() {
-            //history heuristic
-            score += history_table.get(stm, mv.piece(), mv.to());
-        } else if mv.is_capture() {
-            // mvv-lva for captures
-            // safe to unwrap the captured piece because we already checked
-            score += Self::mvv_lva(mv.captured_piece().unwrap(), mv.piece());
-        }
-
-        // negate the score to get the best move first
-        -score
-    }
-
+    /// Returns the MVV/LVA score of capturing `captured` with `capturing`, used to order captures.
     pub(crate) fn mvv_lva(captured: Piece, capturing: Piece) -> LargeScoreType {
         let can_capture = captured != Piece::King && captured != Piece::None;
         ((can_capture as LargeScoreType)
@@ -80,6 +166,8 @@ impl<Values: EvalValues + Default> Evaluation<Values> {
             << 16
     }
 
+    /// Relative rank of a piece used for MVV-LVA ordering. This is intentionally not a material
+    /// value; material values live in [`chess::pieces::PIECE_VALUES`].
     pub(crate) fn piece_value(piece: Piece) -> LargeScoreType {
         match piece {
             Piece::King => 0,
@@ -93,39 +181,106 @@ impl<Values: EvalValues + Default> Evaluation<Values> {
     }
 }
 
-impl<Values: EvalValues<ReturnScore = PhasedScore> + Default> Eval<Board> for Evaluation<Values> {
-    /// Evaluates the given position.
+impl<Values: EvalValues<ReturnScore = PhasedScore> + Default> Evaluation<Values> {
+    /// Evaluates the given position like [`Eval::eval`], but also returns the contribution of each
+    /// evaluation term. This is slower than [`Eval::eval`] and meant for debugging and tuning.
     ///
     /// # Arguments
     ///
     /// - `board`: The [`Board`] to evaluate.
-    fn eval(&self, board: &Board) -> Score {
+    pub fn evaluate_debug(&self, board: &Board) -> EvalBreakdown {
         let side_to_move = board.side_to_move();
-        let mut mg: [i32; 2] = [0; 2];
-        let mut eg: [i32; 2] = [0; 2];
+        let (psqt, game_phase) = self.psqt_term(board);
+        let pawn_structure = self.pawn_structure_term(board);
+        let king_safety = self.king_safety_term(board);
+        let bad_bishop = self.bad_bishop_term(board);
+
+        EvalBreakdown {
+            psqt: clamped_phased_score(psqt.relative_to(side_to_move)),
+            pawn_structure: clamped_phased_score(pawn_structure.relative_to(side_to_move)),
+            king_safety: clamped_phased_score(king_safety.relative_to(side_to_move)),
+            bad_bishop: clamped_phased_score(bad_bishop.relative_to(side_to_move)),
+            game_phase: game_phase.min(MAX_PHASE),
+            draw_divisor: draw_divisor(board),
+            elementary_mate: endgame::evaluate(board),
+            total: self.eval(board),
+        }
+    }
+
+    /// Sums the piece square tables for each side and computes the game phase.
+    fn psqt_term(&self, board: &Board) -> (TermScore, PhaseType) {
+        let mut term = TermScore::default();
         let mut game_phase = 0_i32;
 
         let mut occupancy = board.all_pieces();
         // loop through occupied squares
-        while occupancy.as_number() > 0 {
-            let sq = bitboard_helpers::next_bit(&mut occupancy);
-            let maybe_piece = board.piece_on_square(sq as u8);
+        while let Some(sq) = occupancy.pop_lsb() {
+            let maybe_piece = board.piece_on_square(sq);
             if let Some((piece, side)) = maybe_piece {
-                let phased_score: PhasedScore = self.values.psqt(sq as u8, piece, side);
-                mg[side as usize] += phased_score.mg() as i32;
-                eg[side as usize] += phased_score.eg() as i32;
-
+                term.add(side, self.values.psqt(sq, piece, side));
                 game_phase += GAMEPHASE_INC[piece as usize] as i32;
             }
         }
-        let stm_idx = side_to_move as usize;
-        let opposite = Side::opposite(side_to_move) as usize;
-        let mg_score = mg[stm_idx] - mg[opposite];
-        let eg_score = eg[stm_idx] - eg[opposite];
-        let score = PhasedScore::new(mg_score as ScoreType, eg_score as ScoreType);
-        // taper the score based on the game phase
-        let val = score.taper(game_phase.min(24) as PhaseType, 24);
-        Score::new(val)
+
+        (term, game_phase)
+    }
+
+    /// Sums the pawn structure terms for each side.
+    fn pawn_structure_term(&self, board: &Board) -> TermScore {
+        let mut term = TermScore::default();
+        for side in [PlayerSide::White, PlayerSide::Black] {
+            term.add(side, pawn_structure::evaluate(board, side, &self.values));
+        }
+        term
+    }
+
+    /// Sums the king safety terms for each side.
+    fn king_safety_term(&self, board: &Board) -> TermScore {
+        let mut term = TermScore::default();
+        for side in [PlayerSide::White, PlayerSide::Black] {
+            term.add(side, king_safety::evaluate(board, side, &self.values));
+        }
+        term
+    }
+
+    /// Sums the bad bishop penalties for each side.
+    fn bad_bishop_term(&self, board: &Board) -> TermScore {
+        let mut term = TermScore::default();
+        for side in [PlayerSide::White, PlayerSide::Black] {
+            term.add(side, bad_bishop::evaluate(board, side, &self.values));
+        }
+        term
+    }
+}
+
+impl<Values: EvalValues<ReturnScore = PhasedScore> + Default> Eval<Board> for Evaluation<Values> {
+    /// Evaluates the given position.
+    ///
+    /// # Arguments
+    ///
+    /// - `board`: The [`Board`] to evaluate.
+    fn eval(&self, board: &Board) -> Score {
+        // known elementary mates only need the lone king driven to the right place
+        if let Some(score) = endgame::evaluate(board) {
+            return score;
+        }
+
+        let side_to_move = board.side_to_move();
+        let (psqt, game_phase) = self.psqt_term(board);
+        let pawn_structure = self.pawn_structure_term(board);
+        let king_safety = self.king_safety_term(board);
+        let bad_bishop = self.bad_bishop_term(board);
+
+        let (psqt_mg, psqt_eg) = psqt.relative_to(side_to_move);
+        let (pawn_mg, pawn_eg) = pawn_structure.relative_to(side_to_move);
+        let (king_mg, king_eg) = king_safety.relative_to(side_to_move);
+        let (bishop_mg, bishop_eg) = bad_bishop.relative_to(side_to_move);
+        let score = clamped_phased_score((
+            psqt_mg + pawn_mg + king_mg + bishop_mg,
+            psqt_eg + pawn_eg + king_eg + bishop_eg,
+        ));
+        // all terms are summed as phased scores and collapsed once, scalar adjustments come after
+        Score::new(score.collapse(game_phase).0 / draw_divisor(board))
     }
 }
 
@@ -141,18 +296,175 @@ impl Default for ByteKnightEvaluation {
 mod tests {
     use chess::{
         board::Board,
-        moves::{self, Move},
         pieces::{Piece, ALL_PIECES, PIECE_SHORT_NAMES},
-        side::Side,
-        square::Square,
+        side::PlayerSide,
     };
 
+    use super::is_opposite_colored_bishop_ending;
     use crate::{
-        evaluation::ByteKnightEvaluation,
-        score::{LargeScoreType, ScoreType},
-        traits::Eval,
+        evaluation::{ByteKnightEvaluation, Evaluation},
+        hce_values::ByteKnightValues,
+        king_safety::KingZone,
+        pawn_structure,
+        phased_score::{PhasedScore, S},
+        score::{LargeScoreType, Score, ScoreType},
+        traits::{Eval, EvalValues},
+        tuneable::OCB_DRAW_DIVISOR,
     };
 
+    /// Values with an absurdly large piece square table for white, to test the evaluation clamp.
+    #[derive(Default)]
+    struct InflatedValues(ByteKnightValues);
+
+    impl EvalValues for InflatedValues {
+        type ReturnScore = PhasedScore;
+
+        fn psqt(&self, square: u8, piece: Piece, side: PlayerSide) -> PhasedScore {
+            match side {
+                PlayerSide::White => S(20_000, 20_000),
+                PlayerSide::Black => self.0.psqt(square, piece, side),
+            }
+        }
+
+        fn doubled_pawn(&self) -> PhasedScore {
+            self.0.doubled_pawn()
+        }
+
+        fn isolated_pawn(&self) -> PhasedScore {
+            self.0.isolated_pawn()
+        }
+
+        fn backward_pawn(&self) -> PhasedScore {
+            self.0.backward_pawn()
+        }
+
+        fn passed_pawn(&self, relative_rank: u8) -> PhasedScore {
+            self.0.passed_pawn(relative_rank)
+        }
+
+        fn protected_passed_pawn(&self) -> PhasedScore {
+            self.0.protected_passed_pawn()
+        }
+
+        fn connected_passed_pawn(&self) -> PhasedScore {
+            self.0.connected_passed_pawn()
+        }
+
+        fn pawn_shield(&self, zone: KingZone, distance: u8) -> PhasedScore {
+            self.0.pawn_shield(zone, distance)
+        }
+
+        fn pawn_storm(&self, zone: KingZone, distance: u8) -> PhasedScore {
+            self.0.pawn_storm(zone, distance)
+        }
+
+        fn bad_bishop(&self) -> PhasedScore {
+            self.0.bad_bishop()
+        }
+    }
+
+    #[test]
+    fn eval_breakdown_sums_to_total() {
+        let eval = ByteKnightEvaluation::default();
+        for fen in [
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+            "4k3/7p/8/3P4/4P3/1p6/P7/4K3 b - - 0 1",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+            "4k3/5b2/8/3p4/3P4/2P5/8/2B1K3 w - - 0 1",
+        ] {
+            let board = Board::from_fen(fen).unwrap();
+            let breakdown = eval.evaluate_debug(&board);
+            assert_eq!(breakdown.total, eval.eval(&board), "{}", fen);
+            assert_eq!(
+                breakdown.sum().collapse(breakdown.game_phase).0 / breakdown.draw_divisor,
+                breakdown.total.0,
+                "{}",
+                fen
+            );
+        }
+
+        // only white has pawns, so the pawn structure term is all white's
+        let board = Board::from_fen("4k3/8/8/8/8/2P5/2P5/4K3 w - - 0 1").unwrap();
+        let breakdown = eval.evaluate_debug(&board);
+        assert_eq!(
+            breakdown.pawn_structure,
+            pawn_structure::evaluate(&board, PlayerSide::White, &ByteKnightValues::default())
+        );
+    }
+
+    #[test]
+    fn single_collapse_matches_collapsed_terms() {
+        let eval = ByteKnightEvaluation::default();
+        let path = format!("{}/../data/standard.epd", env!("CARGO_MANIFEST_DIR"));
+        let epd = std::fs::read_to_string(path).unwrap();
+        for fen in epd
+            .lines()
+            .map(|line| line.split(';').next().unwrap().trim())
+        {
+            let board = Board::from_fen(fen).unwrap();
+            let breakdown = eval.evaluate_debug(&board);
+            let phase = breakdown.game_phase;
+            let collapsed = breakdown.sum().collapse(phase);
+            let terms = [
+                breakdown.psqt,
+                breakdown.pawn_structure,
+                breakdown.king_safety,
+                breakdown.bad_bishop,
+            ];
+            let per_term = terms
+                .iter()
+                .map(|term| term.collapse(phase).0)
+                .sum::<ScoreType>();
+            // collapsing truncates towards zero, which drops less than 1 from every term and from
+            // the sum, so the two can differ by less than the number of terms
+            assert!(
+                (collapsed.0 - per_term).abs() < terms.len() as ScoreType,
+                "{}",
+                fen
+            );
+        }
+    }
+
+    #[test]
+    fn opposite_colored_bishops_are_drawish() {
+        let eval = ByteKnightEvaluation::default();
+        // white is a pawn up, with the bishops on opposite colors (c1 is dark, f7 is light) and
+        // then on the same color (e7 is dark)
+        let opposite = Board::from_fen("4k3/5b2/8/3p4/3P4/2P5/8/2B1K3 w - - 0 1").unwrap();
+        let same = Board::from_fen("4k3/4b3/8/3p4/3P4/2P5/8/2B1K3 w - - 0 1").unwrap();
+        assert!(is_opposite_colored_bishop_ending(&opposite));
+        assert!(!is_opposite_colored_bishop_ending(&same));
+
+        let opposite_score = eval.eval(&opposite);
+        let same_score = eval.eval(&same);
+        assert!(opposite_score > Score::DRAW);
+        assert!(opposite_score < same_score);
+        let breakdown = eval.evaluate_debug(&opposite);
+        assert_eq!(breakdown.draw_divisor, OCB_DRAW_DIVISOR);
+        assert_eq!(
+            opposite_score.0,
+            breakdown.sum().collapse(breakdown.game_phase).0 / OCB_DRAW_DIVISOR
+        );
+
+        // any other piece besides pawns means it is not a bishop ending
+        let with_knight = Board::from_fen("4k3/5b2/8/3p4/3P4/2P5/8/2BNK3 w - - 0 1").unwrap();
+        assert!(!is_opposite_colored_bishop_ending(&with_knight));
+        let two_bishops = Board::from_fen("4k3/5b2/8/3p4/3P4/2P5/8/2B1KB2 w - - 0 1").unwrap();
+        assert!(!is_opposite_colored_bishop_ending(&two_bishops));
+    }
+
+    #[test]
+    fn inflated_evaluation_is_clamped() {
+        let eval = Evaluation::new(InflatedValues::default());
+        let mut board = Board::default_board();
+        assert_eq!(eval.eval(&board), Score::MAX_EVAL);
+        assert!(!eval.eval(&board).is_mate());
+
+        board.flip_side_to_move();
+        assert_eq!(eval.eval(&board), -Score::MAX_EVAL);
+    }
+
     #[test]
     fn mvv_lva_scaling() {
         for captured in ALL_PIECES {
@@ -169,55 +481,6 @@ mod tests {
         }
     }
 
-    #[test]
-    fn score_moves() {
-        let from = Square::from_square_index(0);
-        let to = Square::from_square_index(1);
-        let mut mv = Move::new(
-            &from,
-            &to,
-            moves::MoveDescriptor::None,
-            Piece::Pawn,
-            Some(Piece::Queen),
-            None,
-        );
-        let side = Side::Black;
-        let history_table = Default::default();
-        // note that these scores are for ordering, so they are negated
-        assert_eq!(
-            -ByteKnightEvaluation::score_move_for_ordering(side, &mv, &None, &history_table),
-            ByteKnightEvaluation::mvv_lva(mv.captured_piece().unwrap(), mv.piece())
-        );
-
-        mv = Move::new(
-            &from,
-            &to,
-            moves::MoveDescriptor::None,
-            Piece::Bishop,
-            Some(Piece::Rook),
-            None,
-        );
-
-        assert_eq!(
-            -ByteKnightEvaluation::score_move_for_ordering(side, &mv, &None, &history_table),
-            ByteKnightEvaluation::mvv_lva(mv.captured_piece().unwrap(), mv.piece())
-        );
-
-        mv = Move::new(
-            &from,
-            &to,
-            moves::MoveDescriptor::None,
-            Piece::Knight,
-            Some(Piece::Pawn),
-            None,
-        );
-
-        assert_eq!(
-            -ByteKnightEvaluation::score_move_for_ordering(side, &mv, &None, &history_table),
-            ByteKnightEvaluation::mvv_lva(mv.captured_piece().unwrap(), mv.piece())
-        );
-    }
-
     #[test]
     fn score_stability() {
         // These values were determined empirically by running this test and manually copy/pasting the results
@@ -356,13 +619,13 @@ mod tests {
         ];
 
         let scores: [ScoreType; 128] = [
-            0, 56, 488, 499, -488, -499, 980, -980, 445, 458, -445, -458, 0, 9, 14, 12, -9, -14,
-            -12, -488, -499, 488, 499, -980, 980, -445, -458, 445, 458, 0, -9, -14, -12, 9, 14, 12,
-            2, 1, 0, -342, 406, -2, -1, 3, 342, -406, 0, -29, 634, -628, 25, 29, -634, 628, 0, -1,
-            0, 1, -925, -990, -77, 929, -990, 77, 162, 95, -162, -95, 69, -162, -95, 162, 95, -69,
-            59, 59, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, -2, -27, 7, 4, 7, -4, -7, -169, 9, 27, -7, -4,
-            -7, 4, 7, 169, -9, -4, 3, 4, -3, 9, -9, 0, 4, -3, -4, 3, -9, 9, 0, -3, 15, 26, 42, 3,
-            -15, -26, -42, 37, 53,
+            0, 51, 10120, 10120, -10120, -10120, 980, -980, 10130, 10120, -10130, -10120, 0, 9, 14,
+            12, -9, -14, -12, -10120, -10120, 10120, 10120, -980, 980, -10130, -10120, 10130,
+            10120, 0, -9, -14, -12, 9, 14, 12, 2, 1, 0, -342, 406, -2, -1, 3, 342, -406, 0, -29,
+            634, -628, 25, 29, -634, 628, 0, -1, 0, 1, -10120, -10120, -77, 10120, -10120, 77, 156,
+            89, -156, -89, 71, -156, -89, 156, 89, -71, 53, 53, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0,
+            -2, -27, 7, 4, 7, -4, -7, -295, 9, 27, -7, -4, -7, 4, 7, 295, -9, -4, 3, 4, -3, 9, -9,
+            0, 4, -3, -4, 3, -9, 9, 0, -3, 15, 26, 42, 3, -15, -26, -42, 24, 59,
         ];
 
         let eval = ByteKnightEvaluation::default();
@@ -1,11 +1,12 @@
 use chess::{
     definitions::NumberOf,
     pieces::Piece,
-    side::Side,
+    side::PlayerSide,
     square::{self},
 };
 
 use crate::{
+    king_safety::{KingZone, PAWN_DISTANCES},
     phased_score::{PhasedScore, S},
     traits::EvalValues,
 };
@@ -80,14 +81,90 @@ pub const PSQTS : [[PhasedScore; NumberOf::SQUARES]; NumberOf::PIECE_TYPES]  = [
     ],
 ];
 
+pub const DOUBLED_PAWN: PhasedScore = S(-10, -25);
+pub const ISOLATED_PAWN: PhasedScore = S(-12, -14);
+pub const BACKWARD_PAWN: PhasedScore = S(-8, -10);
+/// Passed pawn bonus indexed by the rank relative to the pawn's side.
+pub const PASSED_PAWN: [PhasedScore; NumberOf::RANKS] = [
+    S(0, 0),
+    S(2, 8),
+    S(5, 12),
+    S(10, 25),
+    S(25, 45),
+    S(45, 80),
+    S(70, 120),
+    S(0, 0),
+];
+pub const PROTECTED_PASSED_PAWN: PhasedScore = S(10, 20);
+pub const CONNECTED_PASSED_PAWN: PhasedScore = S(8, 18);
+/// Pawn shield bonus indexed by king zone and the distance of the shield pawn from the king.
+pub const PAWN_SHIELD: [[PhasedScore; PAWN_DISTANCES]; KingZone::COUNT] = [
+    // Queenside
+    [S(0, 0), S(18, 0), S(10, 0), S(4, 0)],
+    // Center
+    [S(0, 0), S(8, 0), S(4, 0), S(2, 0)],
+    // Kingside
+    [S(0, 0), S(22, 0), S(12, 0), S(5, 0)],
+];
+/// Pawn storm penalty indexed by king zone and the distance of the enemy pawn from the king. A
+/// pawn right in front of the king is usually blocked, so it is less dangerous than one two ranks
+/// away.
+pub const PAWN_STORM: [[PhasedScore; PAWN_DISTANCES]; KingZone::COUNT] = [
+    // Queenside
+    [S(0, 0), S(-4, 0), S(-18, -2), S(-8, 0)],
+    // Center
+    [S(0, 0), S(-2, 0), S(-10, 0), S(-5, 0)],
+    // Kingside
+    [S(0, 0), S(-5, 0), S(-20, -2), S(-10, 0)],
+];
+/// Bishop penalty per friendly pawn on the bishop's square color. Blocked pawns on that color count
+/// twice.
+pub const BAD_BISHOP: PhasedScore = S(-3, -7);
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ByteKnightValues {}
 
 impl EvalValues for ByteKnightValues {
     type ReturnScore = PhasedScore;
 
-    fn psqt(&self, square: u8, piece: Piece, side: Side) -> Self::ReturnScore {
-        PSQTS[piece as usize][square::flip_if(side == Side::White, square) as usize]
+    fn psqt(&self, square: u8, piece: Piece, side: PlayerSide) -> Self::ReturnScore {
+        PSQTS[piece as usize][square::flip_if(side == PlayerSide::White, square) as usize]
+    }
+
+    fn doubled_pawn(&self) -> Self::ReturnScore {
+        DOUBLED_PAWN
+    }
+
+    fn isolated_pawn(&self) -> Self::ReturnScore {
+        ISOLATED_PAWN
+    }
+
+    fn backward_pawn(&self) -> Self::ReturnScore {
+        BACKWARD_PAWN
+    }
+
+    fn passed_pawn(&self, relative_rank: u8) -> Self::ReturnScore {
+        PASSED_PAWN[relative_rank as usize]
+    }
+
+    fn protected_passed_pawn(&self) -> Self::ReturnScore {
+        PROTECTED_PASSED_PAWN
+    }
+
+    fn connected_passed_pawn(&self) -> Self::ReturnScore {
+        CONNECTED_PASSED_PAWN
+    }
+
+    fn pawn_shield(&self, zone: KingZone, distance: u8) -> Self::ReturnScore {
+        PAWN_SHIELD[zone as usize][distance as usize]
+    }
+
+    fn pawn_storm(&self, zone: KingZone, distance: u8) -> Self::ReturnScore {
+        PAWN_STORM[zone as usize][distance as usize]
+    }
+
+    fn bad_bishop(&self) -> Self::ReturnScore {
+        BAD_BISHOP
     }
 }
 
@@ -96,7 +173,7 @@ mod tests {
     use chess::board::Board;
 
     use super::*;
-    use crate::{evaluation::Evaluation, psqt::Psqt, traits::Eval};
+    use crate::{evaluation::Evaluation, psqt::Psqt};
 
     #[test]
     fn verify_values_match_pesto() {
@@ -111,7 +188,9 @@ mod tests {
 
         let score = psqt.evaluate(&board);
         println!("{}", score);
-        let new_eval_score = eval.eval(&board);
+        // only compare the piece square tables, the other evaluation terms are not part of PeSTO
+        let breakdown = eval.evaluate_debug(&board);
+        let new_eval_score = breakdown.psqt.collapse(breakdown.game_phase);
         println!("{}", new_eval_score);
         assert_eq!(score, new_eval_score);
     }
@@ -1,55 +1,67 @@
-use chess::{
-    definitions::NumberOf,
-    pieces::{Piece, PIECE_NAMES},
-    side::Side,
-};
+use chess::{definitions::NumberOf, moves::Move, pieces::SQUARE_NAME, side::PlayerSide};
 
 use crate::score::{LargeScoreType, Score};
 
+/// Butterfly history table for quiet move ordering.
+///
+/// Scores are indexed by `[side_to_move][from_square][to_square]`, so the same from/to pair is
+/// tracked separately for each side and independently of the piece that moved. Only quiet moves
+/// are scored, captures are ordered by MVV/LVA instead.
 pub struct HistoryTable {
-    table: [[[LargeScoreType; NumberOf::SQUARES]; NumberOf::PIECE_TYPES]; NumberOf::SIDES],
+    table: [[[LargeScoreType; NumberOf::SQUARES]; NumberOf::SQUARES]; NumberOf::SIDES],
 }
 
 impl HistoryTable {
     pub(crate) fn new() -> Self {
-        let table =
-            [[[Default::default(); NumberOf::SQUARES]; NumberOf::PIECE_TYPES]; NumberOf::SIDES];
+        let table = [[[Default::default(); NumberOf::SQUARES]; NumberOf::SQUARES]; NumberOf::SIDES];
         Self { table }
     }
 
-    pub(crate) fn get(&self, side: Side, piece: Piece, square: u8) -> LargeScoreType {
-        assert!(side != Side::Both, "Side cannot be Both");
-        self.table[side as usize][piece as usize][square as usize]
+    /// Returns the history score of `mv` for `side`.
+    pub(crate) fn get(&self, side: PlayerSide, mv: &Move) -> LargeScoreType {
+        self.table[side as usize][mv.from() as usize][mv.to() as usize]
     }
 
-    pub(crate) fn update(&mut self, side: Side, piece: Piece, square: u8, bonus: LargeScoreType) {
-        assert!(side != Side::Both, "Side cannot be Both");
-        let current_value = self.table[side as usize][piece as usize][square as usize];
+    /// Applies a history bonus (or a penalty if negative) to `mv` for `side`. The bonus is clamped
+    /// to [`Score::MAX_HISTORY`] and scaled down as the score approaches it. Moves that are not
+    /// quiet are ignored.
+    pub(crate) fn update(&mut self, side: PlayerSide, mv: &Move, bonus: LargeScoreType) {
+        if !mv.is_quiet() {
+            return;
+        }
+
+        let entry = &mut self.table[side as usize][mv.from() as usize][mv.to() as usize];
+        let current_value = *entry;
         let clamped_bonus = bonus.clamp(-Score::MAX_HISTORY, Score::MAX_HISTORY);
-        let new_value = current_value + clamped_bonus
+        *entry = current_value + clamped_bonus
             - current_value * clamped_bonus.abs() / Score::MAX_HISTORY;
-        self.table[side as usize][piece as usize][square as usize] = new_value;
     }
 
     pub(crate) fn clear(&mut self) {
         for side in 0..NumberOf::SIDES {
-            for piece_type in 0..NumberOf::PIECE_TYPES {
-                for square in 0..NumberOf::SQUARES {
-                    self.table[side][piece_type][square] = Default::default();
+            for from in 0..NumberOf::SQUARES {
+                for to in 0..NumberOf::SQUARES {
+                    self.table[side][from][to] = Default::default();
                 }
             }
         }
     }
 
-    pub(crate) fn print_for_side(&self, side: Side) {
-        for (piece_type, piece_name) in PIECE_NAMES.iter().enumerate() {
-            println!("{} - {}", piece_name, side);
+    /// Prints the history of `side` as one board of destination squares for every from square
+    /// that has any history.
+    pub(crate) fn print_for_side(&self, side: PlayerSide) {
+        for (from, to_squares) in self.table[side as usize].iter().enumerate() {
+            if to_squares.iter().all(|score| *score == 0) {
+                continue;
+            }
+
+            println!("{} - {}", SQUARE_NAME[from], side);
             // print from white's perspective
             for rank in (0..=NumberOf::RANKS - 1).rev() {
                 print!("|");
                 for file in 0..NumberOf::FILES {
                     let square = file + rank * NumberOf::FILES;
-                    print!("{:5} ", self.table[side as usize][piece_type][square]);
+                    print!("{:5} ", to_squares[square]);
                 }
                 println!("|");
             }
@@ -66,19 +78,33 @@ impl Default for HistoryTable {
 #[cfg(test)]
 mod tests {
     use super::HistoryTable;
-    use chess::{definitions::Squares, pieces::Piece, side::Side};
+    use chess::{
+        definitions::Squares,
+        moves::{Move, MoveDescriptor},
+        pieces::Piece,
+        side::PlayerSide,
+        square::Square,
+    };
+
+    fn quiet_move(from: u8, to: u8, piece: Piece) -> Move {
+        Move::new(
+            &Square::from_square_index(from),
+            &Square::from_square_index(to),
+            MoveDescriptor::None,
+            piece,
+            None,
+            None,
+        )
+    }
 
     #[test]
     fn initialize_history_table() {
         let history_table = HistoryTable::new();
-        // loop through all sides, piece types, and squares
+        // loop through all sides, from squares, and to squares
         for side in 0..2 {
-            for piece_type in 0..6 {
-                for square in 0..64 {
-                    assert_eq!(
-                        history_table.table[side][piece_type][square],
-                        Default::default()
-                    );
+            for from in 0..64 {
+                for to in 0..64 {
+                    assert_eq!(history_table.table[side][from][to], Default::default());
                 }
             }
         }
@@ -87,13 +113,44 @@ mod tests {
     #[test]
     fn store_and_read() {
         let mut history_table = HistoryTable::new();
-        let side = Side::Black;
-        let piece = Piece::Pawn;
-        let square = Squares::A1;
+        let side = PlayerSide::Black;
+        let mv = quiet_move(Squares::A7, Squares::A6, Piece::Pawn);
         let score = 37;
-        history_table.update(side, piece, square, score);
-        assert_eq!(history_table.get(side, piece, square), score);
-        history_table.update(side, piece, square, score);
-        assert_eq!(history_table.get(side, piece, square), score + score);
+        history_table.update(side, &mv, score);
+        assert_eq!(history_table.get(side, &mv), score);
+        history_table.update(side, &mv, score);
+        assert_eq!(history_table.get(side, &mv), score + score);
+    }
+
+    #[test]
+    fn indexed_by_side_from_and_to() {
+        let mut history_table = HistoryTable::new();
+        let e2e4 = quiet_move(Squares::E2, Squares::E4, Piece::Pawn);
+        history_table.update(PlayerSide::White, &e2e4, 100);
+        assert_eq!(history_table.get(PlayerSide::White, &e2e4), 100);
+
+        // black's lookups are not affected
+        assert_eq!(history_table.get(PlayerSide::Black, &e2e4), 0);
+        // the same from/to pair shares the score regardless of the moving piece
+        let queen_e2e4 = quiet_move(Squares::E2, Squares::E4, Piece::Queen);
+        assert_eq!(history_table.get(PlayerSide::White, &queen_e2e4), 100);
+        // other moves to the same square are not affected
+        let d2e4 = quiet_move(Squares::D2, Squares::E4, Piece::Bishop);
+        assert_eq!(history_table.get(PlayerSide::White, &d2e4), 0);
+    }
+
+    #[test]
+    fn captures_are_not_scored() {
+        let mut history_table = HistoryTable::new();
+        let capture = Move::new(
+            &Square::from_square_index(Squares::E4),
+            &Square::from_square_index(Squares::D5),
+            MoveDescriptor::None,
+            Piece::Pawn,
+            Some(Piece::Pawn),
+            None,
+        );
+        history_table.update(PlayerSide::White, &capture, 100);
+        assert_eq!(history_table.get(PlayerSide::White, &capture), 0);
     }
 }
@@ -0,0 +1,160 @@
+/*
+ * info_throttle.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use std::{
+    fmt::Display,
+    io::Write,
+    time::{Duration, Instant},
+};
+
+/// The minimum time between two intermediate UCI `info` lines.
+pub const INFO_UPDATE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Limits how often intermediate UCI `info` lines are written, so fast searches don't flood the GUI.
+///
+/// Intermediate updates (for example bounds from aspiration re-searches) are written at most once
+/// per interval. Updates that arrive too early are coalesced: only the latest one is kept as the
+/// pending update. The next update after the interval replaces it and is written instead, and
+/// [`InfoThrottle::flush`] writes it right away. End of iteration lines are always written right
+/// away and drop any pending update, since they are more up to date.
+#[derive(Clone, Debug)]
+pub struct InfoThrottle {
+    interval: Duration,
+    last_written: Option<Instant>,
+    pending: Option<String>,
+}
+
+impl Default for InfoThrottle {
+    fn default() -> Self {
+        Self::new(INFO_UPDATE_INTERVAL)
+    }
+}
+
+impl InfoThrottle {
+    /// Creates a new throttle that writes at most one intermediate update per `interval`.
+    pub fn new(interval: Duration) -> Self {
+        InfoThrottle {
+            interval,
+            last_written: None,
+            pending: None,
+        }
+    }
+
+    /// Writes an intermediate update if the interval since the last written line has passed,
+    /// otherwise keeps it as the pending update.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` - Where the line is written, usually stdout.
+    /// - `line` - The info line.
+    /// - `now` - The current time.
+    pub fn update(&mut self, writer: &mut impl Write, line: impl Display, now: Instant) {
+        let due = self
+            .last_written
+            .is_none_or(|last| now.duration_since(last) >= self.interval);
+        if due {
+            self.pending = None;
+            self.write(writer, line, now);
+        } else {
+            self.pending = Some(line.to_string());
+        }
+    }
+
+    /// Writes an end of iteration line right away, dropping any pending update.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` - Where the line is written, usually stdout.
+    /// - `line` - The info line.
+    /// - `now` - The current time.
+    pub fn iteration_end(&mut self, writer: &mut impl Write, line: impl Display, now: Instant) {
+        self.pending = None;
+        self.write(writer, line, now);
+    }
+
+    /// Writes the pending update if there is one, regardless of the interval.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` - Where the line is written, usually stdout.
+    /// - `now` - The current time.
+    pub fn flush(&mut self, writer: &mut impl Write, now: Instant) {
+        if let Some(line) = self.pending.take() {
+            self.write(writer, line, now);
+        }
+    }
+
+    fn write(&mut self, writer: &mut impl Write, line: impl Display, now: Instant) {
+        writeln!(writer, "{}", line).unwrap();
+        self.last_written = Some(now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{InfoThrottle, INFO_UPDATE_INTERVAL};
+
+    #[test]
+    fn rapid_updates_are_coalesced() {
+        let mut throttle = InfoThrottle::default();
+        let mut output = Vec::new();
+        let start = Instant::now();
+
+        // ten updates within 100ms only write the first one
+        for i in 0..10 {
+            let now = start + Duration::from_millis(i * 9);
+            throttle.update(&mut output, format!("info nodes {}", i), now);
+        }
+        assert_eq!(String::from_utf8(output.clone()).unwrap(), "info nodes 0\n");
+
+        // flushing writes the latest pending update, even within the interval
+        output.clear();
+        throttle.flush(&mut output, start + Duration::from_millis(95));
+        assert_eq!(String::from_utf8(output.clone()).unwrap(), "info nodes 9\n");
+
+        output.clear();
+        throttle.flush(&mut output, start + Duration::from_millis(96));
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn iteration_end_is_always_written() {
+        let mut throttle = InfoThrottle::default();
+        let mut output = Vec::new();
+        let start = Instant::now();
+
+        throttle.update(&mut output, "info depth 1 lowerbound", start);
+        throttle.update(&mut output, "info depth 1 upperbound", start);
+        throttle.iteration_end(&mut output, "info depth 1", start);
+        // the next update has to wait for the interval again
+        throttle.update(&mut output, "info depth 2 lowerbound", start);
+        throttle.update(
+            &mut output,
+            "info depth 2 upperbound",
+            start + INFO_UPDATE_INTERVAL,
+        );
+
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(
+            output.lines().collect::<Vec<_>>(),
+            [
+                "info depth 1 lowerbound",
+                "info depth 1",
+                "info depth 2 upperbound"
+            ]
+        );
+    }
+}
@@ -28,6 +28,7 @@ use uci_parser::UciCommand;
 pub(crate) enum EngineCommand {
     HashInfo,
     History,
+    Eval,
 }
 
 impl FromStr for EngineCommand {
@@ -37,6 +38,7 @@ impl FromStr for EngineCommand {
         match s {
             "hash" => Ok(EngineCommand::HashInfo),
             "history" => Ok(EngineCommand::History),
+            "eval" => Ok(EngineCommand::Eval),
             _ => Err(anyhow::anyhow!("Invalid engine command")),
         }
     }
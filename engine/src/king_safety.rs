@@ -0,0 +1,167 @@
+/*
+ * king_safety.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use chess::{board::Board, definitions::NumberOf, pieces::Piece, side::PlayerSide};
+
+use crate::{
+    pawn_structure::{file_mask, ranks_at_or_behind, relative_rank},
+    phased_score::{PhasedScore, S},
+    traits::EvalValues,
+};
+
+/// Number of entries in the pawn shield and storm tables. Index 0 means there is no pawn on the
+/// file within range, indices 1 to 3 are the number of ranks the pawn is in front of the king.
+pub const PAWN_DISTANCES: usize = 4;
+
+/// The part of the board the king is on. Pawn shield and storm values depend on it, since a
+/// castled king relies much more on its pawns than a king in the center.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KingZone {
+    /// Files a to c.
+    Queenside = 0,
+    /// Files d and e.
+    Center = 1,
+    /// Files f to h.
+    Kingside = 2,
+}
+
+impl KingZone {
+    /// The number of king zones.
+    pub const COUNT: usize = 3;
+
+    /// Returns the zone of a king on the given file (0 - 7).
+    pub fn from_file(file: usize) -> Self {
+        match file {
+            0..=2 => KingZone::Queenside,
+            3..=4 => KingZone::Center,
+            _ => KingZone::Kingside,
+        }
+    }
+}
+
+/// Returns the distance in ranks from the king to the closest of the given pawns on `file` that is
+/// in front of the king, relative to `side`. Returns 0 if there is no such pawn within range.
+fn closest_pawn_distance(pawns: u64, file: usize, king_square: usize, side: PlayerSide) -> u8 {
+    let king_rank = king_square / NumberOf::FILES;
+    let mut in_front = pawns & file_mask(file) & !ranks_at_or_behind(king_rank, side);
+    let king_relative_rank = relative_rank(king_square, side);
+
+    let mut closest = 0;
+    while in_front != 0 {
+        let sq = in_front.trailing_zeros() as usize;
+        in_front &= in_front - 1;
+        let distance = relative_rank(sq, side) - king_relative_rank;
+        if (distance as usize) < PAWN_DISTANCES && (closest == 0 || distance < closest) {
+            closest = distance;
+        }
+    }
+    closest
+}
+
+/// Returns the files covered by the pawn shield of a king on `king_file`: its own file and the
+/// adjacent files.
+fn shield_files(king_file: usize) -> impl Iterator<Item = usize> {
+    king_file.saturating_sub(1)..=(king_file + 1).min(NumberOf::FILES - 1)
+}
+
+/// Evaluates the king safety of `side`: bonuses for friendly pawns in front of the king (the pawn
+/// shield) and penalties for enemy pawns advancing towards it (the pawn storm).
+///
+/// # Arguments
+///
+/// - `board` - The board to evaluate.
+/// - `side` - The side whose king is evaluated.
+/// - `values` - The evaluation values to use.
+pub(crate) fn evaluate<Values: EvalValues<ReturnScore = PhasedScore>>(
+    board: &Board,
+    side: PlayerSide,
+    values: &Values,
+) -> PhasedScore {
+    let king_square = board.king_square(side) as usize;
+    let king_file = king_square % NumberOf::FILES;
+    let zone = KingZone::from_file(king_file);
+    let pawns = board.piece_bitboard(Piece::Pawn, side).as_number();
+    let enemy_pawns = board
+        .piece_bitboard(Piece::Pawn, PlayerSide::opposite(side))
+        .as_number();
+
+    let mut score = S(0, 0);
+    for file in shield_files(king_file) {
+        score += values.pawn_shield(zone, closest_pawn_distance(pawns, file, king_square, side));
+        score += values.pawn_storm(
+            zone,
+            closest_pawn_distance(enemy_pawns, file, king_square, side),
+        );
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use chess::{board::Board, pieces::Piece, side::PlayerSide};
+
+    use super::{closest_pawn_distance, KingZone};
+    use crate::{evaluation::ByteKnightEvaluation, traits::Eval};
+
+    #[test]
+    fn measures_shield_and_storm_distances() {
+        // white castled short with pawns on f2, g3 and h2. Black pawns storm down the g and h
+        // files, the h4 pawn is three ranks in front of the king.
+        let board = Board::from_fen("4k3/8/8/6p1/7p/6P1/5P1P/6K1 w - - 0 1").unwrap();
+        let king = board.king_square(PlayerSide::White) as usize;
+        let white = board.piece_bitboard(Piece::Pawn, PlayerSide::White);
+        let black = board.piece_bitboard(Piece::Pawn, PlayerSide::Black);
+
+        assert_eq!(KingZone::from_file(king % 8), KingZone::Kingside);
+        assert_eq!(
+            closest_pawn_distance(white.as_number(), 5, king, PlayerSide::White),
+            1
+        );
+        assert_eq!(
+            closest_pawn_distance(white.as_number(), 6, king, PlayerSide::White),
+            2
+        );
+        assert_eq!(
+            closest_pawn_distance(black.as_number(), 7, king, PlayerSide::White),
+            3
+        );
+        // the g5 pawn is too far away to count
+        assert_eq!(
+            closest_pawn_distance(black.as_number(), 6, king, PlayerSide::White),
+            0
+        );
+        // nothing on the e file
+        assert_eq!(
+            closest_pawn_distance(white.as_number(), 4, king, PlayerSide::White),
+            0
+        );
+    }
+
+    #[test]
+    fn missing_shield_pawns_score_worse() {
+        let eval = ByteKnightEvaluation::default();
+
+        // both sides castled short, then white's f, g and h pawns moved to the queenside so the
+        // material stays the same
+        let shielded =
+            Board::from_fen("r4rk1/ppp2ppp/2n5/8/8/2N5/PPP2PPP/R4RK1 w - - 0 1").unwrap();
+        let exposed = Board::from_fen("r4rk1/ppp2ppp/2n5/8/8/PPN5/PPPP4/R4RK1 w - - 0 1").unwrap();
+        assert!(eval.eval(&exposed) < eval.eval(&shielded));
+
+        let shielded_breakdown = eval.evaluate_debug(&shielded);
+        let exposed_breakdown = eval.evaluate_debug(&exposed);
+        assert_eq!(shielded_breakdown.king_safety.mg(), 0);
+        assert!(exposed_breakdown.king_safety.mg() < 0);
+    }
+}
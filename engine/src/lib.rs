@@ -2,17 +2,26 @@
 #![feature(type_alias_impl_trait)]
 
 pub mod aspiration_window;
+pub mod bad_bishop;
 pub mod defs;
+pub mod endgame;
 pub mod engine;
 pub mod evaluation;
 pub mod hce_values;
 pub mod history_table;
+pub mod info_throttle;
 pub mod input_handler;
+pub mod king_safety;
+pub mod log_level;
+pub mod move_order;
+pub mod pawn_structure;
 pub mod phased_score;
 pub mod psqt;
 pub mod score;
 pub mod search;
 pub mod search_thread;
+pub mod see;
+pub mod timer;
 pub mod traits;
 pub mod ttable;
 pub mod tuneable;
@@ -0,0 +1,131 @@
+/*
+ * log_level.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use std::{fmt::Display, io::Write, str::FromStr};
+
+use uci_parser::{UciInfo, UciResponse};
+
+/// Verbosity of the diagnostic messages the engine sends as UCI `info string` lines.
+///
+/// Levels are ordered from least to most verbose. A message is only sent if its level is less than
+/// or equal to the configured level.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LogLevel {
+    /// No diagnostic messages at all.
+    Off,
+    /// Errors, such as an invalid position or option.
+    Error,
+    /// Recoverable problems the user may want to know about.
+    Warning,
+    /// General information, such as a resized hash table.
+    #[default]
+    Info,
+    /// Detailed search diagnostics.
+    Debug,
+}
+
+impl LogLevel {
+    /// Returns true if messages of the given `level` should be sent when this is the configured level.
+    pub fn allows(&self, level: LogLevel) -> bool {
+        level != LogLevel::Off && level <= *self
+    }
+}
+
+impl FromStr for LogLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "off" => Ok(LogLevel::Off),
+            "error" => Ok(LogLevel::Error),
+            "warning" => Ok(LogLevel::Warning),
+            "info" => Ok(LogLevel::Info),
+            "debug" => Ok(LogLevel::Debug),
+            _ => Err(anyhow::anyhow!("Invalid log level: {}", s)),
+        }
+    }
+}
+
+impl Display for LogLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogLevel::Off => write!(f, "off"),
+            LogLevel::Error => write!(f, "error"),
+            LogLevel::Warning => write!(f, "warning"),
+            LogLevel::Info => write!(f, "info"),
+            LogLevel::Debug => write!(f, "debug"),
+        }
+    }
+}
+
+/// Writes `message` as a UCI `info string` line if `level` is allowed by the configured `log_level`.
+/// This is the only way diagnostics should reach stdout so that GUIs are not confused by non-UCI output.
+///
+/// # Arguments
+///
+/// - `writer` - Where to write the message, usually stdout.
+/// - `log_level` - The configured log level.
+/// - `level` - The level of this message.
+/// - `message` - The message to send.
+pub(crate) fn log_info_string(
+    writer: &mut impl Write,
+    log_level: LogLevel,
+    level: LogLevel,
+    message: impl Display,
+) {
+    if log_level.allows(level) {
+        let info = UciInfo::default().string(message.to_string());
+        writeln!(writer, "{}", UciResponse::info(info)).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::{log_info_string, LogLevel};
+
+    #[test]
+    fn levels_are_gated() {
+        assert!(LogLevel::Info.allows(LogLevel::Error));
+        assert!(LogLevel::Info.allows(LogLevel::Info));
+        assert!(!LogLevel::Info.allows(LogLevel::Debug));
+        assert!(LogLevel::Debug.allows(LogLevel::Debug));
+        assert!(!LogLevel::Off.allows(LogLevel::Error));
+        assert!(!LogLevel::Debug.allows(LogLevel::Off));
+
+        let mut output = Vec::new();
+        log_info_string(&mut output, LogLevel::Warning, LogLevel::Debug, "hidden");
+        log_info_string(&mut output, LogLevel::Warning, LogLevel::Error, "shown");
+        assert_eq!(
+            String::from_utf8(output).unwrap().trim_end(),
+            "info string shown"
+        );
+    }
+
+    #[test]
+    fn parse_log_level() {
+        for level in [
+            LogLevel::Off,
+            LogLevel::Error,
+            LogLevel::Warning,
+            LogLevel::Info,
+            LogLevel::Debug,
+        ] {
+            assert_eq!(LogLevel::from_str(&level.to_string()).unwrap(), level);
+        }
+        assert_eq!(LogLevel::from_str("DEBUG").unwrap(), LogLevel::Debug);
+        assert!(LogLevel::from_str("verbose").is_err());
+    }
+}
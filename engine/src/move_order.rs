@@ -0,0 +1,298 @@
+/*
+ * move_order.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use std::fmt::Display;
+
+use chess::{
+    bitboard::Bitboard, board::Board, move_generation::MoveGenerator, moves::Move, pieces::Piece,
+    side::PlayerSide,
+};
+
+use crate::{
+    evaluation::ByteKnightEvaluation,
+    history_table::HistoryTable,
+    score::LargeScoreType,
+    see::{self, SeeValues},
+    tuneable::THREAT_ORDERING_BONUS,
+};
+
+/// Score of a move used for move ordering. Higher scores are searched first.
+///
+/// Every move falls into one of the categories below, from best to worst. Each category starts at
+/// its base score and moves within a category are ordered by an additional, category specific
+/// score that always stays below [`MoveScore::CATEGORY_RANGE`]:
+///
+/// 1. [`MoveScore::TT_MOVE`] - the move stored in the transposition table.
+/// 2. [`MoveScore::GOOD_CAPTURE`] - captures that don't lose material according to SEE, ordered
+///    by MVV/LVA. Capturing a piece that threatens one of ours breaks ties.
+/// 3. [`MoveScore::KILLER`] - killer moves.
+/// 4. [`MoveScore::COUNTERMOVE`] - counter moves.
+/// 5. [`MoveScore::QUIET`] - quiet moves and promotions, ordered by their history score. Moving a
+///    piece out of a threat by a less valuable piece adds a bonus. This is
+///    the only category that can go below its base score, by at most
+///    [`Score::MAX_HISTORY`](crate::score::Score::MAX_HISTORY).
+/// 6. [`MoveScore::BAD_CAPTURE`] - captures that lose material according to SEE, ordered by
+///    MVV/LVA.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MoveScore(LargeScoreType);
+
+impl MoveScore {
+    /// The range of scores within a single category.
+    pub const CATEGORY_RANGE: LargeScoreType = 1 << 24;
+    pub const TT_MOVE: MoveScore = MoveScore(1 << 29);
+    pub const GOOD_CAPTURE: MoveScore = MoveScore(1 << 28);
+    pub const KILLER: MoveScore = MoveScore(1 << 27);
+    pub const COUNTERMOVE: MoveScore = MoveScore(1 << 26);
+    pub const QUIET: MoveScore = MoveScore(0);
+    pub const BAD_CAPTURE: MoveScore = MoveScore(-(1 << 28));
+
+    /// Returns a score within the category starting at `base`.
+    fn in_category(base: MoveScore, score: LargeScoreType) -> MoveScore {
+        debug_assert!(score < Self::CATEGORY_RANGE);
+        MoveScore(base.0 + score)
+    }
+
+    /// Returns `true` if the score belongs to a capture that loses material.
+    pub fn is_bad_capture(&self) -> bool {
+        self.0 < Self::BAD_CAPTURE.0 + Self::CATEGORY_RANGE
+    }
+}
+
+impl Display for MoveScore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Assigns [`MoveScore`]s to the moves of a single position.
+pub struct MoveOrder<'a> {
+    side_to_move: PlayerSide,
+    tt_move: Move,
+    history_table: &'a HistoryTable,
+    see_values: SeeValues,
+    /// Our pieces attacked by a less valuable enemy piece.
+    threatened: Bitboard,
+    /// Enemy pieces attacking one of the threatened pieces.
+    threats: Bitboard,
+}
+
+impl<'a> MoveOrder<'a> {
+    /// Creates a new move order for the given position.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The position the moves are played from.
+    /// - `move_gen` - The move generator, used to find threatened pieces.
+    /// - `tt_move` - The transposition table move, or [`Move::NULL`] if there is none.
+    /// - `history_table` - The history table used to order quiet moves.
+    pub fn new(
+        board: &Board,
+        move_gen: &MoveGenerator,
+        tt_move: Move,
+        history_table: &'a HistoryTable,
+    ) -> Self {
+        let (threatened, threats) = Self::find_threats(board, move_gen);
+        MoveOrder {
+            side_to_move: board.side_to_move(),
+            tt_move,
+            history_table,
+            see_values: see::piece_values(board),
+            threatened,
+            threats,
+        }
+    }
+
+    /// Finds the pieces of the side to move that are attacked by a less valuable enemy piece, and
+    /// the enemy pieces attacking them.
+    fn find_threats(board: &Board, move_gen: &MoveGenerator) -> (Bitboard, Bitboard) {
+        let us = board.side_to_move();
+        let them = PlayerSide::opposite(us);
+        let occupancy = board.all_pieces();
+        let attacks = |piece| move_gen.piece_attacks_by(board, piece, them, &occupancy);
+        let ours = |piece| *board.piece_bitboard(piece, us);
+
+        let by_pawns = attacks(Piece::Pawn);
+        let by_minors = by_pawns | attacks(Piece::Knight) | attacks(Piece::Bishop);
+        let by_rooks = by_minors | attacks(Piece::Rook);
+        let threatened = (ours(Piece::Knight) | ours(Piece::Bishop)) & by_pawns
+            | ours(Piece::Rook) & by_minors
+            | ours(Piece::Queen) & by_rooks;
+
+        let mut threats = Bitboard::default();
+        let mut remaining = threatened;
+        while let Some(square) = remaining.pop_lsb() {
+            threats |= move_gen.attackers_to(board, square, &occupancy) & board.pieces(them);
+        }
+        (threatened, threats)
+    }
+
+    /// Scores a move for ordering, see [`MoveScore`] for the categories.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The position the move is played from, the same as passed to [`MoveOrder::new`].
+    /// - `move_gen` - The move generator, used for static exchange evaluation.
+    /// - `mv` - The move to score.
+    ///
+    /// # Returns
+    ///
+    /// The ordering score of the move.
+    pub fn score(&self, board: &Board, move_gen: &MoveGenerator, mv: &Move) -> MoveScore {
+        if !self.tt_move.is_null() && *mv == self.tt_move {
+            return MoveScore::TT_MOVE;
+        }
+
+        if mv.is_capture() {
+            // safe to unwrap the captured piece because we already checked
+            let mut mvv_lva =
+                ByteKnightEvaluation::mvv_lva(mv.captured_piece().unwrap(), mv.piece());
+            if self.threats.is_square_occupied(mv.to()) {
+                mvv_lva += THREAT_ORDERING_BONUS;
+            }
+            let base = if see::see(board, move_gen, mv, 0, &self.see_values) {
+                MoveScore::GOOD_CAPTURE
+            } else {
+                MoveScore::BAD_CAPTURE
+            };
+            return MoveScore::in_category(base, mvv_lva);
+        }
+
+        if mv.is_quiet() {
+            let mut score = self.history_table.get(self.side_to_move, mv);
+            if self.threatened.is_square_occupied(mv.from()) {
+                score += THREAT_ORDERING_BONUS;
+            }
+            return MoveScore::in_category(MoveScore::QUIET, score);
+        }
+
+        MoveScore::QUIET
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chess::{
+        board::Board, definitions::Squares, move_generation::MoveGenerator, move_list::MoveList,
+        moves::Move, pieces::ALL_PIECES,
+    };
+
+    use super::{MoveOrder, MoveScore};
+    use crate::{evaluation::ByteKnightEvaluation, history_table::HistoryTable, score::Score};
+
+    #[test]
+    fn categories_do_not_overlap() {
+        let categories = [
+            MoveScore::TT_MOVE,
+            MoveScore::GOOD_CAPTURE,
+            MoveScore::KILLER,
+            MoveScore::COUNTERMOVE,
+            MoveScore::QUIET,
+            MoveScore::BAD_CAPTURE,
+        ];
+        for pair in categories.windows(2) {
+            assert!(pair[0].0 - Score::MAX_HISTORY > pair[1].0 + MoveScore::CATEGORY_RANGE);
+        }
+
+        for captured in ALL_PIECES {
+            for capturing in ALL_PIECES {
+                let mvv_lva = ByteKnightEvaluation::mvv_lva(captured, capturing);
+                assert!((0..MoveScore::CATEGORY_RANGE).contains(&mvv_lva));
+            }
+        }
+    }
+
+    #[test]
+    fn orders_moves_by_category() {
+        // the queen can take an undefended knight on g5 or the pawn on d5, which is defended by
+        // the e6 pawn. The rook can take an undefended pawn on a7.
+        let board = Board::from_fen("4k3/p7/1p2p3/3p2n1/8/8/3Q4/R3K3 w - - 0 1").unwrap();
+        let move_gen = MoveGenerator::new();
+        let mut move_list = MoveList::new();
+        move_gen.generate_legal_moves(&board, &mut move_list);
+        let find = |from: u8, to: u8| -> Move {
+            *move_list
+                .iter()
+                .find(|mv| mv.from() == from && mv.to() == to)
+                .unwrap()
+        };
+
+        let tt_move = find(Squares::E1, Squares::F1);
+        let queen_takes_knight = find(Squares::D2, Squares::G5);
+        let rook_takes_pawn = find(Squares::A1, Squares::A7);
+        let history_quiet = find(Squares::D2, Squares::D3);
+        let quiet = find(Squares::E1, Squares::D1);
+        let queen_takes_pawn = find(Squares::D2, Squares::D5);
+
+        let mut history_table = HistoryTable::new();
+        history_table.update(board.side_to_move(), &history_quiet, 100);
+        let move_order = MoveOrder::new(&board, &move_gen, tt_move, &history_table);
+        let score = |mv: &Move| move_order.score(&board, &move_gen, mv);
+
+        let ordered = [
+            tt_move,
+            queen_takes_knight,
+            rook_takes_pawn,
+            history_quiet,
+            quiet,
+            queen_takes_pawn,
+        ];
+        for pair in ordered.windows(2) {
+            assert!(score(&pair[0]) > score(&pair[1]), "{} {}", pair[0], pair[1]);
+        }
+
+        assert_eq!(score(&tt_move), MoveScore::TT_MOVE);
+        assert!(score(&queen_takes_knight) > MoveScore::GOOD_CAPTURE);
+        assert_eq!(score(&quiet), MoveScore::QUIET);
+        assert!(score(&queen_takes_pawn).is_bad_capture());
+        assert!(!score(&rook_takes_pawn).is_bad_capture());
+        assert!(!score(&quiet).is_bad_capture());
+
+        // without a transposition table move, the king move is just another quiet
+        let move_order = MoveOrder::new(&board, &move_gen, Move::NULL, &history_table);
+        assert_eq!(
+            move_order.score(&board, &move_gen, &tt_move),
+            MoveScore::QUIET
+        );
+    }
+
+    #[test]
+    fn threatened_pieces_are_ordered_first() {
+        // the knight on e6 attacks the queen, the knight on c6 doesn't threaten anything
+        let board = Board::from_fen("4k3/8/2n1n3/3P4/5Q2/8/8/4K3 w - - 0 1").unwrap();
+        let move_gen = MoveGenerator::new();
+        let moves = move_gen.generate_legal_moves_vec(&board);
+        let find = |from: u8, to: u8| -> Move {
+            *moves
+                .iter()
+                .find(|mv| mv.from() == from && mv.to() == to)
+                .unwrap()
+        };
+
+        let history_table = HistoryTable::new();
+        let move_order = MoveOrder::new(&board, &move_gen, Move::NULL, &history_table);
+        let score = |mv: &Move| move_order.score(&board, &move_gen, mv);
+
+        // moving the attacked queen comes before a neutral quiet move
+        let queen_escape = find(Squares::F4, Squares::A4);
+        let king_move = find(Squares::E1, Squares::D1);
+        assert!(score(&queen_escape) > score(&king_move));
+        assert_eq!(score(&king_move), MoveScore::QUIET);
+
+        // taking the attacker comes before an otherwise equal capture
+        let takes_attacker = find(Squares::D5, Squares::E6);
+        let takes_other = find(Squares::D5, Squares::C6);
+        assert!(score(&takes_attacker) > score(&takes_other));
+        assert!(score(&takes_other) > MoveScore::GOOD_CAPTURE);
+    }
+}
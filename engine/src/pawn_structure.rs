@@ -0,0 +1,291 @@
+/*
+ * pawn_structure.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use chess::{
+    bitboard::Bitboard,
+    bitboard_helpers,
+    board::Board,
+    definitions::{NumberOf, FILE_A},
+    pieces::Piece,
+    side::PlayerSide,
+};
+
+use crate::{
+    phased_score::{PhasedScore, S},
+    traits::EvalValues,
+};
+
+const FILE_H: u64 = FILE_A << 7;
+
+/// Returns a mask of all squares on the given file.
+pub(crate) const fn file_mask(file: usize) -> u64 {
+    FILE_A << file
+}
+
+/// Returns a mask of all squares on the files next to the given file.
+pub(crate) const fn adjacent_files_mask(file: usize) -> u64 {
+    let file = file_mask(file);
+    ((file << 1) & !FILE_A) | ((file >> 1) & !FILE_H)
+}
+
+/// Returns a mask of all squares on the given rank and the ranks behind it, relative to `side`.
+pub(crate) fn ranks_at_or_behind(rank: usize, side: PlayerSide) -> u64 {
+    match side {
+        PlayerSide::White => u64::MAX >> ((NumberOf::RANKS - 1 - rank) * NumberOf::FILES),
+        _ => u64::MAX << (rank * NumberOf::FILES),
+    }
+}
+
+/// Returns all squares attacked by the given pawns.
+fn pawn_attacks(pawns: u64, side: PlayerSide) -> u64 {
+    match side {
+        PlayerSide::White => ((pawns << 7) & !FILE_H) | ((pawns << 9) & !FILE_A),
+        _ => ((pawns >> 9) & !FILE_H) | ((pawns >> 7) & !FILE_A),
+    }
+}
+
+/// Returns the number of doubled pawns of `side`. Every pawn on a file beyond the first counts once.
+pub(crate) fn doubled_pawns(board: &Board, side: PlayerSide) -> u32 {
+    let pawns = board.piece_bitboard(Piece::Pawn, side).as_number();
+    (0..NumberOf::FILES)
+        .map(|file| (pawns & file_mask(file)).count_ones().saturating_sub(1))
+        .sum()
+}
+
+/// Returns the pawns of `side` that have no friendly pawns on the adjacent files.
+pub(crate) fn isolated_pawns(board: &Board, side: PlayerSide) -> Bitboard {
+    let pawns = board.piece_bitboard(Piece::Pawn, side).as_number();
+    let mut isolated = 0;
+    let mut remaining = Bitboard::new(pawns);
+    while remaining.as_number() != 0 {
+        let sq = bitboard_helpers::next_bit(&mut remaining);
+        if pawns & adjacent_files_mask(sq % NumberOf::FILES) == 0 {
+            isolated |= 1 << sq;
+        }
+    }
+    Bitboard::new(isolated)
+}
+
+/// Returns the pawns of `side` that are backward.
+///
+/// A pawn is backward if no friendly pawn on an adjacent file is level with or behind it (so it
+/// cannot be supported by a pawn advance) and the square in front of it is attacked by an enemy
+/// pawn (so it cannot advance safely). Isolated pawns are not counted as backward.
+pub(crate) fn backward_pawns(board: &Board, side: PlayerSide) -> Bitboard {
+    let pawns = board.piece_bitboard(Piece::Pawn, side).as_number();
+    let enemy_pawns = board
+        .piece_bitboard(Piece::Pawn, PlayerSide::opposite(side))
+        .as_number();
+    let enemy_attacks = pawn_attacks(enemy_pawns, PlayerSide::opposite(side));
+
+    let mut backward = 0;
+    let mut remaining = Bitboard::new(pawns);
+    while remaining.as_number() != 0 {
+        let sq = bitboard_helpers::next_bit(&mut remaining);
+        let adjacent = pawns & adjacent_files_mask(sq % NumberOf::FILES);
+        if adjacent == 0 {
+            continue;
+        }
+
+        let supporters = adjacent & ranks_at_or_behind(sq / NumberOf::FILES, side);
+        let stop_square = match side {
+            PlayerSide::White => sq + NumberOf::FILES,
+            _ => sq.wrapping_sub(NumberOf::FILES),
+        };
+        if supporters == 0
+            && stop_square < NumberOf::SQUARES
+            && enemy_attacks & (1 << stop_square) != 0
+        {
+            backward |= 1 << sq;
+        }
+    }
+    Bitboard::new(backward)
+}
+
+/// Returns the passed pawns of `side`, i.e. pawns with no enemy pawns in front of them on the same
+/// or adjacent files.
+pub(crate) fn passed_pawns(board: &Board, side: PlayerSide) -> Bitboard {
+    let pawns = board.piece_bitboard(Piece::Pawn, side).as_number();
+    let enemy_pawns = board
+        .piece_bitboard(Piece::Pawn, PlayerSide::opposite(side))
+        .as_number();
+
+    let mut passed = 0;
+    let mut remaining = Bitboard::new(pawns);
+    while remaining.as_number() != 0 {
+        let sq = bitboard_helpers::next_bit(&mut remaining);
+        let file = sq % NumberOf::FILES;
+        let front_span = (file_mask(file) | adjacent_files_mask(file))
+            & !ranks_at_or_behind(sq / NumberOf::FILES, side);
+        if enemy_pawns & front_span == 0 {
+            passed |= 1 << sq;
+        }
+    }
+    Bitboard::new(passed)
+}
+
+/// Returns the passed pawns of `side` that are defended by a friendly pawn.
+pub(crate) fn protected_passed_pawns(board: &Board, side: PlayerSide) -> Bitboard {
+    let pawns = board.piece_bitboard(Piece::Pawn, side).as_number();
+    Bitboard::new(passed_pawns(board, side).as_number() & pawn_attacks(pawns, side))
+}
+
+/// Returns the passed pawns of `side` that have another passed pawn on an adjacent file.
+pub(crate) fn connected_passed_pawns(board: &Board, side: PlayerSide) -> Bitboard {
+    let passed = passed_pawns(board, side).as_number();
+    let mut connected = 0;
+    let mut remaining = Bitboard::new(passed);
+    while remaining.as_number() != 0 {
+        let sq = bitboard_helpers::next_bit(&mut remaining);
+        if passed & adjacent_files_mask(sq % NumberOf::FILES) != 0 {
+            connected |= 1 << sq;
+        }
+    }
+    Bitboard::new(connected)
+}
+
+/// Returns the rank of the given square relative to `side`, so 0 is the side's back rank.
+pub(crate) fn relative_rank(square: usize, side: PlayerSide) -> u8 {
+    let rank = (square / NumberOf::FILES) as u8;
+    match side {
+        PlayerSide::White => rank,
+        _ => (NumberOf::RANKS - 1) as u8 - rank,
+    }
+}
+
+/// Evaluates the pawn structure of `side`, returning the sum of all pawn structure terms.
+///
+/// # Arguments
+///
+/// - `board` - The board to evaluate.
+/// - `side` - The side whose pawns are evaluated.
+/// - `values` - The evaluation values to use.
+pub(crate) fn evaluate<Values: EvalValues<ReturnScore = PhasedScore>>(
+    board: &Board,
+    side: PlayerSide,
+    values: &Values,
+) -> PhasedScore {
+    let mut score = S(0, 0);
+    for _ in 0..doubled_pawns(board, side) {
+        score += values.doubled_pawn();
+    }
+    for _ in 0..isolated_pawns(board, side).number_of_occupied_squares() {
+        score += values.isolated_pawn();
+    }
+    for _ in 0..backward_pawns(board, side).number_of_occupied_squares() {
+        score += values.backward_pawn();
+    }
+
+    let mut passed = passed_pawns(board, side);
+    while passed.as_number() != 0 {
+        let sq = bitboard_helpers::next_bit(&mut passed);
+        score += values.passed_pawn(relative_rank(sq, side));
+    }
+    for _ in 0..protected_passed_pawns(board, side).number_of_occupied_squares() {
+        score += values.protected_passed_pawn();
+    }
+    for _ in 0..connected_passed_pawns(board, side).number_of_occupied_squares() {
+        score += values.connected_passed_pawn();
+    }
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use chess::{board::Board, definitions::Squares, side::PlayerSide};
+
+    use super::{
+        backward_pawns, connected_passed_pawns, doubled_pawns, isolated_pawns, passed_pawns,
+        protected_passed_pawns,
+    };
+    use crate::{evaluation::ByteKnightEvaluation, traits::Eval};
+
+    #[test]
+    fn detects_pawn_weaknesses() {
+        // white has doubled c-pawns, black's c and e pawns are isolated
+        let board = Board::from_fen("4k3/8/8/2p5/4p3/2P1P3/2PP4/4K3 w - - 0 1").unwrap();
+        assert_eq!(doubled_pawns(&board, PlayerSide::White), 1);
+        assert_eq!(doubled_pawns(&board, PlayerSide::Black), 0);
+        assert_eq!(
+            isolated_pawns(&board, PlayerSide::Black).number_of_occupied_squares(),
+            2
+        );
+        assert_eq!(
+            isolated_pawns(&board, PlayerSide::White).number_of_occupied_squares(),
+            0
+        );
+
+        // the d6 pawn cannot be supported by the c5 pawn and d5 is attacked by the e4 pawn
+        let board = Board::from_fen("4k3/8/3p4/2p5/4P3/8/8/4K3 w - - 0 1").unwrap();
+        let backward = backward_pawns(&board, PlayerSide::Black);
+        assert_eq!(backward.number_of_occupied_squares(), 1);
+        assert!(backward.is_square_occupied(Squares::D6));
+        assert_eq!(
+            backward_pawns(&board, PlayerSide::White).number_of_occupied_squares(),
+            0
+        );
+    }
+
+    #[test]
+    fn weak_pawns_score_worse() {
+        let eval = ByteKnightEvaluation::default();
+
+        // same material, white pawns on c3/d2 versus doubled and isolated on c2/c3
+        let healthy = Board::from_fen("4k3/8/8/8/8/2P5/3P4/4K3 w - - 0 1").unwrap();
+        let doubled = Board::from_fen("4k3/8/8/8/8/2P5/2P5/4K3 w - - 0 1").unwrap();
+        assert!(eval.eval(&doubled) < eval.eval(&healthy));
+
+        // same material, isolated a and c pawns versus connected b and c pawns
+        let connected = Board::from_fen("4k3/8/8/8/8/8/1PP5/4K3 w - - 0 1").unwrap();
+        let isolated = Board::from_fen("4k3/8/8/8/8/8/P1P5/4K3 w - - 0 1").unwrap();
+        assert!(eval.eval(&isolated) < eval.eval(&connected));
+    }
+
+    #[test]
+    fn detects_passed_pawns() {
+        // white: d5 and e4 are connected passers and e4 protects d5. The a2 and b3 pawns stop each
+        // other from being passed, so black's only passer is h7.
+        let board = Board::from_fen("4k3/7p/8/3P4/4P3/1p6/P7/4K3 w - - 0 1").unwrap();
+        let passed = passed_pawns(&board, PlayerSide::White);
+        assert_eq!(passed.number_of_occupied_squares(), 2);
+        assert!(passed.is_square_occupied(Squares::D5));
+        assert!(passed.is_square_occupied(Squares::E4));
+
+        let protected = protected_passed_pawns(&board, PlayerSide::White);
+        assert_eq!(protected.number_of_occupied_squares(), 1);
+        assert!(protected.is_square_occupied(Squares::D5));
+        assert_eq!(
+            connected_passed_pawns(&board, PlayerSide::White).number_of_occupied_squares(),
+            2
+        );
+
+        let black_passed = passed_pawns(&board, PlayerSide::Black);
+        assert_eq!(black_passed.number_of_occupied_squares(), 1);
+        assert!(black_passed.is_square_occupied(Squares::H7));
+        assert_eq!(
+            connected_passed_pawns(&board, PlayerSide::Black).number_of_occupied_squares(),
+            0
+        );
+    }
+
+    #[test]
+    fn connected_passers_score_better() {
+        let eval = ByteKnightEvaluation::default();
+
+        // two passers on adjacent files versus two passers far apart, same ranks
+        let connected = Board::from_fen("4k3/8/8/3PP3/8/8/8/4K3 w - - 0 1").unwrap();
+        let apart = Board::from_fen("4k3/8/8/1P4P1/8/8/8/4K3 w - - 0 1").unwrap();
+        assert!(eval.eval(&connected) > eval.eval(&apart));
+    }
+}
@@ -1,9 +1,19 @@
-use std::fmt::Display;
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign},
+};
 
-use crate::score::{LargeScoreType, ScoreType};
+use crate::score::{LargeScoreType, Score, ScoreType};
 
 /// Represents a phased score in centipawns meaning that the score holds 2 values. One for midgame and one for endgame.
 ///
+/// Evaluation terms that depend on the stage of the game are phased scores and are summed as
+/// phased scores. The sum is collapsed to a single [`Score`] exactly once, with
+/// [`PhasedScore::collapse`], at the end of the evaluation. Collapsing every term on its own would
+/// be slower and each collapse truncates, so the error would grow with the number of terms. Scalar
+/// adjustments that don't depend on the phase, such as scaling drawish endings, are applied to the
+/// collapsed score.
+///
 /// The mg score is stored in the upper 16 bits and the eg score in the lower 16 bits.
 /// MSB mmmmmmmm mmmmmmmm eeeeeeee eeeeeeee LSB
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
@@ -13,6 +23,8 @@ pub struct PhasedScore {
 }
 
 pub type PhaseType = i32;
+/// The game phase of the starting position, a phase of 0 is a pure endgame.
+pub const MAX_PHASE: PhaseType = 24;
 const BITS: usize = ScoreType::BITS as usize;
 
 impl PhasedScore {
@@ -33,12 +45,29 @@ impl PhasedScore {
         (self.value & 0xFFFF) as ScoreType
     }
 
+    /// Adds two phased scores, saturating each half at the numeric bounds of [`ScoreType`]
+    /// instead of letting an overflowing half corrupt the other.
+    pub fn saturating_add(&self, other: PhasedScore) -> PhasedScore {
+        PhasedScore::new(
+            self.mg().saturating_add(other.mg()),
+            self.eg().saturating_add(other.eg()),
+        )
+    }
+
+    /// Interpolates between the mg and eg scores based on the given phase.
+    /// The phase is clamped to `[0, max_phase]`.
     pub fn taper(&self, phase: PhaseType, max_phase: PhaseType) -> ScoreType {
-        let mg_phase = phase.min(max_phase);
+        let mg_phase = phase.clamp(0, max_phase);
         let eg_phase = max_phase - mg_phase;
         ((self.mg() as PhaseType * mg_phase + self.eg() as PhaseType * eg_phase) / max_phase)
             as ScoreType
     }
+
+    /// Collapses the phased score to a single [`Score`] for the given game phase, see
+    /// [`PhasedScore::taper`]. The phase is clamped to `[0, MAX_PHASE]`.
+    pub fn collapse(&self, phase: PhaseType) -> Score {
+        Score::new(self.taper(phase, MAX_PHASE))
+    }
 }
 
 const fn phase_score(mg: ScoreType, eg: ScoreType) -> PhasedScore {
@@ -50,6 +79,22 @@ pub const fn S(mg: ScoreType, eg: ScoreType) -> PhasedScore {
     phase_score(mg, eg)
 }
 
+impl Add for PhasedScore {
+    type Output = PhasedScore;
+
+    fn add(self, rhs: PhasedScore) -> Self::Output {
+        PhasedScore {
+            value: self.value + rhs.value,
+        }
+    }
+}
+
+impl AddAssign for PhasedScore {
+    fn add_assign(&mut self, rhs: PhasedScore) {
+        self.value += rhs.value;
+    }
+}
+
 impl Display for PhasedScore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "mg: {}, eg: {}", self.mg(), self.eg())
@@ -58,10 +103,12 @@ impl Display for PhasedScore {
 
 #[cfg(test)]
 mod tests {
+    use super::{PhasedScore, MAX_PHASE};
+    use crate::hce_values::PSQTS;
+    use crate::score::{Score, ScoreType};
+
     #[test]
     fn phased_score() {
-        use super::PhasedScore;
-
         let ps = PhasedScore::new(100, 50);
         assert_eq!(ps.mg(), 100);
         assert_eq!(ps.eg(), 50);
@@ -82,4 +129,76 @@ mod tests {
         assert_eq!(ps.eg(), -26);
         assert_eq!(ps.taper(phase, 24), 56);
     }
+
+    #[test]
+    fn addition_does_not_carry_between_halves() {
+        // every pair of PSQT values must add without the eg half leaking into the mg half
+        let all_values = PSQTS.iter().flatten().collect::<Vec<_>>();
+        for &a in &all_values {
+            for &b in &all_values {
+                let sum = *a + *b;
+                assert_eq!(sum.mg(), a.mg() + b.mg(), "{a} + {b}");
+                assert_eq!(sum.eg(), a.eg() + b.eg(), "{a} + {b}");
+            }
+        }
+
+        // accumulate the largest and smallest possible PSQT sums for a full set of pieces
+        let counts = [1, 1, 2, 2, 2, 8];
+        let mut max_sum = PhasedScore::default();
+        let mut min_sum = PhasedScore::default();
+        let (mut max_mg, mut max_eg, mut min_mg, mut min_eg) = (0, 0, 0, 0);
+        for (table, count) in PSQTS.iter().zip(counts) {
+            let max_entry = table.iter().max_by_key(|s| s.mg()).unwrap();
+            let min_entry = table.iter().min_by_key(|s| s.eg()).unwrap();
+            for _ in 0..count {
+                max_sum += *max_entry;
+                min_sum += *min_entry;
+                max_mg += max_entry.mg();
+                max_eg += max_entry.eg();
+                min_mg += min_entry.mg();
+                min_eg += min_entry.eg();
+            }
+        }
+        assert_eq!(max_sum.mg(), max_mg);
+        assert_eq!(max_sum.eg(), max_eg);
+        assert_eq!(min_sum.mg(), min_mg);
+        assert_eq!(min_sum.eg(), min_eg);
+
+        // negative eg values borrow from the mg half, which must be undone on extraction
+        let sum = PhasedScore::new(-1, -1) + PhasedScore::new(1, -1);
+        assert_eq!(sum.mg(), 0);
+        assert_eq!(sum.eg(), -2);
+    }
+
+    #[test]
+    fn saturating_add() {
+        let a = PhasedScore::new(ScoreType::MAX - 10, ScoreType::MIN + 10);
+        let b = PhasedScore::new(100, -100);
+        let sum = a.saturating_add(b);
+        assert_eq!(sum.mg(), ScoreType::MAX);
+        assert_eq!(sum.eg(), ScoreType::MIN);
+
+        let sum = PhasedScore::new(10, -20).saturating_add(PhasedScore::new(-5, 30));
+        assert_eq!(sum.mg(), 5);
+        assert_eq!(sum.eg(), 10);
+    }
+
+    #[test]
+    fn collapse_tapers_over_the_full_phase() {
+        let ps = PhasedScore::new(100, -50);
+        assert_eq!(ps.collapse(MAX_PHASE), Score::new(100));
+        assert_eq!(ps.collapse(0), Score::new(-50));
+        assert_eq!(ps.collapse(MAX_PHASE + 10), Score::new(100));
+        assert_eq!(ps.collapse(12), Score::new(ps.taper(12, MAX_PHASE)));
+    }
+
+    #[test]
+    fn taper_clamps_phase() {
+        let ps = PhasedScore::new(100, -50);
+        assert_eq!(ps.taper(24, 24), 100);
+        assert_eq!(ps.taper(0, 24), -50);
+        assert_eq!(ps.taper(48, 24), 100);
+        assert_eq!(ps.taper(-12, 24), -50);
+        assert_eq!(ps.taper(12, 24), 25);
+    }
 }
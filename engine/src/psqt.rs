@@ -12,7 +12,7 @@
  *
  */
 
-use chess::{bitboard_helpers, board::Board, pieces::PIECE_NAMES, side::Side, square};
+use chess::{board::Board, pieces::PIECE_NAMES, side::PlayerSide, square};
 
 use crate::score::{Score, ScoreType};
 
@@ -238,21 +238,20 @@ impl Psqt {
 
         let mut occupancy = board.all_pieces();
         // loop through occupied squares
-        while occupancy.as_number() > 0 {
-            let sq = bitboard_helpers::next_bit(&mut occupancy);
-            let maybe_piece = board.piece_on_square(sq as u8);
+        while let Some(sq) = occupancy.pop_lsb() {
+            let maybe_piece = board.piece_on_square(sq);
             if let Some((piece, side)) = maybe_piece {
                 let pc_idx = piece as usize * 2 + side as usize;
 
-                mg[side as usize] += self.mg_table[pc_idx][sq] as i32;
-                eg[side as usize] += self.eg_table[pc_idx][sq] as i32;
+                mg[side as usize] += self.mg_table[pc_idx][sq as usize] as i32;
+                eg[side as usize] += self.eg_table[pc_idx][sq as usize] as i32;
 
                 game_phase += GAMEPHASE_INC[piece as usize] as i32;
             }
         }
 
-        let mg_score = mg[side_to_move as usize] - mg[Side::opposite(side_to_move) as usize];
-        let eg_score = eg[side_to_move as usize] - eg[Side::opposite(side_to_move) as usize];
+        let mg_score = mg[side_to_move as usize] - mg[PlayerSide::opposite(side_to_move) as usize];
+        let eg_score = eg[side_to_move as usize] - eg[PlayerSide::opposite(side_to_move) as usize];
         println!("psqt got mg {} eg {}", mg_score, eg_score);
         let mg_phase = game_phase.min(24);
         let eg_phase = 24 - mg_phase;
@@ -33,6 +33,9 @@ impl Score {
     /// The minimum mate score. This is the maximum score minus the maximum depth.
     pub const MINIMUM_MATE: Score = Score(Score::MATE.0 - MAX_DEPTH as ScoreType);
     pub const INF: Score = Score(ScoreType::MAX as ScoreType);
+    /// The largest absolute static evaluation. Evaluations are clamped to this so they stay
+    /// readable and can never be mistaken for mate scores.
+    pub const MAX_EVAL: Score = Score(30_000);
 
     // Max/min score for history heuristic
     // Must be lower then the minimum score for captures in MVV_LVA
@@ -42,6 +45,16 @@ impl Score {
         Score(score)
     }
 
+    /// Returns the score for the side to move mating in `ply` plies from the root.
+    pub fn mate_in(ply: u8) -> Score {
+        Score(Score::MATE.0 - ply as ScoreType)
+    }
+
+    /// Returns the score for the side to move getting mated in `ply` plies from the root.
+    pub fn mated_in(ply: u8) -> Score {
+        Score(-Score::MATE.0 + ply as ScoreType)
+    }
+
     pub fn clamp(&self, min: ScoreType, max: ScoreType) -> Score {
         Score(self.0.clamp(min, max))
     }
@@ -55,21 +68,54 @@ impl Score {
     pub fn pow(&self, exp: u32) -> Score {
         Score(self.0.pow(exp))
     }
+
+    /// Returns the number of plies until mate if this is a mate score, the inverse of
+    /// [`Score::mate_in`] and [`Score::mated_in`]. The value is positive if the side to move is
+    /// mating and negative if it is getting mated.
+    pub fn mate_distance(&self) -> Option<LargeScoreType> {
+        if !self.is_mate() {
+            return None;
+        }
+
+        let plies = Score::MATE.0 as LargeScoreType - (self.0 as LargeScoreType).abs();
+        Some(if self.0 > 0 { plies } else { -plies })
+    }
+
+    /// Returns the number of moves (not plies) until mate if this is a mate score. The value is
+    /// positive if the side to move is mating and negative if it is getting mated.
+    pub fn moves_to_mate(&self) -> Option<LargeScoreType> {
+        self.mate_distance().map(|plies| {
+            if plies > 0 {
+                (plies + 1) / 2
+            } else {
+                plies / 2
+            }
+        })
+    }
+
+    /// Formats the score as a UCI score token, either `cp <centipawns>` or `mate <moves>`.
+    /// Scores are relative to the side to move, so a negative mate means the side to move is
+    /// getting mated.
+    pub fn to_uci_string(&self) -> String {
+        match self.moves_to_mate() {
+            Some(moves) => format!("mate {}", moves),
+            None => format!("cp {}", self.0),
+        }
+    }
 }
 
 impl From<Score> for UciScore {
     fn from(value: Score) -> Self {
-        UciScore::cp(value.0.into())
+        match value.moves_to_mate() {
+            Some(moves) => UciScore::mate(moves),
+            None => UciScore::cp(value.0.into()),
+        }
     }
 }
 
 impl Display for Score {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        if self.0.abs() >= Score::MATE.0.abs() {
-            write!(f, "mate {}", (self.0 - Score::MATE.0) / 2)
-        } else {
-            write!(f, "cp {}", self.0)
-        }
+        write!(f, "{}", self.to_uci_string())
     }
 }
 
@@ -193,3 +239,45 @@ impl Shl<u32> for Score {
         Score(self.0 << rhs)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use uci_parser::UciScore;
+
+    use super::Score;
+
+    #[test]
+    fn uci_string_renders_cp_and_mate() {
+        assert_eq!(Score::new(35).to_uci_string(), "cp 35");
+        assert_eq!(Score::new(-120).to_uci_string(), "cp -120");
+
+        // mate in 3 moves is 5 plies away for the side to move
+        let mating = Score::MATE - 5;
+        assert_eq!(mating.to_uci_string(), "mate 3");
+        assert_eq!(format!("{}", mating), "mate 3");
+        // getting mated in 3 moves is 6 plies away for the side to move
+        let mated = -Score::MATE + 6;
+        assert_eq!(mated.to_uci_string(), "mate -3");
+        assert_eq!(UciScore::from(mated), UciScore::mate(-3));
+        assert_eq!(UciScore::from(Score::new(35)), UciScore::cp(35));
+    }
+
+    #[test]
+    fn mate_constructors() {
+        assert!(Score::mate_in(1) > Score::mate_in(3));
+        assert!(Score::mated_in(1) < Score::mated_in(3));
+        for score in [Score::mate_in(1), Score::mate_in(3)] {
+            assert!(score.is_mate());
+            assert!(score > Score::MAX_EVAL);
+            assert!(-score < -Score::MAX_EVAL);
+        }
+        assert_eq!(Score::mated_in(4), -Score::mate_in(4));
+
+        assert_eq!(Score::mate_in(5).mate_distance(), Some(5));
+        assert_eq!(Score::mated_in(6).mate_distance(), Some(-6));
+        assert_eq!(Score::mate_in(5).moves_to_mate(), Some(3));
+        assert_eq!(Score::mated_in(6).moves_to_mate(), Some(-3));
+        assert_eq!(Score::MAX_EVAL.mate_distance(), None);
+        assert_eq!(Score::DRAW.moves_to_mate(), None);
+    }
+}
@@ -13,7 +13,9 @@
  */
 
 use std::{
+    cmp::Reverse,
     fmt::Display,
+    io::Write,
     sync::{
         atomic::{AtomicBool, Ordering},
         Arc,
@@ -23,26 +25,84 @@ use std::{
 
 use chess::{board::Board, move_generation::MoveGenerator, move_list::MoveList, moves::Move};
 use itertools::Itertools;
-use uci_parser::{UciInfo, UciResponse, UciSearchOptions};
+use uci_parser::{UciBound, UciInfo, UciResponse, UciScore, UciSearchOptions};
 
 use crate::{
     aspiration_window::AspirationWindow,
     defs::MAX_DEPTH,
     evaluation::ByteKnightEvaluation,
     history_table::HistoryTable,
+    info_throttle::InfoThrottle,
+    log_level::{log_info_string, LogLevel},
+    move_order::MoveOrder,
     score::{LargeScoreType, Score, ScoreType},
+    timer::Timer,
     traits::Eval,
     ttable::{self, TranspositionTableEntry},
+    tuneable::{DRAW_JITTER, QSEARCH_SEE_PRUNE_FIRST_PLY, QS_MAX_PLY, STOP_ON_MATE},
 };
 use ttable::TranspositionTable;
 
+/// Default time reserved for communication delay between the engine and the GUI, see
+/// [`SearchParameters::new`].
+pub const DEFAULT_MOVE_OVERHEAD: Duration = Duration::from_millis(30);
+/// Maximum move overhead accepted by the `Move Overhead` UCI option.
+pub const MAX_MOVE_OVERHEAD: Duration = Duration::from_millis(5000);
+/// Smallest time limit the time management will allocate, so a search always has some time.
+const MIN_TIMEOUT: Duration = Duration::from_millis(1);
+/// Number of moves the remaining time is spread over when the GUI does not send `movestogo`.
+const DEFAULT_MOVES_TO_GO: u32 = 20;
+
+/// Why a search stopped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SearchStopReason {
+    /// The maximum search depth was reached.
+    #[default]
+    DepthReached,
+    /// The time allocated for the search ran out.
+    TimeLimit,
+    /// The maximum number of nodes was searched.
+    NodeLimit,
+    /// The search was stopped externally, e.g. by the `stop` command.
+    Stopped,
+    /// There is only one legal move, so the search ended early.
+    OnlyMove,
+    /// A forced mate for the side to move was confirmed, so the search ended early.
+    MateFound,
+}
+
+impl Display for SearchStopReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchStopReason::DepthReached => write!(f, "depth reached"),
+            SearchStopReason::TimeLimit => write!(f, "time limit"),
+            SearchStopReason::NodeLimit => write!(f, "node limit"),
+            SearchStopReason::Stopped => write!(f, "stopped"),
+            SearchStopReason::OnlyMove => write!(f, "only move"),
+            SearchStopReason::MateFound => write!(f, "mate found"),
+        }
+    }
+}
+
 /// Result for a search.
 #[derive(Clone, Copy, Debug)]
 pub struct SearchResult {
+    /// The score of the last completed iteration, from the point of view of the side to move.
+    ///
+    /// [`SearchResult::default`] uses `-Score::INF` as a "no score yet" sentinel. A finished search
+    /// never returns it: if no iteration completed, the score falls back to the static evaluation
+    /// of the root, or the mate/draw score if there are no legal moves.
     pub score: Score,
+    /// The best move found, always a legal move if the position has one.
     pub best_move: Option<Move>,
+    /// The expected reply to the best move, the second move of the principal variation. `None` if
+    /// the principal variation is shorter than two moves.
+    pub ponder_move: Option<Move>,
     pub nodes: u64,
     pub depth: u8,
+    /// The maximum ply reached, including quiescence search.
+    pub seldepth: u8,
+    pub stop_reason: SearchStopReason,
 }
 
 impl Default for SearchResult {
@@ -50,8 +110,11 @@ impl Default for SearchResult {
         SearchResult {
             score: -Score::INF,
             best_move: None,
+            ponder_move: None,
             nodes: 0,
             depth: 1,
+            seldepth: 0,
+            stop_reason: SearchStopReason::default(),
         }
     }
 }
@@ -79,6 +142,20 @@ pub struct SearchParameters {
     pub soft_timeout: Duration,
     pub hard_timeout: Duration,
     pub max_nodes: u64,
+    pub log_level: LogLevel,
+    /// Jitter the score of repetitions and fifty move rule draws, see [`draw_jitter`].
+    pub draw_jitter: bool,
+    /// Search exactly to `max_depth`, even if there is only a single legal move.
+    pub fixed_depth: bool,
+    /// Search until told to stop (`go infinite` or `go ponder`). The search doesn't end early on
+    /// its own, e.g. when it found a mate.
+    pub infinite: bool,
+    /// The maximum number of plies quiescence search goes beyond the main search, see
+    /// [`QS_MAX_PLY`].
+    pub qs_max_ply: u8,
+    /// Prune losing captures in the first ply of quiescence search too, see
+    /// [`QSEARCH_SEE_PRUNE_FIRST_PLY`].
+    pub qs_see_prune_first_ply: bool,
 }
 
 impl Default for SearchParameters {
@@ -89,25 +166,52 @@ impl Default for SearchParameters {
             soft_timeout: Duration::MAX,
             hard_timeout: Duration::MAX,
             max_nodes: u64::MAX,
+            log_level: LogLevel::default(),
+            draw_jitter: DRAW_JITTER,
+            fixed_depth: false,
+            infinite: false,
+            qs_max_ply: QS_MAX_PLY,
+            qs_see_prune_first_ply: QSEARCH_SEE_PRUNE_FIRST_PLY,
         }
     }
 }
 
 impl SearchParameters {
     /// Creates a new set of search parameters from the UCI options and the current board.
-    pub fn new(uci_options: &UciSearchOptions, board: &Board) -> Self {
+    ///
+    /// # Arguments
+    ///
+    /// - `uci_options` - The options given to the `go` command.
+    /// - `board` - The current board, used to pick the clock of the side to move.
+    /// - `move_overhead` - Time subtracted from the allocated time to account for communication
+    ///   delay with the GUI. The time limits never drop below a small positive value.
+    pub fn new(uci_options: &UciSearchOptions, board: &Board, move_overhead: Duration) -> Self {
         let mut params = Self::default();
         if let Some(depth) = uci_options.depth {
-            params.max_depth = depth as u8;
+            // always search at least one ply so we have a move to play
+            params.max_depth = depth.clamp(1, MAX_DEPTH as u32) as u8;
+            params.fixed_depth = true;
         }
 
+        params.infinite = uci_options.infinite || uci_options.ponder;
+
         if let Some(nodes) = uci_options.nodes {
             params.max_nodes = nodes as u64;
         }
 
-        if let Some(time) = uci_options.movetime {
-            params.soft_timeout = time;
-            params.hard_timeout = time;
+        let with_overhead = |time: Duration| time.saturating_sub(move_overhead).max(MIN_TIMEOUT);
+
+        let no_time = uci_options.movetime.is_none()
+            && uci_options.wtime.is_none()
+            && uci_options.btime.is_none();
+        if no_time {
+            // without a clock (e.g. a plain `go depth`) the search only stops at the depth, the
+            // node limit or when told to stop
+            params.soft_timeout = Duration::MAX;
+            params.hard_timeout = Duration::MAX;
+        } else if let Some(time) = uci_options.movetime {
+            params.soft_timeout = with_overhead(time);
+            params.hard_timeout = with_overhead(time);
         } else {
             let (time, increment) = if board.side_to_move().is_white() {
                 (uci_options.wtime, uci_options.winc)
@@ -119,13 +223,23 @@ impl SearchParameters {
             if let Some(time) = time {
                 // TODO: How can we tune these params?
                 let inc = increment.unwrap_or(Duration::ZERO) / 2;
-                params.soft_timeout = time / 20 + inc;
-                params.hard_timeout = time / 5 + inc;
+                let moves_to_go = uci_options.movestogo.unwrap_or(DEFAULT_MOVES_TO_GO).max(1);
+                params.hard_timeout = with_overhead(time / 5 + inc);
+                params.soft_timeout =
+                    with_overhead(time / moves_to_go + inc).min(params.hard_timeout);
             }
         }
 
         params
     }
+
+    /// Returns a [`Timer`] that started at the start time, with the soft and hard timeouts as its
+    /// limits.
+    pub fn timer(&self) -> Timer {
+        Timer::new(self.start_time)
+            .with_soft_limit(self.soft_timeout)
+            .with_hard_limit(self.hard_timeout)
+    }
 }
 
 impl Display for SearchParameters {
@@ -138,14 +252,25 @@ impl Display for SearchParameters {
     }
 }
 
+/// Returns a small, deterministic offset in `-1..=2` centipawns for draw scores, based on the
+/// number of searched nodes. Slightly varying draw scores keep the search from treating every
+/// repetition as exactly equal, which avoids blindly steering into draws in equal positions.
+pub(crate) fn draw_jitter(nodes: u64) -> Score {
+    Score::new(2 - (nodes & 3) as ScoreType)
+}
+
 pub struct Search<'search_lifetime> {
     transposition_table: &'search_lifetime mut TranspositionTable,
     history_table: &'search_lifetime mut HistoryTable,
     move_gen: MoveGenerator,
     nodes: u64,
+    seldepth: u8,
+    completed_depth: u8,
     parameters: SearchParameters,
+    timer: Timer,
     eval: ByteKnightEvaluation,
     stop_flag: Option<Arc<AtomicBool>>,
+    info_throttle: InfoThrottle,
 }
 
 impl<'a> Search<'a> {
@@ -159,9 +284,13 @@ impl<'a> Search<'a> {
             history_table,
             move_gen: MoveGenerator::new(),
             nodes: 0,
+            seldepth: 0,
+            completed_depth: 0,
             parameters: parameters.clone(),
+            timer: parameters.timer(),
             eval: ByteKnightEvaluation::default(),
             stop_flag: None,
+            info_throttle: InfoThrottle::default(),
         }
     }
 
@@ -183,42 +312,171 @@ impl<'a> Search<'a> {
     ) -> SearchResult {
         self.stop_flag = stop_flag;
 
-        let info = UciInfo::default().string(format!("searching {}", self.parameters));
-        let message = UciResponse::info(info);
-        println!("{}", message);
+        log_info_string(
+            &mut std::io::stdout(),
+            self.parameters.log_level,
+            LogLevel::Debug,
+            format!("searching {}", self.parameters),
+        );
 
         let result = self.iterative_deepening(board);
-        // search ended, reset our node count
+        log_info_string(
+            &mut std::io::stdout(),
+            self.parameters.log_level,
+            LogLevel::Debug,
+            format!("search stopped: {}", result.stop_reason),
+        );
+        // search ended, reset our node count, selective depth and completed depth
         self.nodes = 0;
+        self.seldepth = 0;
+        self.completed_depth = 0;
         result
     }
 
+    /// Runs only the quiescence search on `board` with a full window, i.e. the static evaluation
+    /// after all captures have been resolved. This is used to find quiet positions for tuning.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The position to search.
+    ///
+    /// # Returns
+    ///
+    /// The quiescence score from the point of view of the side to move.
+    pub fn quiescence_score(&mut self, board: &mut Board) -> Score {
+        let score = self.quiescence(board, 0, 0, -Score::INF, Score::INF);
+        self.nodes = 0;
+        self.seldepth = 0;
+        score
+    }
+
+    /// Updates the selective depth if `ply` is the deepest ply reached so far.
+    fn update_seldepth(&mut self, ply: ScoreType) {
+        self.seldepth = self.seldepth.max(ply as u8);
+    }
+
     fn should_stop_searching(&self) -> bool {
-        self.parameters.start_time.elapsed() >= self.parameters.hard_timeout // hard timeout
-            || self.nodes >= self.parameters.max_nodes // node limit reached
-            || self.stop_flag.as_ref().is_some_and(|f| f.load(Ordering::Relaxed))
-        // stop flag set
-    }
-
-    fn send_info(
-        &self,
-        depth: u8,
-        nodes: u64,
-        score: Score,
-        nps: f32,
-        time: u64,
-        best_move: Option<Move>,
-    ) {
-        // create UciInfo and print it
+        self.hard_stop_reason().is_some()
+    }
+
+    /// Returns why the search has to stop immediately, if it does.
+    fn hard_stop_reason(&self) -> Option<SearchStopReason> {
+        if self
+            .stop_flag
+            .as_ref()
+            .is_some_and(|f| f.load(Ordering::Relaxed))
+        {
+            // stop flag set
+            Some(SearchStopReason::Stopped)
+        } else if self.nodes >= self.parameters.max_nodes {
+            // node limit reached
+            Some(SearchStopReason::NodeLimit)
+        } else if self.completed_depth > 0 && self.timer.is_hard_expired() {
+            // hard timeout, only once we have searched at least one depth so we always have a
+            // searched move to play
+            Some(SearchStopReason::TimeLimit)
+        } else {
+            None
+        }
+    }
+
+    /// Builds a UCI `info` line for the current state of the search.
+    ///
+    /// # Arguments
+    ///
+    /// - `depth` - The depth that was searched.
+    /// - `score` - The score of the root position, with a bound if it is not exact.
+    /// - `pv` - The principal variation.
+    fn info_line(&self, depth: u8, score: UciScore, pv: &[Move]) -> UciResponse<String> {
+        let elapsed = self.timer.elapsed();
         let info = UciInfo::new()
             .depth(depth)
-            .nodes(nodes)
+            .seldepth(self.seldepth)
+            .nodes(self.nodes)
             .score(score)
-            .nps(nps.trunc())
-            .time(time)
-            .pv(best_move.map(|m| m.to_long_algebraic()));
-        let message = UciResponse::info(info);
-        println!("{}", message);
+            .nps((self.nodes as f32 / elapsed.as_secs_f32()).trunc())
+            .time(elapsed.as_millis() as u64)
+            .pv(pv.iter().map(|m| m.to_long_algebraic()));
+        UciResponse::info(info)
+    }
+
+    /// Writes the UCI `info` line for a completed iteration. These are never rate limited.
+    ///
+    /// # Arguments
+    ///
+    /// - `writer` - Where the info line is written, usually stdout.
+    /// - `depth` - The depth that was searched.
+    /// - `score` - The score of the root position.
+    /// - `pv` - The principal variation.
+    fn send_info(&mut self, writer: &mut impl Write, depth: u8, score: UciScore, pv: &[Move]) {
+        let line = self.info_line(depth, score, pv);
+        self.info_throttle
+            .iteration_end(writer, line, Instant::now());
+    }
+
+    /// Searches the root position to the given depth inside of the aspiration `window`. Whenever
+    /// the score falls outside of the window, the window is widened and the position searched
+    /// again. Every re-search is logged at the debug level to help diagnose evaluation instability,
+    /// and the bound found by the failed search is reported as a `lowerbound` or `upperbound` score.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The root position.
+    /// - `depth` - The depth to search to.
+    /// - `window` - The initial aspiration window.
+    /// - `writer` - Where re-search diagnostics and info lines are written, usually stdout.
+    ///
+    /// # Returns
+    ///
+    /// The score of the root position, or `None` if the search had to stop before a score inside
+    /// the window was found.
+    fn aspiration_search(
+        &mut self,
+        board: &mut Board,
+        depth: ScoreType,
+        mut window: AspirationWindow,
+        writer: &mut impl Write,
+    ) -> Option<Score> {
+        loop {
+            let score = self.negamax(board, depth, 0, window.alpha(), window.beta());
+
+            let previous = window;
+            let (failure, bound) = if window.failed_low(score) {
+                // fail low, widen the window
+                window.widen_down(score, depth);
+                ("fail low", UciBound::Upperbound)
+            } else if window.failed_high(score) {
+                // fail high, widen the window
+                window.widen_up(score, depth);
+                ("fail high", UciBound::Lowerbound)
+            } else {
+                // we have a valid score
+                return Some(score);
+            };
+
+            log_info_string(
+                writer,
+                self.parameters.log_level,
+                LogLevel::Debug,
+                format!(
+                    "aspiration {} at depth {} score {}, re-searching window {} -> {}",
+                    failure, depth, score.0, previous, window
+                ),
+            );
+
+            // check stop conditions
+            if self.should_stop_searching() {
+                return None;
+            }
+
+            // the score is only a bound, but still lets the GUI follow the search. Re-searches can
+            // be very fast at low depths, so these updates are rate limited
+            let pv = self
+                .transposition_table
+                .extract_pv(board, &self.move_gen, depth as usize);
+            let line = self.info_line(depth as u8, UciScore::from(score).with_bound(bound), &pv);
+            self.info_throttle.update(writer, line, Instant::now());
+        }
     }
 
     fn iterative_deepening(&mut self, board: &mut Board) -> SearchResult {
@@ -227,70 +485,142 @@ impl<'a> Search<'a> {
         let mut move_list = MoveList::new();
 
         self.move_gen.generate_legal_moves(board, &mut move_list);
+        // verify the root moves in debug builds, this is too slow to do at every node
+        #[cfg(debug_assertions)]
+        self.move_gen
+            .verify_legal_moves(board, move_list.as_slice())
+            .unwrap();
         if !move_list.is_empty() {
             best_result.best_move = Some(*move_list.at(0).unwrap())
         }
 
-        'deepening: while self.parameters.start_time.elapsed() <= self.parameters.soft_timeout
-            && best_result.depth <= self.parameters.max_depth
+        // if the root position is already drawn (repetition, fifty move rule or insufficient material)
+        // we still search for a legal best move, but always report a draw so the GUI can adjudicate
+        let root_is_draw = !move_list.is_empty() && board.is_draw();
+
+        // with a single legal move there is nothing to decide, so only search depth 1 to get a
+        // score and an info line and play the move right away. An infinite search has to keep
+        // going until it is stopped.
+        let only_move =
+            move_list.len() == 1 && !self.parameters.fixed_depth && !self.parameters.infinite;
+        let max_depth = if only_move {
+            1
+        } else {
+            self.parameters.max_depth
+        };
+
+        let mut mate_found = false;
+        'deepening: while (self.completed_depth == 0 || !self.timer.is_soft_expired())
+            && best_result.depth <= max_depth
         {
             // create an aspiration window around the best result so far
-            let mut aspiration_window =
+            let aspiration_window =
                 AspirationWindow::around(best_result.score, best_result.depth as ScoreType);
 
-            let mut score: Score;
-            'aspiration_window: loop {
-                // search the tree, starting at the current depth (starts at 1)
-                score = self.negamax(
-                    board,
-                    best_result.depth as ScoreType,
-                    0,
-                    aspiration_window.alpha(),
-                    aspiration_window.beta(),
-                );
-
-                if aspiration_window.failed_low(score) {
-                    // fail low, widen the window
-                    aspiration_window.widen_down(score, best_result.depth as ScoreType);
-                } else if aspiration_window.failed_high(score) {
-                    // fail high, widen the window
-                    aspiration_window.widen_up(score, best_result.depth as ScoreType);
-                } else {
-                    // we have a valid score, break the loop
-                    break 'aspiration_window;
-                }
+            // search the tree, starting at the current depth (starts at 1)
+            let Some(score) = self.aspiration_search(
+                board,
+                best_result.depth as ScoreType,
+                aspiration_window,
+                &mut std::io::stdout(),
+            ) else {
+                // we have to stop searching now, use the best result we have
+                // no score update, but let the GUI see the last bound we found
+                self.info_throttle
+                    .flush(&mut std::io::stdout(), Instant::now());
+                break 'deepening;
+            };
 
-                // check stop conditions
-                if self.should_stop_searching() {
-                    // we have to stop searching now, use the best result we have
-                    // no score update
-                    break 'deepening;
-                }
+            // an aborted iteration only searched part of the tree, so keep the result of the last
+            // completed one
+            if self.should_stop_searching() {
+                break 'deepening;
             }
 
             // update the best result
+            let previous_score = best_result.score;
             best_result.score = score;
-            best_result.best_move = self
+            if let Some(mv) = self
                 .transposition_table
                 .get_entry(board.zobrist_hash())
-                .map(|e| e.board_move);
+                .map(|e| e.board_move)
+                .filter(|mv| move_list.contains(mv))
+            {
+                best_result.best_move = Some(mv);
+            }
+
+            // reconstruct the principal variation from the transposition table
+            let pv = self.transposition_table.extract_pv(
+                board,
+                &self.move_gen,
+                best_result.depth as usize,
+            );
+            // the PV moves are already checked to be legal, the reply only makes sense if the PV
+            // starts with our best move
+            best_result.ponder_move = pv
+                .get(1)
+                .copied()
+                .filter(|_| pv.first() == best_result.best_move.as_ref());
 
             // send UCI info
             self.send_info(
+                &mut std::io::stdout(),
                 best_result.depth,
-                self.nodes,
-                best_result.score,
-                (self.nodes as f32 / self.parameters.start_time.elapsed().as_secs_f32()).trunc(),
-                self.parameters.start_time.elapsed().as_millis() as u64,
-                best_result.best_move,
+                UciScore::from(if root_is_draw {
+                    Score::DRAW
+                } else {
+                    best_result.score
+                }),
+                &pv,
             );
 
             // increment depth for next iteration
+            self.completed_depth = best_result.depth;
             best_result.depth += 1;
+
+            // the same mate twice in a row, found by an iteration deep enough to see all of it, is
+            // confirmed. Deeper iterations would only take longer to report it again.
+            mate_found = STOP_ON_MATE
+                && !self.parameters.fixed_depth
+                && !self.parameters.infinite
+                && score == previous_score
+                && score
+                    .mate_distance()
+                    .is_some_and(|plies| plies > 0 && plies <= self.completed_depth as i32);
+            if mate_found {
+                break 'deepening;
+            }
         }
 
-        // update total nodes for the current search
+        // the search was aborted (or not allowed to run) before the first iteration completed, so
+        // there is no searched score yet. Fall back to something reasonable instead of -INF.
+        if self.completed_depth == 0 {
+            best_result.score = if !move_list.is_empty() {
+                self.eval.eval(board)
+            } else if self.move_gen.in_check_info(board).0 {
+                Score::mated_in(0)
+            } else {
+                Score::DRAW
+            };
+        }
+
+        // update total nodes and selective depth for the current search
         best_result.nodes = self.nodes;
+        best_result.seldepth = self.seldepth;
+        best_result.stop_reason = if let Some(reason) = self.hard_stop_reason() {
+            reason
+        } else if mate_found {
+            SearchStopReason::MateFound
+        } else if self.timer.is_soft_expired() {
+            SearchStopReason::TimeLimit
+        } else if only_move {
+            SearchStopReason::OnlyMove
+        } else {
+            SearchStopReason::DepthReached
+        };
+        if root_is_draw {
+            best_result.score = Score::DRAW;
+        }
 
         // return our best result so far
         best_result
@@ -306,14 +636,46 @@ impl<'a> Search<'a> {
     ) -> Score {
         // increment node count
         self.nodes += 1;
+        self.update_seldepth(ply);
         let alpha_original = alpha;
         let mut alpha_use = alpha;
         let mut beta_use = beta;
         let not_root = ply > 0;
         let zobrist = board.zobrist_hash();
 
+        // repeating any position since the root is scored as a draw, since the side that repeated
+        // can force it again. This is how forced repetitions like perpetual checks are recognized.
+        if not_root && (board.is_draw() || board.is_repetition_within(ply as usize)) {
+            return if self.parameters.draw_jitter {
+                Score::DRAW + draw_jitter(self.nodes)
+            } else {
+                Score::DRAW
+            };
+        }
+
+        // nothing in the search is indexed by the ply, but mate scores and the selective depth only
+        // have room for MAX_DEPTH plies. Without extensions the depth limit already keeps the
+        // search shallower, this guards against lines that would be extended beyond it.
+        if ply >= MAX_DEPTH as ScoreType - 1 {
+            return self.eval.eval(board);
+        }
+
         if depth == 0 {
-            return self.quiescence(board, alpha, beta);
+            return self.quiescence(board, ply, 0, alpha, beta);
+        }
+
+        // get all legal moves
+        let mut move_list = MoveList::new();
+        self.move_gen.generate_legal_moves(board, &mut move_list);
+        let (in_check, _) = self.move_gen.in_check_info(board);
+
+        // do we have moves?
+        if move_list.is_empty() {
+            return if in_check {
+                Score::mated_in(ply as u8)
+            } else {
+                Score::DRAW
+            };
         }
 
         let tt_entry = self.transposition_table.get_entry(board.zobrist_hash());
@@ -324,7 +686,12 @@ impl<'a> Search<'a> {
                 // depth must be greater or equal to the current depth and the board
                 // must be the same position. Without these checks, we could be looking up the wrong entry
                 // due to collisions since we use a modulo as the hash function
-                if tt_entry.depth as ScoreType >= depth && tt_entry.zobrist == zobrist {
+                // a stored move that isn't legal here means the entry is from another position
+                // that collided with this one, so it can't be trusted
+                if tt_entry.depth as ScoreType >= depth
+                    && tt_entry.zobrist == zobrist
+                    && (tt_entry.board_move.is_null() || move_list.contains(&tt_entry.board_move))
+                {
                     match tt_entry.flag {
                         ttable::EntryFlag::Exact => {
                             return tt_entry.score;
@@ -345,26 +712,16 @@ impl<'a> Search<'a> {
             }
         }
 
-        // get all legal moves
-        let mut move_list = MoveList::new();
-        self.move_gen.generate_legal_moves(board, &mut move_list);
-
-        // do we have moves?
-        if move_list.is_empty() {
-            return if board.is_in_check(&self.move_gen) {
-                -Score::MATE + ply
-            } else {
-                Score::DRAW
-            };
-        }
-
-        // sort moves by MVV/LVA
+        // sort moves by their ordering score, best first. Moves with the same ordering score are
+        // searched from the lowest from/to square up, so the order (and with it the move picked
+        // among equally scored moves below) doesn't depend on the move generator's internal order
+        let tt_move = tt_entry.map_or(Move::NULL, |tt| tt.board_move);
+        let move_order = MoveOrder::new(board, &self.move_gen, tt_move, self.history_table);
         let sorted_moves = move_list.iter().sorted_by_cached_key(|mv| {
-            ByteKnightEvaluation::score_move_for_ordering(
-                board.side_to_move(),
-                mv,
-                &tt_entry,
-                self.history_table,
+            (
+                Reverse(move_order.score(board, &self.move_gen, mv)),
+                mv.from(),
+                mv.to(),
             )
         });
 
@@ -374,7 +731,7 @@ impl<'a> Search<'a> {
 
         // really "bad" initial score
         let mut best_score = -Score::INF;
-        let mut best_move = None;
+        let mut best_move = Move::NULL;
 
         // loop through all moves
         // TODO(PT): Not a fan of this clone() call, but we needed it (for now) for the history malus update later on.
@@ -401,32 +758,31 @@ impl<'a> Search<'a> {
             // undo the move
             board.unmake_move().unwrap();
 
-            // check the results
+            // check the results. Only a strictly better score replaces the best move, so among moves
+            // with the same score the one searched first wins. At the root this is the move picked.
             if score > best_score {
                 // we improved, so update the score and best move
                 best_score = score;
-                best_move = Some(*mv);
+                best_move = *mv;
 
                 // update alpha
                 alpha_use = alpha_use.max(best_score);
                 if alpha_use >= beta_use {
                     // update history table for quiets
-                    if mv.is_quiet() {
+                    if board.is_quiet(mv) {
                         // calculate history bonus
                         let bonus = 300 * depth - 250;
                         self.history_table.update(
                             board.side_to_move(),
-                            mv.piece(),
-                            mv.to(),
+                            mv,
                             bonus as LargeScoreType,
                         );
 
                         // apply a penalty to all quiets searched so far
-                        for mv in sorted_moves.take(i).filter(|mv| mv.is_quiet()) {
+                        for mv in sorted_moves.take(i).filter(|mv| board.is_quiet(mv)) {
                             self.history_table.update(
                                 board.side_to_move(),
-                                mv.piece(),
-                                mv.to(),
+                                mv,
                                 -bonus as LargeScoreType,
                             );
                         }
@@ -456,7 +812,7 @@ impl<'a> Search<'a> {
                 depth as u8,
                 best_score,
                 flag,
-                best_move.unwrap(),
+                best_move,
             ));
 
         best_score
@@ -465,10 +821,16 @@ impl<'a> Search<'a> {
     /// Implements [quiescence search](https://www.chessprogramming.org/Quiescence_Search).
     /// We use this to avoid the horizon effect. The idea is to evaluate quiet moves where there are no tactical moves to make.
     ///
+    /// Captures that lose material according to static exchange evaluation (see
+    /// [`MoveScore::is_bad_capture`](crate::move_order::MoveScore::is_bad_capture)) are skipped,
+    /// unless the side to move is in check or this is the first ply and
+    /// [`SearchParameters::qs_see_prune_first_ply`] is off.
+    ///
     /// # Arguments
     ///
     /// - `board` - The current board state.
     /// - `ply` - The current ply.
+    /// - `qs_ply` - The number of plies since quiescence search started.
     /// - `alpha` - The current alpha value.
     /// - `beta` - The current beta value.
     ///
@@ -476,43 +838,75 @@ impl<'a> Search<'a> {
     ///
     /// The score of the position.
     ///
-    fn quiescence(&mut self, board: &mut Board, alpha: Score, beta: Score) -> Score {
+    fn quiescence(
+        &mut self,
+        board: &mut Board,
+        ply: ScoreType,
+        qs_ply: u8,
+        alpha: Score,
+        beta: Score,
+    ) -> Score {
+        self.update_seldepth(ply);
+
+        // checkmate has to be found before standing pat, otherwise mates at the horizon are
+        // scored by the evaluation
+        let (in_check, _) = self.move_gen.in_check_info(board);
+        let mut move_list = MoveList::new();
+        if in_check {
+            self.move_gen.generate_legal_moves(board, &mut move_list);
+            if move_list.is_empty() {
+                return Score::mated_in(ply as u8);
+            }
+        }
+
         let standing_eval = self.eval.eval(board);
         if standing_eval >= beta {
             return beta;
         }
+
+        // long capture chains can blow up the search, so stand pat once the quiescence or
+        // selective depth limit is reached. This also applies in check: mates were already
+        // detected above, and the evasions are only searched if we have plies left.
+        if qs_ply >= self.parameters.qs_max_ply || ply >= MAX_DEPTH as ScoreType - 1 {
+            return standing_eval;
+        }
         let mut alpha_use = alpha.max(standing_eval);
 
-        let mut move_list = MoveList::new();
-        self.move_gen.generate_legal_moves(board, &mut move_list);
+        if !in_check {
+            self.move_gen.generate_legal_moves(board, &mut move_list);
+        }
 
         // we only want captures here
         let captures = move_list
             .iter()
-            .filter(|mv: &&Move| mv.captured_piece().is_some())
+            .filter(|mv: &&Move| board.is_capture(mv))
             .collect_vec();
 
-        // no captures
+        // no captures, stand pat. This is not a stalemate since quiet moves aren't searched here
         if captures.is_empty() {
             return standing_eval;
         }
 
-        let sorted_moves = captures.into_iter().sorted_by_cached_key(|mv| {
-            ByteKnightEvaluation::score_move_for_ordering(
-                board.side_to_move(),
-                mv,
-                &None,
-                self.history_table,
-            )
-        });
+        let move_order = MoveOrder::new(board, &self.move_gen, Move::NULL, self.history_table);
+        let sorted_moves = captures
+            .into_iter()
+            .map(|mv| (mv, move_order.score(board, &self.move_gen, mv)))
+            .sorted_by_key(|(_, score)| Reverse(*score));
         let mut best = standing_eval;
 
-        for mv in sorted_moves {
+        // losing captures are pruned, unless we have to get out of check
+        let see_pruning = (qs_ply > 0 || self.parameters.qs_see_prune_first_ply) && !in_check;
+
+        for (mv, move_score) in sorted_moves {
+            if see_pruning && move_score.is_bad_capture() {
+                continue;
+            }
+
             board.make_move_unchecked(mv).unwrap();
             let score = if board.is_draw() {
                 Score::DRAW
             } else {
-                let eval = -self.quiescence(board, -beta, -alpha_use);
+                let eval = -self.quiescence(board, ply + 1, qs_ply + 1, -beta, -alpha_use);
                 self.nodes += 1;
                 eval
             };
@@ -540,15 +934,34 @@ impl<'a> Search<'a> {
 
 #[cfg(test)]
 mod tests {
-    use std::time::Duration;
+    use std::{
+        sync::{atomic::AtomicBool, Arc},
+        time::Duration,
+    };
 
-    use chess::{board::Board, pieces::ALL_PIECES};
+    use chess::{
+        board::Board,
+        definitions::Squares,
+        move_generation::MoveGenerator,
+        moves::{Move, MoveDescriptor},
+        pieces::{Piece, ALL_PIECES},
+        square::Square,
+    };
+    use uci_parser::{UciCommand, UciSearchOptions};
 
     use crate::{
+        aspiration_window::AspirationWindow,
+        defs::MAX_DEPTH,
         evaluation::ByteKnightEvaluation,
-        score::Score,
-        search::{Search, SearchParameters},
-        ttable::TranspositionTable,
+        log_level::LogLevel,
+        score::{Score, ScoreType},
+        search::{
+            draw_jitter, Search, SearchParameters, SearchStopReason, DEFAULT_MOVE_OVERHEAD,
+            MIN_TIMEOUT,
+        },
+        see::{piece_values, see},
+        traits::Eval,
+        ttable::{EntryFlag, TranspositionTable, TranspositionTableEntry},
     };
 
     use super::LargeScoreType;
@@ -573,6 +986,102 @@ mod tests {
         );
     }
 
+    #[test]
+    fn equal_best_moves_are_picked_deterministically() {
+        // both Qa8 and Re8 mate, the queen move comes first because it starts on the lower square
+        let fen = "6k1/5ppp/8/8/8/8/8/Q3R1K1 w - - 0 1";
+        let config = SearchParameters {
+            max_depth: 3,
+            ..Default::default()
+        };
+
+        for _ in 0..3 {
+            let mut board = Board::from_fen(fen).unwrap();
+            let mut ttable = TranspositionTable::default();
+            let mut history_table = Default::default();
+            let mut search = Search::new(&config, &mut ttable, &mut history_table);
+            let res = search.search(&mut board, None);
+            assert!(res.score.is_mate(), "{}", res.score);
+            assert_eq!(res.best_move.unwrap().to_long_algebraic(), "a1a8");
+        }
+    }
+
+    #[test]
+    fn leaf_checkmate_and_stalemate_scores() {
+        let config = SearchParameters::default();
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+
+        // black is checkmated, at a regular node and when reaching it in quiescence search
+        let mut mated = Board::from_fen("k7/1Q6/1K6/8/8/8/8/8 b - - 0 1").unwrap();
+        let score = search.negamax(&mut mated, 1, 0, -Score::INF, Score::INF);
+        assert_eq!(score, Score::mated_in(0));
+        let score = search.quiescence(&mut mated, 3, 0, -Score::INF, Score::INF);
+        assert_eq!(score, Score::mated_in(3));
+
+        // black is stalemated. Quiescence search only sees that there are no captures, so it
+        // stands pat instead
+        let mut stalemate = Board::from_fen("k7/8/1Q6/8/8/8/8/K7 b - - 0 1").unwrap();
+        let score = search.negamax(&mut stalemate, 1, 0, -Score::INF, Score::INF);
+        assert_eq!(score, Score::DRAW);
+        let score = search.quiescence(&mut stalemate, 3, 0, -Score::INF, Score::INF);
+        assert_eq!(score, ByteKnightEvaluation::default().eval(&stalemate));
+
+        // a mate in one is found by the first iteration
+        let mut board = Board::from_fen("k7/8/KQ6/8/8/8/8/8 w - - 0 1").unwrap();
+        let config = SearchParameters {
+            max_depth: 1,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let res = Search::new(&config, &mut ttable, &mut history_table).search(&mut board, None);
+        assert_eq!(res.score, Score::mate_in(1));
+    }
+
+    #[test]
+    fn search_without_completed_iteration_still_has_a_score() {
+        let move_gen = MoveGenerator::new();
+        let mut board = Board::default_board();
+        let legal_moves = move_gen.generate_legal_moves_vec(&board);
+
+        // no iteration is allowed to run, so the score falls back to the static evaluation
+        let config = SearchParameters {
+            max_depth: 0,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let res = Search::new(&config, &mut ttable, &mut history_table).search(&mut board, None);
+        assert!(legal_moves.contains(&res.best_move.unwrap()));
+        assert_eq!(res.score, ByteKnightEvaluation::default().eval(&board));
+
+        // stopped before the search even started
+        let config = SearchParameters::default();
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let stop_flag = Arc::new(AtomicBool::new(true));
+        let res = Search::new(&config, &mut ttable, &mut history_table)
+            .search(&mut board, Some(stop_flag));
+        assert_eq!(res.stop_reason, SearchStopReason::Stopped);
+        assert!(legal_moves.contains(&res.best_move.unwrap()));
+        assert!(res.score > -Score::INF);
+        assert!(res.score.mate_distance().is_none());
+
+        // without legal moves there is no best move, but the score is still the game result
+        let mut mated = Board::from_fen("k7/1Q6/1K6/8/8/8/8/8 b - - 0 1").unwrap();
+        let config = SearchParameters {
+            max_depth: 0,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let res = Search::new(&config, &mut ttable, &mut history_table).search(&mut mated, None);
+        assert!(res.best_move.is_none());
+        assert_eq!(res.score, Score::mated_in(0));
+    }
+
     #[test]
     fn black_mated_in_1() {
         let fen = "1k6/8/KQ6/2Q5/8/8/8/8 b - - 0 1";
@@ -590,6 +1099,310 @@ mod tests {
         assert_eq!(res.best_move.unwrap().to_long_algebraic(), "b8a8")
     }
 
+    #[test]
+    fn only_move_is_played_immediately() {
+        // black is in check from the rook on d8 and must recapture with the rook on d2
+        let fen = "3R2k1/5ppp/8/8/8/8/3r1PPP/6K1 b - - 0 1";
+        let mut board = Board::from_fen(fen).unwrap();
+        let config = SearchParameters {
+            max_depth: 64,
+            ..Default::default()
+        };
+
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+
+        assert_eq!(res.best_move.unwrap().to_long_algebraic(), "d2d8");
+        assert_eq!(res.stop_reason, SearchStopReason::OnlyMove);
+        // only a single iteration was searched
+        assert_eq!(res.depth, 2);
+        assert!(res.nodes > 0);
+        assert!(res.score > -Score::INF);
+    }
+
+    #[test]
+    fn narrow_aspiration_window_logs_re_search() {
+        // white is up a queen, so a narrow window around an equal score has to fail high
+        let mut board = Board::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        let config = SearchParameters {
+            max_depth: 4,
+            log_level: LogLevel::Debug,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+
+        let window = AspirationWindow::around(Score::new(0), 4);
+        let mut output = Vec::new();
+        let score = search
+            .aspiration_search(&mut board, 4, window, &mut output)
+            .unwrap();
+        assert!(score > Score::new(0));
+
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("info string aspiration fail high at depth 4"));
+
+        // re-searches are not logged below the debug level
+        search.parameters.log_level = LogLevel::Info;
+        let mut output = Vec::new();
+        search.aspiration_search(&mut board, 4, window, &mut output);
+        let output = String::from_utf8(output).unwrap();
+        assert!(!output.contains("info string"));
+    }
+
+    #[test]
+    fn fail_high_reports_lowerbound() {
+        // white is up a queen, so a narrow window around an equal score has to fail high
+        let mut board = Board::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        let config = SearchParameters {
+            max_depth: 4,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+
+        let window = AspirationWindow::around(Score::new(0), 4);
+        let mut output = Vec::new();
+        search
+            .aspiration_search(&mut board, 4, window, &mut output)
+            .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+        let bound_line = output.lines().next().unwrap();
+        assert!(bound_line.starts_with("info depth 4"), "{}", bound_line);
+        assert!(bound_line.contains(" lowerbound "), "{}", bound_line);
+        assert!(!output.contains("upperbound"));
+    }
+
+    #[test]
+    fn search_reports_stop_reason() {
+        let mut board = Board::default_board();
+        let config = SearchParameters {
+            max_nodes: 1000,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+        assert_eq!(res.stop_reason, SearchStopReason::NodeLimit);
+        assert!(res.best_move.is_some());
+
+        let config = SearchParameters {
+            max_depth: 2,
+            ..Default::default()
+        };
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+        assert_eq!(res.stop_reason, SearchStopReason::DepthReached);
+    }
+
+    #[test]
+    fn search_stops_once_mate_is_confirmed() {
+        // 1. Ra6 bxa6 2. b7# is mate in 2, i.e. 3 plies
+        let mut board = Board::from_fen("kbK5/pp6/1P6/8/8/8/8/R7 w - - 0 1").unwrap();
+        let config = SearchParameters {
+            max_depth: 30,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+
+        assert_eq!(res.stop_reason, SearchStopReason::MateFound);
+        assert_eq!(res.score, Score::mate_in(3));
+        assert_eq!(res.score.moves_to_mate(), Some(2));
+        assert_eq!(res.best_move.unwrap().to_long_algebraic(), "a1a6");
+        // stopped right after the mate was found twice, well before the maximum depth
+        assert!(res.depth <= 6, "{}", res.depth);
+
+        // with a fixed depth the search keeps going
+        let config = SearchParameters {
+            max_depth: 6,
+            fixed_depth: true,
+            ..Default::default()
+        };
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+        assert_eq!(res.stop_reason, SearchStopReason::DepthReached);
+        assert_eq!(res.score, Score::mate_in(3));
+    }
+
+    #[test]
+    fn queen_sacrifice_is_not_pruned() {
+        // Qxg8+ loses the queen for a bishop, but Rxg8 Nf7 is smothered mate
+        let mut board = Board::from_fen("5rbk/6pp/7N/3Q4/8/8/5PPP/6K1 w - - 0 1").unwrap();
+        let config = SearchParameters {
+            max_depth: 4,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+
+        assert_eq!(res.best_move.unwrap().to_long_algebraic(), "d5g8");
+        assert!(res.score.is_mate());
+        assert!(res.score > Score::new(0));
+    }
+
+    #[test]
+    fn quiescence_skips_losing_captures() {
+        // Qxe5 wins a pawn but loses the queen to dxe5, it is the only capture
+        let mut board = Board::from_fen("k7/8/3p4/4p3/8/8/8/4Q1K1 w - - 0 1").unwrap();
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+
+        let mut results = Vec::new();
+        for prune in [true, false] {
+            let config = SearchParameters {
+                qs_see_prune_first_ply: prune,
+                ..Default::default()
+            };
+            let mut search = Search::new(&config, &mut ttable, &mut history_table);
+            let score = search.quiescence(&mut board, 0, 0, -Score::INF, Score::INF);
+            results.push((score, search.nodes));
+        }
+
+        // standing pat is better either way, but only the unpruned search looks at the capture
+        let eval = ByteKnightEvaluation::default().eval(&board);
+        assert_eq!(results[0], (eval, 0));
+        assert_eq!(results[1].0, eval);
+        assert!(results[1].1 >= 2);
+    }
+
+    #[test]
+    fn quiescence_searches_losing_captures_in_check() {
+        // the knight checks, the king has no squares and Qxf2 exf2 is the only evasion
+        let mut board = Board::from_fen("k7/8/8/8/8/4pQ2/5nPP/6RK w - - 0 1").unwrap();
+        let move_gen = MoveGenerator::new();
+        let evasions = move_gen.generate_legal_moves_vec(&board);
+        assert_eq!(evasions.len(), 1);
+        assert_eq!(evasions[0].to_long_algebraic(), "f3f2");
+        assert!(!see(
+            &board,
+            &move_gen,
+            &evasions[0],
+            0,
+            &piece_values(&board)
+        ));
+
+        let config = SearchParameters::default();
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let score = search.quiescence(&mut board, 0, 0, -Score::INF, Score::INF);
+
+        assert!(search.nodes >= 1);
+        assert!(!score.is_mate());
+    }
+
+    #[test]
+    fn seldepth_includes_quiescence() {
+        // lots of captures available, so quiescence goes beyond the nominal depth
+        let mut board =
+            Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+                .unwrap();
+        let config = SearchParameters {
+            max_depth: 3,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+
+        assert!(res.seldepth > config.max_depth);
+        // the counter is reset for the next search
+        assert_eq!(search.seldepth, 0);
+    }
+
+    #[test]
+    fn quiescence_stops_at_the_ply_limit() {
+        // both sides have a full rank of rooks, so the capture sequences go on for many plies
+        let mut board = Board::from_fen("k7/rrrrrrrr/8/8/8/8/RRRRRRRR/7K w - - 0 1").unwrap();
+        let config = SearchParameters {
+            qs_max_ply: 4,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let score = search.quiescence(&mut board, 0, 0, -Score::INF, Score::INF);
+
+        // the limit is reached, but never exceeded
+        assert_eq!(search.seldepth, config.qs_max_ply);
+        assert!(search.nodes < 10_000);
+        assert!(!score.is_mate());
+    }
+
+    #[test]
+    fn search_stops_at_the_maximum_ply() {
+        // a node at the deepest ply with depth left, as if the line had been extended that far
+        let mut board =
+            Board::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1")
+                .unwrap();
+        let config = SearchParameters::default();
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let ply = MAX_DEPTH as ScoreType - 1;
+        let score = search.negamax(&mut board, 6, ply, -Score::INF, Score::INF);
+
+        assert_eq!(score, search.eval.eval(&board));
+        assert_eq!(search.nodes, 1);
+        assert_eq!(search.seldepth, MAX_DEPTH - 1);
+    }
+
+    #[test]
+    fn root_repetition_is_a_draw() {
+        let mut board = Board::default_board();
+        // shuffle the knights back and forth so the start position occurs for the third time
+        for mv in [
+            "g1f3", "g8f6", "f3g1", "f6g8", "g1f3", "g8f6", "f3g1", "f6g8",
+        ] {
+            board.make_uci_move(mv).unwrap();
+        }
+        assert!(board.is_repetition());
+
+        let config = SearchParameters {
+            max_depth: 4,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+
+        assert_eq!(res.score, Score::DRAW);
+        let move_gen = MoveGenerator::new();
+        assert!(res
+            .best_move
+            .is_some_and(|mv| board.is_legal(&mv, &move_gen)));
+    }
+
+    #[test]
+    fn root_fifty_move_rule_is_a_draw() {
+        // white is up a queen, but the fifty move rule has already been reached
+        let mut board = Board::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 100 80").unwrap();
+        let config = SearchParameters {
+            max_depth: 4,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+
+        assert_eq!(res.score, Score::DRAW);
+        assert!(res.best_move.is_some());
+    }
+
     #[test]
     fn stalemate() {
         let fen = "k7/8/KQ6/8/8/8/8/8 b - - 0 1";
@@ -716,9 +1529,17 @@ mod tests {
 
             let side = board.side_to_move();
             let mut max_history = LargeScoreType::MIN;
-            for piece in ALL_PIECES {
-                for square in 0..64 {
-                    let score = history_table.get(side, piece, square);
+            for from in 0..64 {
+                for to in 0..64 {
+                    let mv = Move::new(
+                        &Square::from_square_index(from),
+                        &Square::from_square_index(to),
+                        MoveDescriptor::None,
+                        Piece::Pawn,
+                        None,
+                        None,
+                    );
+                    let score = history_table.get(side, &mv);
                     if score > max_history {
                         max_history = score;
                     }
@@ -730,4 +1551,226 @@ mod tests {
             assert!(max_history < min_mvv_lva);
         }
     }
+
+    #[test]
+    fn move_overhead_is_subtracted_from_allocated_time() {
+        let board = Board::default_board();
+        let options = UciSearchOptions {
+            wtime: Some(Duration::from_secs(10)),
+            btime: Some(Duration::from_secs(10)),
+            ..Default::default()
+        };
+
+        let params = SearchParameters::new(&options, &board, Duration::ZERO);
+        let with_overhead = SearchParameters::new(&options, &board, DEFAULT_MOVE_OVERHEAD);
+        assert_eq!(
+            params.soft_timeout - with_overhead.soft_timeout,
+            DEFAULT_MOVE_OVERHEAD
+        );
+        assert_eq!(
+            params.hard_timeout - with_overhead.hard_timeout,
+            DEFAULT_MOVE_OVERHEAD
+        );
+
+        // a large overhead with little time left never results in a zero time limit
+        let options = UciSearchOptions {
+            wtime: Some(Duration::from_millis(50)),
+            ..Default::default()
+        };
+        let params = SearchParameters::new(&options, &board, Duration::from_secs(1));
+        assert_eq!(params.soft_timeout, MIN_TIMEOUT);
+        assert_eq!(params.hard_timeout, MIN_TIMEOUT);
+
+        let options = UciSearchOptions {
+            movetime: Some(Duration::from_millis(10)),
+            ..Default::default()
+        };
+        let params = SearchParameters::new(&options, &board, Duration::from_secs(1));
+        assert_eq!(params.soft_timeout, MIN_TIMEOUT);
+        assert_eq!(params.hard_timeout, MIN_TIMEOUT);
+    }
+
+    #[test]
+    fn degenerate_time_controls_return_a_legal_move() {
+        let move_gen = MoveGenerator::new();
+        for go in [
+            "go wtime 0 btime 0 movestogo 0",
+            "go wtime 0 btime 0",
+            "go wtime 1 btime 1 winc 0 binc 0",
+            "go wtime 100000 btime 100000 movestogo 4294967295",
+            "go movetime 0",
+            "go depth 0",
+            "go nodes 0",
+        ] {
+            let Ok(UciCommand::Go(options)) = go.parse::<UciCommand>() else {
+                panic!("failed to parse {}", go);
+            };
+
+            let mut board = Board::default_board();
+            let config = SearchParameters::new(&options, &board, DEFAULT_MOVE_OVERHEAD);
+            let mut ttable = Default::default();
+            let mut history_table = Default::default();
+            let mut search = Search::new(&config, &mut ttable, &mut history_table);
+            let res = search.search(&mut board, None);
+
+            let best_move = res.best_move.expect(go);
+            assert!(
+                board.is_legal(&best_move, &move_gen),
+                "{} returned illegal move {}",
+                go,
+                best_move.to_long_algebraic()
+            );
+            assert!(
+                config.start_time.elapsed() < Duration::from_secs(2),
+                "{}",
+                go
+            );
+        }
+    }
+
+    #[test]
+    fn fixed_depth_search_without_clock_ignores_time() {
+        let go = "go depth 8";
+        let Ok(UciCommand::Go(options)) = go.parse::<UciCommand>() else {
+            panic!("failed to parse {}", go);
+        };
+
+        let mut board = Board::default_board();
+        let config = SearchParameters::new(&options, &board, DEFAULT_MOVE_OVERHEAD);
+        assert_eq!(config.soft_timeout, Duration::MAX);
+        assert_eq!(config.hard_timeout, Duration::MAX);
+
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+
+        assert_eq!(res.stop_reason, SearchStopReason::DepthReached);
+        // the result depth is one past the last completed iteration
+        assert_eq!(res.depth, 9);
+
+        // with a clock the depth is only an upper limit, running out of time still stops the search
+        let Ok(UciCommand::Go(options)) = "go depth 30 wtime 1 btime 1".parse::<UciCommand>()
+        else {
+            panic!("failed to parse go depth 30 with a clock");
+        };
+        let config = SearchParameters::new(&options, &board, DEFAULT_MOVE_OVERHEAD);
+        assert_eq!(config.hard_timeout, MIN_TIMEOUT);
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+        assert_eq!(res.stop_reason, SearchStopReason::TimeLimit);
+        assert!(res.depth < 30);
+
+        // a single legal move is still searched to the full depth
+        let Ok(UciCommand::Go(options)) = "go depth 4".parse::<UciCommand>() else {
+            panic!("failed to parse go depth 4");
+        };
+        let mut board = Board::from_fen("3R2k1/5ppp/8/8/8/8/3r1PPP/6K1 b - - 0 1").unwrap();
+        let config = SearchParameters::new(&options, &board, DEFAULT_MOVE_OVERHEAD);
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+        assert_eq!(res.stop_reason, SearchStopReason::DepthReached);
+        assert_eq!(res.depth, 5);
+    }
+
+    #[test]
+    fn perpetual_check_is_a_draw() {
+        // white is two rooks down and about to be mated, but Qe8+ Kh7 Qh5+ Kg8 Qe8+ is a perpetual
+        let fen = "6k1/6p1/8/8/pp6/8/rr2Q1PP/n5K1 w - - 0 1";
+        let mut board = Board::from_fen(fen).unwrap();
+        let config = SearchParameters {
+            max_depth: 8,
+            ..Default::default()
+        };
+
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+
+        assert_eq!(res.best_move.unwrap().to_long_algebraic(), "e2e8");
+        assert_eq!(res.score, Score::DRAW);
+    }
+
+    #[test]
+    fn draw_jitter_is_small() {
+        for nodes in 0..64 {
+            let jitter = draw_jitter(nodes);
+            assert!((-1..=2).contains(&jitter.0), "{}", jitter);
+        }
+        assert_ne!(draw_jitter(0), draw_jitter(1));
+    }
+
+    #[test]
+    fn jittered_draws_stay_draws() {
+        let config = SearchParameters {
+            max_depth: 8,
+            draw_jitter: true,
+            ..Default::default()
+        };
+
+        // the perpetual is still found and scored close to a draw
+        let mut board = Board::from_fen("6k1/6p1/8/8/pp6/8/rr2Q1PP/n5K1 w - - 0 1").unwrap();
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+        assert_eq!(res.best_move.unwrap().to_long_algebraic(), "e2e8");
+        assert!(res.score.0.abs() <= 2, "{}", res.score);
+
+        // decisive scores are not affected
+        let mut board = Board::from_fen("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").unwrap();
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+        assert!(res.score > Score::new(100), "{}", res.score);
+    }
+
+    #[test]
+    fn colliding_tt_entry_is_rejected() {
+        let fen = "k7/8/KQ6/8/8/8/8/8 w - - 0 1";
+        let mut board = Board::from_fen(fen).unwrap();
+        let move_gen = MoveGenerator::new();
+        let mut ttable = TranspositionTable::default();
+
+        // pretend both mates collide with entries from other positions that claim black is winning
+        // and store a move that makes no sense in the mated position
+        let bogus_move = Move::new(
+            &Square::from_square_index(Squares::H1),
+            &Square::from_square_index(Squares::H2),
+            MoveDescriptor::None,
+            Piece::Rook,
+            None,
+            None,
+        );
+        for mate in ["b6a7", "b6b7"] {
+            let mut after_mate = board.clone();
+            after_mate.make_uci_move(mate).unwrap();
+            assert!(!after_mate.is_pseudo_legal(&bogus_move, &move_gen));
+            ttable.store_entry(TranspositionTableEntry::new(
+                after_mate.zobrist_hash(),
+                MAX_DEPTH,
+                Score::new(1000),
+                EntryFlag::Exact,
+                bogus_move,
+            ));
+        }
+
+        let config = SearchParameters {
+            max_depth: 2,
+            ..Default::default()
+        };
+        let mut history_table = Default::default();
+        let mut search = Search::new(&config, &mut ttable, &mut history_table);
+        let res = search.search(&mut board, None);
+
+        assert!(res.score.is_mate(), "{}", res.score);
+        let best_move = res.best_move.unwrap().to_long_algebraic();
+        assert!(best_move == "b6a7" || best_move == "b6b7", "{}", best_move);
+    }
 }
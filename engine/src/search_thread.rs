@@ -4,7 +4,7 @@
  * Created Date: Thursday, November 21st 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Sat Nov 30 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -17,10 +17,10 @@ use std::{
     str::FromStr,
     sync::{
         atomic::{AtomicBool, Ordering},
-        mpsc::{self, Sender},
         Arc, Mutex,
     },
     thread::JoinHandle,
+    time::Duration,
 };
 
 use chess::{board::Board, moves::Move, pieces::SQUARE_NAME};
@@ -28,7 +28,7 @@ use uci_parser::{UciMove, UciResponse};
 
 use crate::{
     history_table::HistoryTable,
-    search::{Search, SearchParameters},
+    search::{Search, SearchParameters, SearchResult},
     ttable::TranspositionTable,
 };
 
@@ -53,112 +53,229 @@ fn move_to_uci_move(mv: &Move) -> UciMove {
     }
 }
 
-#[allow(clippy::large_enum_variant)]
-pub(crate) enum SearchThreadValue {
-    Params(
-        Board,
-        SearchParameters,
-        Arc<Mutex<TranspositionTable>>,
-        Arc<Mutex<HistoryTable>>,
-    ),
-    Exit,
+/// Returns the UCI `bestmove` response for a search result, including the move to ponder on if
+/// the search found one.
+fn best_move_response(result: &SearchResult) -> UciResponse<String> {
+    UciResponse::BestMove {
+        bestmove: result.best_move.map(|mv| move_to_uci_move(&mv).to_string()),
+        ponder: result
+            .ponder_move
+            .map(|mv| move_to_uci_move(&mv).to_string()),
+    }
 }
 
-/// A thread worker that manages the search. It receives search parameters and a board state and
-/// sends the best move to the UCI output.
+/// A search running on its own thread. When the search finishes, either on its own or because it
+/// was stopped, the best move is written to stdout as a UCI `bestmove` response. An infinite
+/// search only writes its best move after it was stopped.
+///
+/// Dropping the handle stops the search and waits for the thread to finish, so a search never
+/// outlives its handle.
 pub(crate) struct SearchThread {
-    sender: Sender<SearchThreadValue>,
-    handle: Option<JoinHandle<()>>,
-    stop_search_flag: Arc<AtomicBool>,
-    is_searching: Arc<AtomicBool>,
+    handle: Option<JoinHandle<SearchResult>>,
+    stop_flag: Arc<AtomicBool>,
 }
 
 impl SearchThread {
-    /// Creates a new [`SearchThread`]. The search thread is responsible for managing the search.
-    /// When the search thread is created, the thread loop starts and begins to wait for search parameters.
-    pub(crate) fn new() -> SearchThread {
-        let (sender, receiver) = mpsc::channel();
+    /// Starts a new search of `board` on a new thread.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The position to search.
+    /// - `params` - The search parameters.
+    /// - `ttable` - The transposition table, locked for the duration of the search.
+    /// - `history_table` - The history table, locked for the duration of the search.
+    ///
+    /// # Returns
+    ///
+    /// The handle of the running search.
+    pub(crate) fn spawn(
+        board: &Board,
+        params: SearchParameters,
+        ttable: Arc<Mutex<TranspositionTable>>,
+        history_table: Arc<Mutex<HistoryTable>>,
+    ) -> SearchThread {
         let stop_flag = Arc::new(AtomicBool::new(false));
-        let is_searching = Arc::new(AtomicBool::new(false));
-
-        let stop_flag_clone = stop_flag.clone();
-        let is_searching_clone = is_searching.clone();
+        let flag = stop_flag.clone();
+        let mut board = board.clone();
 
         let handle = std::thread::spawn(move || {
-            let mut stdout = std::io::stdout();
-            'search_loop: loop {
-                let value = receiver.recv().unwrap();
-                match value {
-                    SearchThreadValue::Params(mut board, params, ttable, history) => {
-                        let mut tt = ttable.lock().unwrap();
-                        let mut hist_table = history.lock().unwrap();
-                        let flag = stop_flag.clone();
-                        is_searching.store(true, Ordering::Relaxed);
-                        let result = Search::new(&params, &mut tt, &mut hist_table)
-                            .search(&mut board, Some(flag));
-                        is_searching.store(false, Ordering::Relaxed);
-                        let best_move = result.best_move;
-                        let move_output = UciResponse::BestMove {
-                            bestmove: best_move
-                                .map(|bot_move| move_to_uci_move(&bot_move).to_string()),
-                            ponder: None,
-                        };
-                        writeln!(
-                            stdout,
-                            "{}",
-                            // TODO: Ponder
-                            move_output
-                        )
-                        .unwrap();
-                    }
-
-                    SearchThreadValue::Exit => {
-                        break 'search_loop;
-                    }
-                }
+            let mut tt = ttable.lock().unwrap();
+            let mut hist_table = history_table.lock().unwrap();
+            let result = Search::new(&params, &mut tt, &mut hist_table)
+                .search(&mut board, Some(flag.clone()));
+
+            // UCI doesn't allow `bestmove` before `stop` in an infinite search, even if the search
+            // has nothing left to do
+            while params.infinite && !flag.load(Ordering::Relaxed) {
+                std::thread::sleep(Duration::from_millis(1));
             }
+
+            let mut stdout = std::io::stdout();
+            writeln!(stdout, "{}", best_move_response(&result)).unwrap();
+            result
         });
 
         SearchThread {
-            sender,
             handle: Some(handle),
-            stop_search_flag: stop_flag_clone,
-            is_searching: is_searching_clone,
+            stop_flag,
         }
     }
 
-    /// Exits the search thread. This will stop the search thread and join it.
-    pub(crate) fn exit(&mut self) {
-        self.stop_search();
-        self.sender.send(SearchThreadValue::Exit).unwrap();
-        self.handle.take().unwrap().join().unwrap();
+    /// Signals the search to stop. The search finishes its current node and then writes its best
+    /// move, use [`SearchThread::join`] to wait for it.
+    pub(crate) fn stop(&self) {
+        self.stop_flag.store(true, Ordering::Relaxed);
     }
 
-    /// Stops the current search if any is in progress.
-    pub(crate) fn stop_search(&self) {
-        self.stop_search_flag.store(true, Ordering::Relaxed);
+    /// Waits for the search to finish and returns its result. This does not stop the search, call
+    /// [`SearchThread::stop`] first to end an infinite search.
+    pub(crate) fn join(mut self) -> SearchResult {
+        self.handle.take().unwrap().join().unwrap()
     }
 
-    /// Starts a new search with the given parameters and board state.
-    pub(crate) fn start_search(
-        &self,
-        board: &Board,
-        params: SearchParameters,
-        ttable: Arc<Mutex<TranspositionTable>>,
-        history_table: Arc<Mutex<HistoryTable>>,
-    ) {
-        self.stop_search_flag.store(false, Ordering::Relaxed);
-        self.sender
-            .send(SearchThreadValue::Params(
-                board.clone(),
-                params,
-                ttable,
-                history_table,
-            ))
-            .unwrap();
+    /// Returns `true` while the search is still running.
+    pub(crate) fn is_running(&self) -> bool {
+        self.handle
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
     }
+}
+
+impl Drop for SearchThread {
+    fn drop(&mut self) {
+        self.stop();
+        if let Some(handle) = self.handle.take() {
+            // a panic in the search has already been reported, don't panic again while dropping
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
+
+    use chess::{board::Board, move_generation::MoveGenerator};
+
+    use uci_parser::UciSearchOptions;
+
+    use super::{best_move_response, SearchThread};
+    use crate::search::{
+        Search, SearchParameters, SearchResult, SearchStopReason, DEFAULT_MOVE_OVERHEAD,
+    };
+
+    #[test]
+    fn stopping_an_infinite_search_returns_a_result() {
+        let board = Board::default_board();
+        let ttable = Arc::new(Mutex::new(Default::default()));
+        let history_table = Arc::new(Mutex::new(Default::default()));
+
+        // the default parameters have no depth, time or node limit
+        let search = SearchThread::spawn(
+            &board,
+            SearchParameters::default(),
+            ttable.clone(),
+            history_table.clone(),
+        );
+        std::thread::sleep(Duration::from_millis(50));
+        assert!(search.is_running());
+
+        let stopped_at = Instant::now();
+        search.stop();
+        let result = search.join();
+        assert!(stopped_at.elapsed() < Duration::from_secs(1));
+        assert_eq!(result.stop_reason, SearchStopReason::Stopped);
+
+        let legal_moves = MoveGenerator::new().generate_legal_moves_vec(&board);
+        assert!(legal_moves.contains(&result.best_move.unwrap()));
+
+        // dropping a running search stops it and releases the tables
+        let search = SearchThread::spawn(
+            &board,
+            SearchParameters::default(),
+            ttable.clone(),
+            history_table,
+        );
+        drop(search);
+        assert!(ttable.try_lock().is_ok());
+    }
+
+    #[test]
+    fn infinite_search_runs_until_stopped() {
+        // mate in 2 (a1a6), found within the first few iterations
+        let board = Board::from_fen("kbK5/pp6/1P6/8/8/8/8/R7 w - - 0 1").unwrap();
+        let options = UciSearchOptions {
+            infinite: true,
+            ..Default::default()
+        };
+        let search = SearchThread::spawn(
+            &board,
+            SearchParameters::new(&options, &board, DEFAULT_MOVE_OVERHEAD),
+            Arc::new(Mutex::new(Default::default())),
+            Arc::new(Mutex::new(Default::default())),
+        );
+        std::thread::sleep(Duration::from_millis(300));
+        assert!(search.is_running());
+
+        search.stop();
+        let result = search.join();
+        assert_eq!(result.stop_reason, SearchStopReason::Stopped);
+        assert_eq!(result.best_move.unwrap().to_long_algebraic(), "a1a6");
+        assert!(result.score.is_mate());
+    }
+
+    #[test]
+    fn infinite_search_with_a_single_legal_move_runs_until_stopped() {
+        // h1g1 is the only legal move
+        let board = Board::from_fen("k6r/8/8/8/8/8/r7/7K w - - 0 1").unwrap();
+        let options = UciSearchOptions {
+            infinite: true,
+            ..Default::default()
+        };
+        let search = SearchThread::spawn(
+            &board,
+            SearchParameters::new(&options, &board, DEFAULT_MOVE_OVERHEAD),
+            Arc::new(Mutex::new(Default::default())),
+            Arc::new(Mutex::new(Default::default())),
+        );
+        std::thread::sleep(Duration::from_millis(300));
+        assert!(search.is_running());
+
+        search.stop();
+        let result = search.join();
+        assert_eq!(result.stop_reason, SearchStopReason::Stopped);
+        assert!(result.depth > 2);
+        assert_eq!(result.best_move.unwrap().to_long_algebraic(), "h1g1");
+    }
+
+    #[test]
+    fn best_move_includes_the_ponder_move() {
+        // the queens trade on d8, black has to recapture with the rook or stay a queen down
+        let mut board = Board::from_fen("3qr1k1/5ppp/8/8/8/8/5PPP/3Q2K1 w - - 0 1").unwrap();
+        let config = SearchParameters {
+            max_depth: 5,
+            ..Default::default()
+        };
+        let mut ttable = Default::default();
+        let mut history_table = Default::default();
+        let result = Search::new(&config, &mut ttable, &mut history_table).search(&mut board, None);
+
+        assert_eq!(
+            best_move_response(&result).to_string().trim_end(),
+            "bestmove d1d8 ponder e8d8"
+        );
 
-    pub(crate) fn is_searching(&self) -> bool {
-        self.is_searching.load(Ordering::Relaxed)
+        // without a reply only the best move is sent
+        let result = SearchResult {
+            ponder_move: None,
+            ..result
+        };
+        assert_eq!(
+            best_move_response(&result).to_string().trim_end(),
+            "bestmove d1d8"
+        );
     }
 }
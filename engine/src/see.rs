@@ -0,0 +1,237 @@
+/*
+ * see.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use chess::{
+    bitboard::Bitboard,
+    board::Board,
+    definitions::NumberOf,
+    move_generation::MoveGenerator,
+    moves::Move,
+    pieces::{Piece, ALL_PIECES},
+    side::PlayerSide,
+};
+
+use crate::{
+    phased_score::{PhaseType, PhasedScore, MAX_PHASE},
+    psqt::{EG_VALUE, GAMEPHASE_INC, MG_VALUE},
+    score::ScoreType,
+    tuneable::SEE_TAPERED_PIECE_VALUES,
+};
+
+/// Piece values used for static exchange evaluation, indexed by [`Piece`].
+pub type SeeValues = [ScoreType; NumberOf::PIECE_TYPES];
+
+/// Order in which pieces are used to recapture, least valuable first.
+const RECAPTURE_ORDER: [Piece; NumberOf::PIECE_TYPES] = [
+    Piece::Pawn,
+    Piece::Knight,
+    Piece::Bishop,
+    Piece::Rook,
+    Piece::Queen,
+    Piece::King,
+];
+
+/// Returns the flat material values of the pieces, see [`chess::pieces::PIECE_VALUES`].
+pub fn flat_piece_values() -> SeeValues {
+    ALL_PIECES.map(|piece| piece.value() as ScoreType)
+}
+
+/// Returns the piece values interpolated between the middle game and end game values based on the
+/// game phase of the given board.
+pub fn tapered_piece_values(board: &Board) -> SeeValues {
+    let phase: PhaseType = ALL_PIECES
+        .iter()
+        .map(|&piece| {
+            let count = board
+                .piece_bitboard(piece, PlayerSide::White)
+                .number_of_occupied_squares()
+                + board
+                    .piece_bitboard(piece, PlayerSide::Black)
+                    .number_of_occupied_squares();
+            GAMEPHASE_INC[piece as usize] as PhaseType * count as PhaseType
+        })
+        .sum();
+
+    ALL_PIECES.map(|piece| {
+        PhasedScore::new(MG_VALUE[piece as usize], EG_VALUE[piece as usize])
+            .taper(phase.min(MAX_PHASE), MAX_PHASE)
+    })
+}
+
+/// Returns the piece values to use for static exchange evaluation on the given board. Whether
+/// these are flat or tapered is controlled by [`SEE_TAPERED_PIECE_VALUES`].
+pub fn piece_values(board: &Board) -> SeeValues {
+    if SEE_TAPERED_PIECE_VALUES {
+        tapered_piece_values(board)
+    } else {
+        flat_piece_values()
+    }
+}
+
+/// Static exchange evaluation. Plays out all captures on the target square of `mv`, always
+/// recapturing with the least valuable piece, and checks if the side making the move comes out at
+/// least `threshold` ahead.
+///
+/// # Arguments
+///
+/// - `board` - The current board, with `mv` not made yet.
+/// - `move_gen` - The move generator used to find the attackers.
+/// - `mv` - The move to evaluate.
+/// - `threshold` - The minimum material gain for the move to pass.
+/// - `values` - The piece values to use, see [`piece_values`].
+///
+/// # Returns
+///
+/// `true` if the exchange gains at least `threshold`, `false` otherwise.
+pub fn see(
+    board: &Board,
+    move_gen: &MoveGenerator,
+    mv: &Move,
+    threshold: ScoreType,
+    values: &SeeValues,
+) -> bool {
+    // castling can never lose material
+    if mv.is_castle() {
+        return threshold <= 0;
+    }
+
+    let value = |piece: Piece| values[piece as usize] as i32;
+    let from = mv.from();
+    let to = mv.to();
+
+    // what we win with the first capture, minus what we are willing to give up
+    let mut balance = mv.captured_piece().map_or(0, value) - threshold as i32;
+    if let Some(promotion) = mv.promotion_piece() {
+        balance += value(promotion) - value(Piece::Pawn);
+    }
+    if balance < 0 {
+        return false;
+    }
+
+    // assume we lose the piece we moved
+    let next_victim = mv.promotion_piece().unwrap_or(mv.piece());
+    balance -= value(next_victim);
+    if balance >= 0 {
+        return true;
+    }
+
+    let mut occupancy = board.all_pieces();
+    occupancy.clear_square(from);
+    occupancy.set_square(to);
+    if mv.is_en_passant_capture() {
+        let captured_square = if board.side_to_move().is_white() {
+            to - NumberOf::FILES as u8
+        } else {
+            to + NumberOf::FILES as u8
+        };
+        occupancy.clear_square(captured_square);
+    }
+
+    let us = board.side_to_move();
+    let mut side = PlayerSide::opposite(us);
+    loop {
+        let attackers = move_gen.attackers_to(board, to, &occupancy);
+        let side_attackers = attackers & board.pieces(side);
+        if side_attackers.as_number() == 0 {
+            break;
+        }
+
+        // recapture with the least valuable piece
+        let (attacker, square) = RECAPTURE_ORDER
+            .iter()
+            .find_map(|&piece| {
+                let pieces = side_attackers & *board.piece_bitboard(piece, side);
+                (pieces.as_number() != 0)
+                    .then(|| (piece, pieces.as_number().trailing_zeros() as u8))
+            })
+            .unwrap();
+        occupancy.clear_square(square);
+        side = PlayerSide::opposite(side);
+
+        // negamax the balance, the side that just recaptured now risks losing its attacker
+        balance = -balance - 1 - value(attacker);
+        if balance >= 0 {
+            // the king can only recapture if the square is no longer defended
+            let defenders = move_gen.attackers_to(board, to, &occupancy) & board.pieces(side);
+            if attacker == Piece::King && defenders != Bitboard::default() {
+                side = PlayerSide::opposite(side);
+            }
+            break;
+        }
+    }
+
+    // the side to move after the exchange is the one that lost it
+    side != us
+}
+
+#[cfg(test)]
+mod tests {
+    use chess::{board::Board, move_generation::MoveGenerator, move_list::MoveList, moves::Move};
+
+    use super::{flat_piece_values, see, tapered_piece_values};
+
+    fn find_move(board: &Board, move_gen: &MoveGenerator, uci: &str) -> Move {
+        let mut move_list = MoveList::new();
+        move_gen.generate_legal_moves(board, &mut move_list);
+        let mv = *move_list.find_uci(uci).unwrap();
+        mv
+    }
+
+    #[test]
+    fn exchanges() {
+        let move_gen = MoveGenerator::new();
+        let values = flat_piece_values();
+        for (fen, uci, threshold, expected) in [
+            // pawn takes a knight defended by a pawn
+            ("4k3/8/2p5/3n4/4P3/8/8/4K3 w - - 0 1", "e4d5", 0, true),
+            // queen takes a pawn defended by a pawn
+            ("4k3/8/2p5/3p4/8/8/8/3QK3 w - - 0 1", "d1d5", 0, false),
+            // rook takes a pawn defended by a rook, but is backed up by another rook
+            ("3rk3/8/8/3p4/8/8/3R4/3RK3 w - - 0 1", "d2d5", 0, true),
+            // same exchange, but winning a full pawn is required
+            ("3rk3/8/8/3p4/8/8/3R4/3RK3 w - - 0 1", "d2d5", 101, false),
+            // quiet move to a square attacked by a pawn
+            ("4k3/8/2p5/8/8/8/8/3QK3 w - - 0 1", "d1d5", 0, false),
+            // the king recaptures the queen, unless the square is still defended
+            ("8/8/4k3/3p4/8/8/3Q4/4K3 w - - 0 1", "d2d5", 0, false),
+            ("8/8/4k3/3p4/8/8/3Q4/3RK3 w - - 0 1", "d2d5", 0, true),
+        ] {
+            let board = Board::from_fen(fen).unwrap();
+            let mv = find_move(&board, &move_gen, uci);
+            assert_eq!(
+                see(&board, &move_gen, &mv, threshold, &values),
+                expected,
+                "{} {}",
+                fen,
+                uci
+            );
+        }
+    }
+
+    #[test]
+    fn tapered_values_follow_game_phase() {
+        let move_gen = MoveGenerator::new();
+        // knight takes a bishop defended by a pawn in a pawn endgame
+        let board = Board::from_fen("4k3/8/2p5/3b4/8/4N3/8/4K3 w - - 0 1").unwrap();
+        let mv = find_move(&board, &move_gen, "e3d5");
+
+        // flat values make the trade worth 10cp, in the endgame it is worth more
+        let flat = flat_piece_values();
+        let tapered = tapered_piece_values(&board);
+        assert!(!see(&board, &move_gen, &mv, 15, &flat));
+        assert!(see(&board, &move_gen, &mv, 15, &tapered));
+
+        assert_eq!(tapered_piece_values(&Board::default_board())[1], 1025);
+    }
+}
@@ -0,0 +1,128 @@
+/*
+ * timer.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use std::time::{Duration, Instant};
+
+/// Tracks the time spent searching against a soft and a hard limit.
+///
+/// Once the soft limit has passed, the search should not start a new iteration. Once the hard
+/// limit has passed, the search has to abort immediately. Both limits default to
+/// [`Duration::MAX`], i.e. no limit.
+#[derive(Clone, Copy, Debug)]
+pub struct Timer {
+    start: Instant,
+    soft_limit: Duration,
+    hard_limit: Duration,
+}
+
+impl Timer {
+    /// Creates a new timer without limits that started at `start`.
+    ///
+    /// # Arguments
+    ///
+    /// - `start` - When the timer started.
+    pub fn new(start: Instant) -> Self {
+        Timer {
+            start,
+            soft_limit: Duration::MAX,
+            hard_limit: Duration::MAX,
+        }
+    }
+
+    /// Sets the soft limit, after which no new search iteration should be started.
+    pub fn with_soft_limit(mut self, limit: Duration) -> Self {
+        self.soft_limit = limit;
+        self
+    }
+
+    /// Sets the hard limit, after which the search has to stop immediately.
+    pub fn with_hard_limit(mut self, limit: Duration) -> Self {
+        self.hard_limit = limit;
+        self
+    }
+
+    /// Returns when the timer started.
+    pub fn start(&self) -> Instant {
+        self.start
+    }
+
+    /// Returns the time elapsed since the timer started.
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Returns the time left until the hard limit, or zero if it has passed.
+    pub fn remaining(&self) -> Duration {
+        self.hard_limit.saturating_sub(self.elapsed())
+    }
+
+    /// Returns `true` if the soft limit has passed.
+    pub fn is_soft_expired(&self) -> bool {
+        self.elapsed() > self.soft_limit
+    }
+
+    /// Returns `true` if the hard limit has been reached.
+    pub fn is_hard_expired(&self) -> bool {
+        self.elapsed() >= self.hard_limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::Timer;
+
+    /// Returns an instant `ago` in the past, so tests don't depend on how long they take to run.
+    fn started_ago(ago: Duration) -> Instant {
+        Instant::now().checked_sub(ago).unwrap()
+    }
+
+    #[test]
+    fn no_limits_never_expire() {
+        let timer = Timer::new(started_ago(Duration::from_secs(60)));
+        assert!(timer.elapsed() >= Duration::from_secs(60));
+        assert!(!timer.is_soft_expired());
+        assert!(!timer.is_hard_expired());
+        assert!(timer.remaining() > Duration::from_secs(60 * 60 * 24 * 365));
+    }
+
+    #[test]
+    fn soft_and_hard_limits_expire_separately() {
+        let timer = Timer::new(started_ago(Duration::from_secs(10)))
+            .with_soft_limit(Duration::from_secs(1))
+            .with_hard_limit(Duration::from_secs(3600));
+        assert!(timer.is_soft_expired());
+        assert!(!timer.is_hard_expired());
+        let remaining = timer.remaining();
+        assert!(remaining <= Duration::from_secs(3590));
+        assert!(remaining > Duration::from_secs(3000));
+
+        let timer = timer.with_hard_limit(Duration::from_secs(5));
+        assert!(timer.is_hard_expired());
+        assert_eq!(timer.remaining(), Duration::ZERO);
+    }
+
+    #[test]
+    fn fresh_timer_has_not_expired() {
+        let start = Instant::now();
+        let timer = Timer::new(start)
+            .with_soft_limit(Duration::from_secs(3600))
+            .with_hard_limit(Duration::from_secs(7200));
+        assert_eq!(timer.start(), start);
+        assert!(!timer.is_soft_expired());
+        assert!(!timer.is_hard_expired());
+        assert!(timer.remaining() > Duration::from_secs(7000));
+    }
+}
@@ -1,6 +1,6 @@
-use chess::{pieces::Piece, side::Side};
+use chess::{pieces::Piece, side::PlayerSide};
 
-use crate::score::Score;
+use crate::{king_safety::KingZone, score::Score};
 
 pub trait Eval<Board> {
     fn eval(&self, board: &Board) -> Score;
@@ -8,5 +8,25 @@ pub trait Eval<Board> {
 
 pub trait EvalValues {
     type ReturnScore;
-    fn psqt(&self, square: u8, piece: Piece, side: Side) -> Self::ReturnScore;
+    fn psqt(&self, square: u8, piece: Piece, side: PlayerSide) -> Self::ReturnScore;
+    /// Penalty for each pawn on a file beyond the first.
+    fn doubled_pawn(&self) -> Self::ReturnScore;
+    /// Penalty for each pawn without friendly pawns on the adjacent files.
+    fn isolated_pawn(&self) -> Self::ReturnScore;
+    /// Penalty for each pawn that cannot be supported and cannot advance safely.
+    fn backward_pawn(&self) -> Self::ReturnScore;
+    /// Bonus for a passed pawn on the given rank, relative to its side (0 - 7).
+    fn passed_pawn(&self, relative_rank: u8) -> Self::ReturnScore;
+    /// Bonus for each passed pawn defended by a friendly pawn.
+    fn protected_passed_pawn(&self) -> Self::ReturnScore;
+    /// Bonus for each passed pawn with another passed pawn on an adjacent file.
+    fn connected_passed_pawn(&self) -> Self::ReturnScore;
+    /// Bonus for the closest friendly pawn in front of the king on each shield file, indexed by how
+    /// many ranks it is in front of the king (0 if there is none in range).
+    fn pawn_shield(&self, zone: KingZone, distance: u8) -> Self::ReturnScore;
+    /// Penalty for the closest enemy pawn in front of the king on each shield file, indexed by how
+    /// many ranks it is in front of the king (0 if there is none in range).
+    fn pawn_storm(&self, zone: KingZone, distance: u8) -> Self::ReturnScore;
+    /// Penalty for each friendly pawn on the square color of a bishop, blocked pawns count twice.
+    fn bad_bishop(&self) -> Self::ReturnScore;
 }
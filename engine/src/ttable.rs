@@ -12,7 +12,8 @@
  *
  */
 
-use chess::moves::Move;
+use anyhow::bail;
+use chess::{board::Board, move_generation::MoveGenerator, move_list::MoveList, moves::Move};
 
 use crate::score::Score;
 
@@ -64,7 +65,7 @@ pub struct TranspositionTable {
 
 pub const MAX_TABLE_SIZE_MB: usize = 1024;
 pub const MIN_TABLE_SIZE_MB: usize = 16;
-const DEFAULT_TABLE_SIZE_MB: usize = MIN_TABLE_SIZE_MB;
+pub const DEFAULT_TABLE_SIZE_MB: usize = MIN_TABLE_SIZE_MB;
 
 impl Default for TranspositionTable {
     fn default() -> Self {
@@ -94,6 +95,30 @@ impl TranspositionTable {
         Self::from_capacity(capacity)
     }
 
+    /// Creates a new table of the given size, as set by the `Hash` UCI option.
+    ///
+    /// # Arguments
+    ///
+    /// - `mb` - The size of the table in MB.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the size is outside of [`MIN_TABLE_SIZE_MB`] to [`MAX_TABLE_SIZE_MB`].
+    pub fn try_from_size_in_mb(mb: usize) -> anyhow::Result<Self> {
+        if mb < MIN_TABLE_SIZE_MB {
+            bail!(
+                "hash size too small, must be at least {} MB",
+                MIN_TABLE_SIZE_MB
+            );
+        } else if mb > MAX_TABLE_SIZE_MB {
+            bail!(
+                "hash size too large, must be at most {} MB",
+                MAX_TABLE_SIZE_MB
+            );
+        }
+        Ok(Self::from_size_in_mb(mb))
+    }
+
     fn get_index(&self, zobrist: u64) -> usize {
         fast_range_64(zobrist, self.table.len() as u64) as usize
     }
@@ -108,6 +133,61 @@ impl TranspositionTable {
         self.table[index] = Some(entry);
     }
 
+    /// Reconstructs the principal variation by walking the best moves stored in the table,
+    /// starting from the given board position.
+    ///
+    /// The walk stops when there is no entry for the current position, when the stored move is not
+    /// legal in the current position, when a position repeats or when `max_len` moves were found.
+    ///
+    /// # Arguments
+    ///
+    /// - `board` - The root position to start the walk from.
+    /// - `move_gen` - The move generator used to verify the legality of each move.
+    /// - `max_len` - The maximum number of moves in the principal variation.
+    ///
+    /// # Returns
+    ///
+    /// The principal variation as a list of moves, starting with the best move at the root.
+    pub(crate) fn extract_pv(
+        &mut self,
+        board: &Board,
+        move_gen: &MoveGenerator,
+        max_len: usize,
+    ) -> Vec<Move> {
+        let mut board = board.clone();
+        let mut pv = Vec::with_capacity(max_len);
+        let mut visited = Vec::with_capacity(max_len);
+        let mut move_list = MoveList::new();
+
+        while pv.len() < max_len {
+            let zobrist = board.zobrist_hash();
+            // guard against cycles in the table
+            if visited.contains(&zobrist) {
+                break;
+            }
+            visited.push(zobrist);
+
+            let entry = match self.get_entry(zobrist) {
+                Some(entry) if entry.zobrist == zobrist => entry,
+                _ => break,
+            };
+
+            // make sure the stored move is legal in this position
+            move_list.clear();
+            move_gen.generate_legal_moves(&board, &mut move_list);
+            if !move_list.contains(&entry.board_move) {
+                break;
+            }
+
+            if board.make_move_unchecked(&entry.board_move).is_err() {
+                break;
+            }
+            pv.push(entry.board_move);
+        }
+
+        pv
+    }
+
     pub(crate) fn clear(&mut self) {
         self.table.iter_mut().for_each(|element| {
             *element = None;
@@ -134,6 +214,9 @@ mod tests {
     use super::{EntryFlag, TranspositionTable, TranspositionTableEntry};
     use crate::score::Score;
     use chess::{
+        board::Board,
+        move_generation::MoveGenerator,
+        move_list::MoveList,
         moves::{Move, MoveDescriptor},
         pieces::Piece,
         square::Square,
@@ -239,4 +322,76 @@ mod tests {
         assert!(stored_entry3.is_some());
         assert_eq!(stored_entry3.unwrap().board_move, mv3);
     }
+
+    #[test]
+    fn extract_pv() {
+        let move_gen = MoveGenerator::new();
+        let mut tt = TranspositionTable::from_size_in_mb(16);
+        let mut board = Board::default_board();
+
+        // store a line of legal moves in the table: 1. e4 e5 2. Nf3
+        let mut line = Vec::new();
+        for uci_move in ["e2e4", "e7e5", "g1f3"] {
+            let mut move_list = MoveList::new();
+            move_gen.generate_legal_moves(&board, &mut move_list);
+            let mv = *move_list.find_uci(uci_move).unwrap();
+            tt.store_entry(TranspositionTableEntry::new(
+                board.zobrist_hash(),
+                1,
+                Score::new(0),
+                EntryFlag::Exact,
+                mv,
+            ));
+            board.make_move_unchecked(&mv).unwrap();
+            line.push(mv);
+        }
+
+        let root = Board::default_board();
+        assert_eq!(tt.extract_pv(&root, &move_gen, 10), line);
+        assert_eq!(tt.extract_pv(&root, &move_gen, 2), line[..2]);
+        assert!(tt.extract_pv(&root, &move_gen, 0).is_empty());
+
+        // an illegal move ends the principal variation
+        let illegal = Move::new(
+            &Square::from_square_index(0),
+            &Square::from_square_index(63),
+            MoveDescriptor::None,
+            Piece::Rook,
+            None,
+            None,
+        );
+        tt.store_entry(TranspositionTableEntry::new(
+            board.zobrist_hash(),
+            1,
+            Score::new(0),
+            EntryFlag::Exact,
+            illegal,
+        ));
+        assert_eq!(tt.extract_pv(&root, &move_gen, 10), line);
+    }
+
+    #[test]
+    fn extract_pv_stops_on_repetition() {
+        let move_gen = MoveGenerator::new();
+        let mut tt = TranspositionTable::from_size_in_mb(16);
+        let mut board = Board::default_board();
+
+        // knights shuffling back and forth creates a cycle in the table
+        for uci_move in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+            let mut move_list = MoveList::new();
+            move_gen.generate_legal_moves(&board, &mut move_list);
+            let mv = *move_list.find_uci(uci_move).unwrap();
+            tt.store_entry(TranspositionTableEntry::new(
+                board.zobrist_hash(),
+                1,
+                Score::new(0),
+                EntryFlag::Exact,
+                mv,
+            ));
+            board.make_move_unchecked(&mv).unwrap();
+        }
+
+        let pv = tt.extract_pv(&Board::default_board(), &move_gen, 64);
+        assert_eq!(pv.len(), 4);
+    }
 }
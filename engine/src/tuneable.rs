@@ -4,7 +4,7 @@
  * Created Date: Wednesday, December 11th 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Thu Dec 12 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -12,7 +12,164 @@
  *
  */
 
-use crate::score::ScoreType;
+use std::{fmt::Display, io::Write};
+
+use crate::{
+    defs::MAX_DEPTH,
+    log_level::{log_info_string, LogLevel},
+    move_order::MoveScore,
+    score::{LargeScoreType, ScoreType},
+};
+
+/// Inclusive range of valid values for a numeric tunable. Values outside of it can break the
+/// search.
+///
+/// The tunables are constants for now, nothing sets them at runtime. The ranges are there for a
+/// future tuning interface (e.g. UCI options), which has to clamp new values with
+/// [`TunableRange::clamp`]. Until then they are only checked against the defaults in the tests.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TunableRange<T> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T: Copy + PartialOrd + Display> TunableRange<T> {
+    pub const fn new(min: T, max: T) -> Self {
+        TunableRange { min, max }
+    }
+
+    /// Returns `true` if `value` is within the range.
+    pub fn contains(&self, value: T) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Clamps a new value for a tunable to the range, logging a warning if it was out of range.
+    ///
+    /// # Arguments
+    ///
+    /// - `name` - The name of the tunable, used in the warning.
+    /// - `value` - The value to set.
+    /// - `writer` - Where the warning is written, usually stdout.
+    /// - `log_level` - The configured log level.
+    ///
+    /// # Returns
+    ///
+    /// The value, clamped to the range.
+    pub fn clamp(&self, name: &str, value: T, writer: &mut impl Write, log_level: LogLevel) -> T {
+        let clamped = if value < self.min {
+            self.min
+        } else if value > self.max {
+            self.max
+        } else {
+            return value;
+        };
+
+        log_info_string(
+            writer,
+            log_level,
+            LogLevel::Warning,
+            format!(
+                "{} value {} is outside of [{}, {}], using {}",
+                name, value, self.min, self.max, clamped
+            ),
+        );
+        clamped
+    }
+}
 
 pub(crate) const MIN_ASPIRATION_DEPTH: ScoreType = 1;
+pub const MIN_ASPIRATION_DEPTH_RANGE: TunableRange<ScoreType> =
+    TunableRange::new(0, MAX_DEPTH as ScoreType);
 pub(crate) const ASPIRATION_WINDOW: ScoreType = 50;
+/// The window has to be positive, otherwise failed aspiration searches never widen it.
+pub const ASPIRATION_WINDOW_RANGE: TunableRange<ScoreType> = TunableRange::new(1, 1_000);
+/// Maximum number of plies quiescence search goes beyond the main search. Deeper nodes stand pat
+/// on the static evaluation, so positions with long capture chains can't blow up the search.
+pub(crate) const QS_MAX_PLY: u8 = 32;
+/// At least one ply of captures is searched, and quiescence nodes never go past [`MAX_DEPTH`]
+/// anyway.
+pub const QS_MAX_PLY_RANGE: TunableRange<u8> = TunableRange::new(1, MAX_DEPTH);
+/// Use game phase interpolated piece values in static exchange evaluation instead of flat ones.
+/// On `data/tactics.epd` (`byte-knight tactics`) both solve 56 of 91 positions at 100k nodes and
+/// 68 at 500k, at 2M nodes the tapered values solve 68 and the flat ones 67. That is within noise,
+/// so the simpler flat values stay the default.
+pub(crate) const SEE_TAPERED_PIECE_VALUES: bool = false;
+/// Prune captures losing material (by SEE) in the first ply of quiescence search too. Deeper
+/// plies are always pruned.
+pub(crate) const QSEARCH_SEE_PRUNE_FIRST_PLY: bool = true;
+/// Jitter draw scores by a few centipawns based on the node count, so the search doesn't steer
+/// into repetitions in equal positions. This is the default of the `Draw Jitter` UCI option.
+pub(crate) const DRAW_JITTER: bool = false;
+/// Stop iterative deepening once the side to move has a forced mate that the last two iterations
+/// agree on, instead of searching until the depth or time limit. Deeper iterations can't find a
+/// shorter mate once the search is deep enough to see the whole mating line.
+pub(crate) const STOP_ON_MATE: bool = true;
+/// Move ordering bonus for quiet moves that move a piece away from an attack by a less valuable
+/// piece, and for captures of a piece that attacks one of those threatened pieces.
+pub(crate) const THREAT_ORDERING_BONUS: LargeScoreType = 8_192;
+/// Larger bonuses could push a capture or quiet move score out of its [`MoveScore`] category.
+pub const THREAT_ORDERING_BONUS_RANGE: TunableRange<LargeScoreType> =
+    TunableRange::new(0, MoveScore::CATEGORY_RANGE / 2);
+
+/// The evaluation of opposite colored bishop endings is divided by this, because they are hard to
+/// win even with an extra pawn or two.
+pub(crate) const OCB_DRAW_DIVISOR: ScoreType = 2;
+/// A divisor of 1 disables the scaling, 0 would divide by zero.
+pub const OCB_DRAW_DIVISOR_RANGE: TunableRange<ScoreType> = TunableRange::new(1, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        TunableRange, ASPIRATION_WINDOW, ASPIRATION_WINDOW_RANGE, MIN_ASPIRATION_DEPTH,
+        MIN_ASPIRATION_DEPTH_RANGE, OCB_DRAW_DIVISOR, OCB_DRAW_DIVISOR_RANGE, QS_MAX_PLY,
+        QS_MAX_PLY_RANGE, THREAT_ORDERING_BONUS, THREAT_ORDERING_BONUS_RANGE,
+    };
+    use crate::log_level::LogLevel;
+
+    #[test]
+    fn defaults_are_in_range() {
+        assert!(MIN_ASPIRATION_DEPTH_RANGE.contains(MIN_ASPIRATION_DEPTH));
+        assert!(ASPIRATION_WINDOW_RANGE.contains(ASPIRATION_WINDOW));
+        assert!(THREAT_ORDERING_BONUS_RANGE.contains(THREAT_ORDERING_BONUS));
+        assert!(OCB_DRAW_DIVISOR_RANGE.contains(OCB_DRAW_DIVISOR));
+        assert!(QS_MAX_PLY_RANGE.contains(QS_MAX_PLY));
+    }
+
+    #[test]
+    fn out_of_range_values_are_clamped() {
+        let range = ASPIRATION_WINDOW_RANGE;
+        let mut output = Vec::new();
+        assert_eq!(
+            range.clamp("ASPIRATION_WINDOW", -50, &mut output, LogLevel::Warning),
+            range.min
+        );
+        assert_eq!(
+            range.clamp(
+                "ASPIRATION_WINDOW",
+                i16::MAX,
+                &mut output,
+                LogLevel::Warning
+            ),
+            range.max
+        );
+        let output = String::from_utf8(output).unwrap();
+        assert_eq!(output.lines().count(), 2);
+        assert!(
+            output.starts_with("info string ASPIRATION_WINDOW value -50 is outside of [1, 1000]")
+        );
+
+        // values in range are kept as is, without a warning
+        let mut output = Vec::new();
+        assert_eq!(
+            range.clamp("ASPIRATION_WINDOW", 25, &mut output, LogLevel::Warning),
+            25
+        );
+        assert!(output.is_empty());
+
+        // warnings respect the log level
+        let range = TunableRange::new(0_i64, 10);
+        let mut output = Vec::new();
+        assert_eq!(range.clamp("test", 11, &mut output, LogLevel::Error), 10);
+        assert!(output.is_empty());
+    }
+}
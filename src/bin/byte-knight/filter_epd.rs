@@ -0,0 +1,139 @@
+/*
+ * filter_epd.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use anyhow::Context;
+use chess::{board::Board, epd::parse_epd_record, move_generation::MoveGenerator};
+use engine::{
+    evaluation::ByteKnightEvaluation,
+    score::ScoreType,
+    search::{Search, SearchParameters},
+    traits::Eval,
+};
+
+/// The largest difference between the static evaluation and the quiescence score for a position to
+/// still count as quiet. With 0 the scores have to be exactly equal, which means no capture
+/// sequence improves on standing pat, i.e. the side to move has no winning capture.
+const QUIET_MARGIN: ScoreType = 0;
+
+/// Returns `true` if the position is quiet: the side to move is not in check and the quiescence
+/// score is within [`QUIET_MARGIN`] of the static evaluation.
+fn is_quiet(
+    board: &mut Board,
+    search: &mut Search,
+    evaluation: &ByteKnightEvaluation,
+    move_gen: &MoveGenerator,
+) -> bool {
+    let (in_check, _) = move_gen.in_check_info(board);
+    if in_check {
+        return false;
+    }
+
+    let static_eval = evaluation.eval(board);
+    let quiescence = search.quiescence_score(board);
+    (quiescence.0 as i32 - static_eval.0 as i32).abs() <= QUIET_MARGIN as i32
+}
+
+/// Returns the lines of `input` that hold quiet positions, see [`is_quiet`]. Empty lines are
+/// skipped.
+///
+/// # Errors
+///
+/// Returns an error with the line number if a line doesn't hold a valid position.
+fn quiet_lines(input: &str) -> anyhow::Result<Vec<&str>> {
+    let parameters = SearchParameters::default();
+    let mut ttable = Default::default();
+    let mut history_table = Default::default();
+    let mut search = Search::new(&parameters, &mut ttable, &mut history_table);
+    let evaluation = ByteKnightEvaluation::default();
+    let move_gen = MoveGenerator::new();
+
+    let mut quiet = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (mut board, _) =
+            parse_epd_record(line).with_context(|| format!("line {}: invalid position", i + 1))?;
+        if is_quiet(&mut board, &mut search, &evaluation, &move_gen) {
+            quiet.push(line);
+        }
+    }
+    Ok(quiet)
+}
+
+/// Copies the quiet positions of the EPD file `input` to `output`, discarding noisy positions
+/// where the side to move is in check or has a winning capture. This is the usual data preparation
+/// for Texel tuning.
+///
+/// # Arguments
+///
+/// - `input` - The EPD file to read.
+/// - `output` - The EPD file to write the quiet positions to.
+///
+/// # Errors
+///
+/// Returns an error if a file can't be read or written, or a line doesn't hold a valid position.
+pub(crate) fn filter_epd(input: &str, output: &str) -> anyhow::Result<()> {
+    let contents =
+        std::fs::read_to_string(input).with_context(|| format!("failed to read {}", input))?;
+    let total = contents.lines().filter(|l| !l.trim().is_empty()).count();
+    let quiet = quiet_lines(&contents)?;
+
+    let mut filtered = quiet.join("\n");
+    if !filtered.is_empty() {
+        filtered.push('\n');
+    }
+    std::fs::write(output, filtered).with_context(|| format!("failed to write {}", output))?;
+    println!(
+        "kept {} of {} positions, wrote {}",
+        quiet.len(),
+        total,
+        output
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::quiet_lines;
+
+    #[test]
+    fn keeps_only_quiet_positions() {
+        let input = [
+            // quiet: the start position
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - c9 \"1/2-1/2\";",
+            // noisy: white can take the undefended black queen
+            "4k3/8/8/3q4/8/8/3R4/4K3 w - - 0 1",
+            // noisy: white is in check
+            "4k3/8/8/8/8/8/4r3/4K3 w - - 0 1",
+            "",
+            // quiet: nothing to capture
+            "4k3/8/8/8/8/8/8/3QK3 b - - 0 1 ; some comment",
+        ]
+        .join("\n");
+
+        let quiet = quiet_lines(&input).unwrap();
+        assert_eq!(
+            quiet,
+            [
+                "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - c9 \"1/2-1/2\";",
+                "4k3/8/8/8/8/8/8/3QK3 b - - 0 1 ; some comment",
+            ]
+        );
+
+        let error = quiet_lines("4k3/8/8/8/8/8/8/4K3 x - -\n").unwrap_err();
+        assert!(error.to_string().starts_with("line 1"), "{}", error);
+    }
+}
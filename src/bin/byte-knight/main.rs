@@ -4,7 +4,7 @@
  * Created Date: Wednesday, August 14th 2024
  * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
  * -----
- * Last Modified: Thu Nov 21 2024
+ * Last Modified: Fri Oct 16 2026
  * -----
  * Copyright (c) 2024 Paul Tsouchlos (DeveloperPaul123)
  * GNU General Public License v3.0 or later
@@ -13,11 +13,18 @@
  */
 
 mod bench;
+mod filter_epd;
+mod tactics;
 
+use bench::BenchLimit;
+use chess::board::Board;
 use clap::{Parser, Subcommand};
 use engine::defs::About;
 use engine::engine::ByteKnight;
+use engine::evaluation::ByteKnightEvaluation;
+use engine::log_level::LogLevel;
 use std::process::exit;
+use std::time::Duration;
 
 #[derive(Parser)]
 #[command(
@@ -26,23 +33,126 @@ use std::process::exit;
 struct Options {
     #[command(subcommand)]
     command: Option<Command>,
+
+    #[arg(
+        long,
+        default_value_t = LogLevel::Info,
+        help = "Verbosity of `info string` diagnostics: off, error, warning, info or debug"
+    )]
+    log_level: LogLevel,
 }
 
 #[derive(Subcommand)]
 #[command(about = "Available commands")]
 enum Command {
-    #[command(about = "Run fixed depth search")]
+    #[command(about = "Run the engine in UCI mode, this is the default without a command")]
+    Uci,
+    #[command(
+        about = "Search the benchmark positions to a fixed depth, for a fixed time or nodes"
+    )]
     Bench {
         #[arg(short, long, default_value = "6")]
         depth: u8,
 
+        #[arg(
+            short,
+            long,
+            conflicts_with_all = ["depth", "nodes"],
+            help = "Search every position for this many milliseconds instead of to a fixed depth"
+        )]
+        movetime: Option<u64>,
+
+        #[arg(
+            short,
+            long,
+            conflicts_with = "depth",
+            help = "Search every position for this many nodes instead of to a fixed depth"
+        )]
+        nodes: Option<u64>,
+
+        #[arg(
+            long,
+            default_value_t = 16,
+            help = "Size of the transposition table in MB"
+        )]
+        hash: usize,
+
+        #[arg(
+            long,
+            default_value_t = 1,
+            help = "Number of search threads, only 1 is supported"
+        )]
+        threads: usize,
+
         #[arg(short, long)]
         epd_file: Option<String>,
+
+        #[arg(
+            short,
+            long,
+            conflicts_with = "epd_file",
+            help = "File with one FEN per line, optionally followed by `; depth <n>`"
+        )]
+        positions: Option<String>,
+    },
+    #[command(about = "Statically evaluate a position, without searching it")]
+    Eval {
+        #[arg(short, long, help = "FEN of the position to evaluate")]
+        fen: String,
+
+        #[arg(
+            short,
+            long,
+            help = "Also print the contribution of each evaluation term"
+        )]
+        breakdown: bool,
+    },
+    #[command(
+        about = "Count the positions of a test suite with `bm` or `am` moves that are solved"
+    )]
+    Tactics {
+        #[arg(short, long, help = "EPD file with the test positions")]
+        epd_file: String,
+
+        #[arg(short, long, default_value = "6")]
+        depth: u8,
+
+        #[arg(
+            short,
+            long,
+            conflicts_with_all = ["depth", "nodes"],
+            help = "Search every position for this many milliseconds instead of to a fixed depth"
+        )]
+        movetime: Option<u64>,
+
+        #[arg(
+            short,
+            long,
+            conflicts_with = "depth",
+            help = "Search every position for this many nodes instead of to a fixed depth"
+        )]
+        nodes: Option<u64>,
+
+        #[arg(
+            long,
+            default_value_t = 16,
+            help = "Size of the transposition table in MB"
+        )]
+        hash: usize,
+    },
+    #[command(about = "Keep only the quiet positions of an EPD file, e.g. to prepare tuning data")]
+    FilterEpd {
+        #[arg(short, long, help = "EPD file to read")]
+        input: String,
+
+        #[arg(short, long, help = "EPD file to write the quiet positions to")]
+        output: String,
     },
 }
 
-fn run_uci() {
+fn run_uci(log_level: LogLevel) {
     let mut engine = ByteKnight::new();
+    engine.set_log_level(log_level);
     let engine_run_result = engine.run();
     match engine_run_result {
         Ok(_) => (),
@@ -53,14 +163,82 @@ fn run_uci() {
     }
 }
 
+fn run_eval(fen: &str, breakdown: bool) {
+    let score = match ByteKnight::evaluate_fen(fen) {
+        Ok(score) => score,
+        Err(e) => {
+            eprintln!("Error evaluating position: {}", e);
+            exit(1);
+        }
+    };
+    println!("{}", score);
+
+    if breakdown {
+        // the FEN was already parsed successfully above
+        let board = Board::from_fen(fen).unwrap();
+        println!("{}", ByteKnightEvaluation::default().evaluate_debug(&board));
+    }
+}
+
+fn run_filter_epd(input: &str, output: &str) {
+    if let Err(e) = filter_epd::filter_epd(input, output) {
+        eprintln!("Error filtering positions: {:#}", e);
+        exit(1);
+    }
+}
+
+fn run_tactics(epd_file: &str, limit: BenchLimit, hash: usize) {
+    if let Err(e) = tactics::tactics(epd_file, limit, hash) {
+        eprintln!("Error running tactics: {:#}", e);
+        exit(1);
+    }
+}
+
+/// Returns the search budget for the `--depth`, `--movetime` and `--nodes` arguments.
+fn bench_limit(depth: u8, movetime: Option<u64>, nodes: Option<u64>) -> BenchLimit {
+    match (movetime, nodes) {
+        (Some(movetime), _) => BenchLimit::MoveTime(Duration::from_millis(movetime)),
+        (_, Some(nodes)) => BenchLimit::Nodes(nodes),
+        _ => BenchLimit::Depth(depth),
+    }
+}
+
+fn run_bench(limit: BenchLimit, hash: usize, threads: usize, positions: &Option<String>) {
+    if let Err(e) = bench::bench(limit, hash, threads, positions) {
+        eprintln!("Error running bench: {:#}", e);
+        exit(1);
+    }
+}
+
 fn main() {
     let args = Options::parse();
     match args.command {
         Some(command) => match command {
-            Command::Bench { depth, epd_file } => {
-                bench::bench(depth, &epd_file);
-            }
+            Command::Bench {
+                depth,
+                movetime,
+                nodes,
+                hash,
+                threads,
+                epd_file,
+                positions,
+            } => run_bench(
+                bench_limit(depth, movetime, nodes),
+                hash,
+                threads,
+                &positions.or(epd_file),
+            ),
+            Command::Tactics {
+                epd_file,
+                depth,
+                movetime,
+                nodes,
+                hash,
+            } => run_tactics(&epd_file, bench_limit(depth, movetime, nodes), hash),
+            Command::Uci => run_uci(args.log_level),
+            Command::Eval { fen, breakdown } => run_eval(&fen, breakdown),
+            Command::FilterEpd { input, output } => run_filter_epd(&input, &output),
         },
-        None => run_uci(),
+        None => run_uci(args.log_level),
     }
 }
@@ -0,0 +1,191 @@
+/*
+ * tactics.rs
+ * Part of the byte-knight project
+ * Created Date: Friday, October 16th 2026
+ * Author: Paul Tsouchlos (DeveloperPaul123) (developer.paul.123@gmail.com)
+ * -----
+ * Last Modified: Fri Oct 16 2026
+ * -----
+ * Copyright (c) 2026 Paul Tsouchlos (DeveloperPaul123)
+ * GNU General Public License v3.0 or later
+ * https://www.gnu.org/licenses/gpl-3.0-standalone.html
+ *
+ */
+
+use anyhow::{bail, Context};
+use chess::{
+    board::Board,
+    epd::{parse_epd_record, parse_san_move},
+    move_generation::MoveGenerator,
+    moves::Move,
+};
+use engine::{search::Search, ttable::TranspositionTable};
+
+use crate::bench::BenchLimit;
+
+/// A test position with the moves that solve it.
+struct TacticsPosition {
+    board: Board,
+    /// The `id` of the EPD record, or its line number.
+    id: String,
+    /// Best moves (`bm`), playing any of them solves the position.
+    best_moves: Vec<Move>,
+    /// Moves to avoid (`am`), playing any of them fails the position.
+    avoid_moves: Vec<Move>,
+}
+
+impl TacticsPosition {
+    /// Returns `true` if playing `mv` solves the position.
+    fn is_solved_by(&self, mv: Move) -> bool {
+        (self.best_moves.is_empty() || self.best_moves.contains(&mv))
+            && !self.avoid_moves.contains(&mv)
+    }
+}
+
+/// Parses the test positions of an EPD file. Every record needs a `bm` or an `am` operation with
+/// moves in SAN. Empty lines are skipped.
+///
+/// # Errors
+///
+/// Returns an error with the line number if a record is invalid, has no moves or one of its moves
+/// is not legal.
+fn parse_tactics_positions(input: &str) -> anyhow::Result<Vec<TacticsPosition>> {
+    let move_gen = MoveGenerator::new();
+    let mut positions = Vec::new();
+    for (i, line) in input.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (board, opcodes) = parse_epd_record(line).with_context(|| format!("line {}", i + 1))?;
+        let moves = |opcode: &str| {
+            opcodes
+                .get(opcode)
+                .map_or("", String::as_str)
+                .split_whitespace()
+                .map(|san| parse_san_move(&board, &move_gen, san))
+                .collect::<anyhow::Result<Vec<_>>>()
+                .with_context(|| format!("line {}: invalid {} move", i + 1, opcode))
+        };
+        let best_moves = moves("bm")?;
+        let avoid_moves = moves("am")?;
+        if best_moves.is_empty() && avoid_moves.is_empty() {
+            bail!("line {}: no bm or am operation", i + 1);
+        }
+
+        let id = opcodes
+            .get("id")
+            .cloned()
+            .unwrap_or_else(|| format!("line {}", i + 1));
+        positions.push(TacticsPosition {
+            board,
+            id,
+            best_moves,
+            avoid_moves,
+        });
+    }
+    Ok(positions)
+}
+
+/// Searches every position with the given limit and prints whether the engine played a solving
+/// move.
+///
+/// # Returns
+///
+/// The number of solved positions and the total number of nodes searched.
+fn run_tactics(
+    positions: &[TacticsPosition],
+    limit: BenchLimit,
+    tt: &mut TranspositionTable,
+) -> (usize, u64) {
+    let mut solved = 0;
+    let mut nodes = 0u64;
+    let mut hist = Default::default();
+    let config = limit.search_parameters(None);
+
+    for position in positions {
+        let mut board = position.board.clone();
+        let mut search = Search::new(&config, tt, &mut hist);
+        let result = search.search(&mut board, None);
+        nodes += result.nodes;
+
+        let best_move = result.best_move.unwrap_or(Move::NULL);
+        let is_solved = position.is_solved_by(best_move);
+        solved += is_solved as usize;
+        println!(
+            "{} {} played {} depth {} nodes {}",
+            position.id,
+            if is_solved { "solved" } else { "failed" },
+            best_move.to_long_algebraic(),
+            result.depth - 1,
+            result.nodes
+        );
+    }
+    (solved, nodes)
+}
+
+/// Runs a test suite of positions with known best moves, e.g. Win At Chess, and prints how many of
+/// them the engine solves.
+///
+/// # Arguments
+///
+/// - `epd_file` - The EPD file with the positions, see [`parse_tactics_positions`].
+/// - `limit` - The search budget of each position.
+/// - `hash_mb` - The size of the transposition table in MB.
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read, a record is invalid or the hash size is invalid.
+pub(crate) fn tactics(epd_file: &str, limit: BenchLimit, hash_mb: usize) -> anyhow::Result<()> {
+    let input = std::fs::read_to_string(epd_file)
+        .with_context(|| format!("failed to read {}", epd_file))?;
+    let positions = parse_tactics_positions(&input)?;
+    let mut tt = TranspositionTable::try_from_size_in_mb(hash_mb)?;
+
+    let (solved, nodes) = run_tactics(&positions, limit, &mut tt);
+    println!(
+        "solved {} of {} positions, {} nodes",
+        solved,
+        positions.len(),
+        nodes
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::BenchLimit;
+
+    use super::{parse_tactics_positions, run_tactics};
+
+    #[test]
+    fn solves_simple_tactics() {
+        let input = [
+            // back rank mate
+            "6k1/5ppp/8/8/8/8/8/R5K1 w - - bm Ra8#; id \"mate\";",
+            "",
+            // a move to avoid instead of a best move
+            "rnbqk1nr/1ppp1ppp/4p3/p7/1b6/2N1P3/PPPPBPPP/R1BQK1NR b KQkq - am Bxc3;",
+        ]
+        .join("\n");
+        let positions = parse_tactics_positions(&input).unwrap();
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions[0].id, "mate");
+        assert_eq!(positions[1].id, "line 3");
+        assert!(!positions[1].is_solved_by(positions[1].avoid_moves[0]));
+
+        let mut tt = Default::default();
+        let (solved, nodes) = run_tactics(&positions[..1], BenchLimit::Depth(3), &mut tt);
+        assert_eq!(solved, 1);
+        assert!(nodes > 0);
+
+        for line in [
+            "6k1/5ppp/8/8/8/8/8/R5K1 w - - id \"no moves\";",
+            "6k1/5ppp/8/8/8/8/8/R5K1 w - - bm Rh8;",
+            "6k1/5ppp/8/8/8/8/8/R5K1 w - - bm Ra8 Rb9;",
+        ] {
+            let error = parse_tactics_positions(line).err().unwrap();
+            assert!(error.to_string().starts_with("line 1"), "{}", error);
+        }
+    }
+}
@@ -6,7 +6,11 @@
 
 use std::time::Instant;
 
-use chess::{board::Board, move_generation::MoveGenerator, perft::perft};
+use chess::{
+    board::Board,
+    move_generation::MoveGenerator,
+    perft::{parse_perft_epd_line, perft},
+};
 use clap::Parser;
 use colored::*;
 
@@ -30,23 +34,17 @@ fn main() {
     let now = Instant::now();
     let move_gen = MoveGenerator::new();
 
-    for (i, entry) in contents.lines().enumerate() {
-        let mut parts = entry.split(';');
-
-        let fen = parts.next().unwrap().trim();
+    for (i, entry) in contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+    {
+        let (fen, entries) = parse_perft_epd_line(entry).unwrap();
 
         print!("{}", "\n[INIT]".yellow());
         println!(" Beginning perft on {fen:?}");
-        for perft_data in parts {
-            let depth = perft_data
-                .get(1..2)
-                .unwrap()
-                .trim()
-                .parse::<usize>()
-                .unwrap();
-            let expected = perft_data.get(3..).unwrap().trim().parse::<u64>().unwrap();
-
-            let mut board = Board::from_fen(fen).unwrap();
+        for (depth, expected) in entries {
+            let mut board = Board::from_fen(&fen).unwrap();
 
             let start = Instant::now();
             let nodes = perft(&mut board, &move_gen, depth, false).unwrap();
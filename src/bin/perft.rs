@@ -33,6 +33,19 @@ struct Args {
 
     #[arg(short, long)]
     epd_file: Option<String>,
+
+    /// Verify the zobrist hash and make/unmake symmetry at every node. This is much slower.
+    #[arg(short, long, default_value_t = false)]
+    verify: bool,
+
+    /// Copy the board for every move instead of using make/unmake. This is the slower reference
+    /// implementation, useful to cross check the make/unmake path.
+    #[arg(long, default_value_t = false, conflicts_with = "verify")]
+    copy_make: bool,
+
+    /// In EPD mode, print the split perft (divide) of every position and depth that fails.
+    #[arg(long, default_value_t = false)]
+    divide_on_fail: bool,
 }
 
 fn read_lines<P>(filename: P) -> io::Result<Vec<String>>
@@ -44,29 +57,125 @@ where
     Ok(reader.lines().map(|l| l.unwrap()).collect())
 }
 
-fn process_epd_file(path: &str, move_generation: &MoveGenerator) {
+/// Runs perft, or the much slower hash checking perft if `verify` is set, or the copy-make
+/// reference perft if `copy_make` is set.
+fn run_perft(
+    board: &mut Board,
+    move_generation: &MoveGenerator,
+    depth: usize,
+    verify: bool,
+    copy_make: bool,
+) -> anyhow::Result<u64> {
+    if verify {
+        perft::perft_hash_check(board, move_generation, depth)
+    } else if copy_make {
+        perft::perft_copy_make(board, move_generation, depth)
+    } else {
+        perft::perft(board, move_generation, depth, false)
+    }
+}
+
+/// Formats split perft results as one `move: nodes` line per root move, followed by the total.
+fn format_divide(results: &[perft::SplitPerftResult]) -> String {
+    let mut output = String::new();
+    for res in results {
+        output.push_str(&format!("{}: {}\n", res.mv.to_long_algebraic(), res.nodes));
+    }
+    output.push('\n');
+    output.push_str(&format!("{}", results.iter().map(|r| r.nodes).sum::<u64>()));
+    output
+}
+
+/// Runs split perft on `fen` and formats the result, see [`format_divide`].
+fn divide(fen: &str, move_generation: &MoveGenerator, depth: usize) -> anyhow::Result<String> {
+    let mut board = Board::from_fen(fen)?;
+    let results = perft::split_perft(&mut board, move_generation, depth, false)?;
+    Ok(format_divide(&results))
+}
+
+/// A position and depth from an EPD file that didn't produce the expected node count, or a line
+/// that couldn't be checked at all.
+struct Failure {
+    fen: String,
+    /// What went wrong, e.g. the expected and the actual node count.
+    reason: String,
+    /// The depth and split perft of the failing position, if requested.
+    divide: Option<(usize, String)>,
+}
+
+/// Prints a failed check.
+fn print_failure(fen: &str, reason: &str) {
+    print!("{} ", "[FAIL]".red().bold());
+    println!("{:<30}: {}", fen, reason);
+}
+
+/// Checks every position of a perft EPD file and prints a summary.
+///
+/// # Returns
+///
+/// The number of failed checks, including lines that couldn't be parsed and perft errors.
+fn process_epd_file(
+    path: &str,
+    move_generation: &MoveGenerator,
+    verify: bool,
+    copy_make: bool,
+    divide_on_fail: bool,
+) -> usize {
     let mut all_failures = Vec::new();
-    let lines = read_lines(path).unwrap();
+    let lines = read_lines(path)
+        .unwrap()
+        .into_iter()
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>();
     let now = std::time::Instant::now();
     lines
         .par_iter()
         .map(|line| {
-            let parts: Vec<&str> = line.split(';').collect();
-            let fen = parts[0];
+            let (fen, entries) = match perft::parse_perft_epd_line(line) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    let reason = format!("{:#}", e);
+                    print_failure(line, &reason);
+                    return vec![Failure {
+                        fen: line.clone(),
+                        reason,
+                        divide: None,
+                    }];
+                }
+            };
+            let fen = fen.as_str();
             let mut failures = Vec::new();
-            for part in parts.iter().skip(1) {
-                let parts = part.split_whitespace().collect::<Vec<&str>>();
-                let depth = parts[0].replace('D', "").parse::<usize>().unwrap();
-                let expected_nodes = parts[1].parse::<u64>().unwrap();
-                let mut board = Board::from_fen(fen).unwrap();
-                let nodes = perft::perft(&mut board, move_generation, depth, false).unwrap();
+            for (depth, expected_nodes) in entries {
+                let result =
+                    Board::from_fen(fen)
+                        .map_err(anyhow::Error::from)
+                        .and_then(|mut board| {
+                            run_perft(&mut board, move_generation, depth, verify, copy_make)
+                        });
+                let nodes = match result {
+                    Ok(nodes) => nodes,
+                    Err(e) => {
+                        let reason = format!("{:2} {:#}", depth, e);
+                        print_failure(fen, &reason);
+                        failures.push(Failure {
+                            fen: fen.to_string(),
+                            reason,
+                            divide: None,
+                        });
+                        continue;
+                    }
+                };
                 if expected_nodes != nodes {
-                    print!("{} ", "[FAIL]".red().bold());
-                    println!(
-                        "{:<30}: {:2} {:^10} != {:^10}",
-                        fen, depth, expected_nodes, nodes
-                    );
-                    failures.push((fen.to_string(), depth, expected_nodes, nodes));
+                    let reason = format!("{:2} {:^10} != {:^10}", depth, expected_nodes, nodes);
+                    print_failure(fen, &reason);
+                    // the divide is computed here so failing positions are split in parallel too
+                    let divide = divide_on_fail
+                        .then(|| (depth, divide(fen, move_generation, depth).unwrap()));
+                    failures.push(Failure {
+                        fen: fen.to_string(),
+                        reason,
+                        divide,
+                    });
                 } else {
                     print!("{} ", "[PASS]".green());
                     println!(
@@ -81,18 +190,21 @@ fn process_epd_file(path: &str, move_generation: &MoveGenerator) {
         .collect_into_vec(&mut all_failures);
     let elapsed = now.elapsed();
 
+    let failure_count = all_failures.iter().map(|f| f.len()).sum::<usize>();
     println!(
         "Summary:\n\t{} failed\n\t{:.2} seconds",
-        all_failures.iter().map(|f| f.len()).sum::<usize>(),
+        failure_count,
         elapsed.as_secs_f64()
     );
 
-    for (fen, depth, expected, actual) in all_failures.iter().flatten() {
-        println!(
-            "{:<30}: {:2} {:^10} != {:^10}",
-            fen, depth, expected, actual
-        );
+    for failure in all_failures.iter().flatten() {
+        println!("{:<30}: {}", failure.fen, failure.reason);
+        if let Some((depth, divide)) = &failure.divide {
+            println!("divide at depth {}:\n{}\n", depth, divide);
+        }
     }
+
+    failure_count
 }
 
 fn main() {
@@ -101,21 +213,26 @@ fn main() {
     let move_generation = MoveGenerator::new();
     if args.epd_file.is_some() {
         let path = args.epd_file.as_ref().unwrap();
-        process_epd_file(path, &move_generation);
+        let failures = process_epd_file(
+            path,
+            &move_generation,
+            args.verify,
+            args.copy_make,
+            args.divide_on_fail,
+        );
+        if failures > 0 {
+            std::process::exit(1);
+        }
     } else if args.split_perft {
         println!("running split perft at depth {}", args.depth);
         let move_results =
             perft::split_perft(&mut board, &move_generation, args.depth, args.print_moves).unwrap();
-        for res in &move_results {
-            println!("{}: {}", res.mv.to_long_algebraic(), res.nodes);
-        }
-        println!();
-        // print the total nodes
-        println!("{}", move_results.iter().map(|r| r.nodes).sum::<u64>());
+        println!("{}", format_divide(&move_results));
     } else {
         for i in 1..args.depth + 1 {
             let now = std::time::Instant::now();
-            let nodes = perft::perft(&mut board, &move_generation, i, false).unwrap();
+            let nodes =
+                run_perft(&mut board, &move_generation, i, args.verify, args.copy_make).unwrap();
             let elapsed = now.elapsed();
             let nps = nodes as f64 / elapsed.as_secs_f64();
             println!(
@@ -4,7 +4,7 @@ use std::{
     process::exit,
 };
 
-use chess::{board::Board, fen};
+use chess::board::Board;
 use console::Emoji;
 use indicatif::ParallelProgressIterator;
 use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
@@ -72,30 +72,6 @@ fn main() {
     println!("Reading test data...");
     let records_result = read_lichess_puzzles(data_path);
 
-    // Compare two FEN strings for equality only using the first four parts
-    let fen_match = |fen_left: &String, fen_right: &String| -> bool {
-        let fen_left_result = fen::split_fen_string(fen_left);
-        let fen_right_result = fen::split_fen_string(fen_right);
-        if fen_left_result.is_err() || fen_right_result.is_err() {
-            return false;
-        }
-
-        let fen_left_parts = fen_left_result.unwrap();
-        let fen_right_parts = fen_right_result.unwrap();
-
-        if fen_left_parts.len() != fen_right_parts.len() {
-            return false;
-        }
-
-        for part in 0..4 {
-            if fen_left_parts[part] != fen_right_parts[part] {
-                return false;
-            }
-        }
-
-        true
-    };
-
     match records_result {
         Ok(records) => {
             let mut hashes: Vec<(u64, String)> = Vec::with_capacity(records.len());
@@ -109,7 +85,8 @@ fn main() {
                     assert!(board.is_ok());
                     let board = board.unwrap();
                     let hash = board.zobrist_hash();
-                    (hash, record.fen.clone())
+                    // only the first four FEN fields are hashed, so ignore the move counters
+                    (hash, board.fen_without_counters())
                 })
                 .collect_into_vec(&mut hashes);
 
@@ -132,7 +109,7 @@ fn main() {
                     let mut matched = false;
                     for i in 0..fens.len() {
                         for j in i + 1..fens.len() {
-                            if fen_match(&fens[i], &fens[j]) {
+                            if fens[i] == fens[j] {
                                 matched = true;
                                 break;
                             }
@@ -0,0 +1,62 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+/// Runs the engine binary with the given arguments, sends `input` to it and returns its output.
+fn run_engine(args: &[&str], input: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_byte-knight"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn uci_handshake() {
+    // both the explicit subcommand and a bare invocation enter UCI mode
+    for args in [&["uci"][..], &[]] {
+        let output = run_engine(args, "uci\nquit\n");
+        assert!(output.lines().any(|line| line == "uciok"), "{}", output);
+        assert!(output.lines().any(|line| line.starts_with("id name")));
+    }
+}
+
+#[test]
+fn advertised_hash_range_is_accepted() {
+    let output = run_engine(&[], "uci\nquit\n");
+    let hash = output
+        .lines()
+        .find(|line| line.starts_with("option name Hash "))
+        .unwrap();
+    let tokens = hash.split_whitespace().collect::<Vec<_>>();
+    let value_of = |name: &str| {
+        let index = tokens.iter().position(|token| *token == name).unwrap();
+        tokens[index + 1].parse::<usize>().unwrap()
+    };
+
+    // setting the default, the minimum and the maximum must not report an error
+    let input = ["default", "min", "max"]
+        .iter()
+        .map(|name| format!("setoption name Hash value {}\n", value_of(name)))
+        .collect::<String>();
+    let output = run_engine(&[], &format!("{}isready\nquit\n", input));
+    assert!(output.lines().any(|line| line == "readyok"), "{}", output);
+    assert!(
+        !output.lines().any(|line| line.starts_with("info string")),
+        "{}\n{}",
+        hash,
+        output
+    );
+}